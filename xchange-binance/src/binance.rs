@@ -1,12 +1,25 @@
 use crate::dto::BinanceException;
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use crate::dto::meta::exchange_info::{BinanceExchangeInfo, RateLimit as BinanceRateLimit, Symbol};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use xchange_core::TimeUnit;
 use xchange_core::currency::currency::Currency;
 use xchange_core::derivative::Derivative;
+use xchange_core::dto::meta::currency_metadata::CurrencyMetaData;
+use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
+use xchange_core::dto::meta::instrument_metadata::InstrumentMetaData;
+use xchange_core::dto::meta::rate_limit::RateLimit;
+use xchange_core::dto::order::{OrderStatus, OrderType};
+use xchange_core::error::ErrorKind;
 use xchange_core::error::exchange_error::{
-    CurrencyPairNotValidError, ExchangeError, ExchangeSecurityError, ExchangeUnavailableError,
-    FundsExceededError, OrderAmountUnderMinimumError, OrderNotValidError, RateLimitExceededError,
+    CurrencyPairNotValidError, ExchangeApiError, ExchangeError, ExchangeSecurityError,
+    ExchangeUnavailableError, FundsExceededError, MarketSuspendedError, NonceError,
+    NotAvailableFromExchangeError, OrderAmountUnderMinimumError, OrderNotFoundError,
+    OrderNotValidError, RateLimitExceededError,
 };
-use xchange_core::instrument::{Instrument, InstrumentKind};
+use xchange_core::exchange::ExchangeType;
+use xchange_core::instrument::{Instrument, InstrumentDTO, InstrumentKind};
 
 /// --------------------------
 /// BinanceErrorAdapter
@@ -14,43 +27,163 @@ use xchange_core::instrument::{Instrument, InstrumentKind};
 pub struct BinanceErrorAdapter;
 
 impl BinanceErrorAdapter {
+    /// BinanceException → 结构化的 ExchangeError。
+    ///
+    /// 保留错误码、HTTP 状态、请求路径和 Retry-After，source 链为
+    /// 对应的核心错误类型（如 `FundsExceededError`）→ BinanceException
     pub fn adapt(e: BinanceException) -> ExchangeError {
         let message = if e.msg.is_empty() {
             "Operation failed without any error message".to_string()
         } else {
             e.msg.clone()
         };
+        let kind = Self::error_kind(&e);
+        let error = ExchangeApiError::new(kind, message.clone())
+            .code(e.code)
+            .http_status(e.http_status)
+            .path(e.path.clone())
+            .retry_after(e.retry_after());
+
+        match kind {
+            ErrorKind::Auth => {
+                error.with_source(ExchangeSecurityError::with_message_and_source(message, e))
+            }
+            ErrorKind::RateLimit => {
+                error.with_source(RateLimitExceededError::with_message_and_source(message, e))
+            }
+            ErrorKind::InsufficientFunds => {
+                error.with_source(FundsExceededError::with_message_and_source(message, e))
+            }
+            ErrorKind::InvalidOrder if Self::is_under_minimum(&e) => error.with_source(
+                OrderAmountUnderMinimumError::with_message_and_source(message, e),
+            ),
+            ErrorKind::InvalidOrder => {
+                error.with_source(OrderNotValidError::with_message_and_source(message, e))
+            }
+            ErrorKind::UnknownOrder => {
+                error.with_source(OrderNotFoundError::with_message_and_source(message, e))
+            }
+            ErrorKind::UnknownInstrument => error.with_source(
+                CurrencyPairNotValidError::with_message_and_source(message, e),
+            ),
+            ErrorKind::MarketSuspended => {
+                error.with_source(MarketSuspendedError::with_message_and_source(message, e))
+            }
+            ErrorKind::Unavailable => error.with_source(
+                ExchangeUnavailableError::with_message_and_source(message, e),
+            ),
+            ErrorKind::NotSupported => error.with_source(
+                NotAvailableFromExchangeError::with_message_and_source(message, e),
+            ),
+            // 本地时钟偏差，保留原始信息便于与请求超时区分
+            ErrorKind::Nonce => error.with_source(NonceError::with_message_and_source(
+                format!("Timestamp outside recvWindow ({}): {}", e.code, message),
+                e,
+            )),
+            _ => error.with_source(e),
+        }
+        .into()
+    }
+
+    /// 按错误码分类，-1010 / -2010 / -2011 等通用拒绝码再按错误信息细分。
+    ///
+    /// 参考 https://developers.binance.com/docs/binance-spot-api-docs/errors
+    /// 和 https://developers.binance.com/docs/derivatives/usds-margined-futures/error-code
+    pub fn error_kind(e: &BinanceException) -> ErrorKind {
+        if Self::filter_failure(&e.msg).is_some() {
+            return ErrorKind::InvalidOrder;
+        }
 
         match e.code {
-            -1002 => ExchangeSecurityError::with_message(message).into(),
-            -1003 => RateLimitExceededError::with_message(message).into(),
+            // 10xx 服务器或网络问题
+            // DISCONNECTED / SERVER_BUSY / SERVICE_SHUTTING_DOWN
+            -1001 | -1004 | -1008 | -1016 => ErrorKind::Unavailable,
+            // UNEXPECTED_RESP / TIMEOUT，执行状态未知
+            -1006 | -1007 => ErrorKind::Timeout,
+            // UNAUTHORIZED / INVALID_SIGNATURE / 合约 "Not found, authenticated, or authorized"
+            -1002 | -1022 | -1099 => ErrorKind::Auth,
+            // TOO_MANY_REQUESTS / TOO_MANY_ORDERS
+            -1003 | -1015 => ErrorKind::RateLimit,
+            // UNKNOWN_ORDER_COMPOSITION
+            -1014 => ErrorKind::InvalidOrder,
+            // UNSUPPORTED_OPERATION
+            -1020 => ErrorKind::NotSupported,
+            BinanceException::TIMESTAMP_OUTSIDE_RECV_WINDOW => ErrorKind::Nonce,
+            -1010 | -2010 | -2011 => Self::rejection_kind(&e.msg),
+            -1013 => ErrorKind::InvalidOrder,
 
-            -1010 | -2010 | -2011 => {
-                if e.msg.contains("insufficient balance") {
-                    FundsExceededError::with_message(message).into()
-                } else {
-                    ExchangeError::Message(message)
-                }
-            }
+            // 11xx 请求参数问题
+            // BAD_PRECISION / NO_DEPTH / TIF_NOT_REQUIRED / INVALID_TIF
+            // INVALID_ORDER_TYPE / INVALID_SIDE
+            -1111 | -1112 | -1117..=-1114 => ErrorKind::InvalidOrder,
+            // BAD_SYMBOL
+            -1121 => ErrorKind::UnknownInstrument,
+            // INVALID_SYMBOLSTATUS，交易对暂停交易
+            -1122 => ErrorKind::MarketSuspended,
+            // INVALID_LISTEN_KEY
+            -1125 => ErrorKind::Auth,
+            -1199..=-1100 => ErrorKind::InvalidRequest,
 
-            -1013 => Self::create_order_not_valid_exception(message, e),
+            // 20xx 订单被拒绝、订单不存在、API key 问题
+            // NO_SUCH_ORDER / ORDER_ARCHIVED
+            -2013 | -2026 => ErrorKind::UnknownOrder,
+            // BAD_API_KEY_FMT / REJECTED_MBX_KEY / API_KEYS_LOCKED
+            -2014 | -2015 | -2017 => ErrorKind::Auth,
+            // NO_TRADING_WINDOW
+            -2016 => ErrorKind::MarketSuspended,
+            // BALANCE_NOT_SUFFICIENT / MARGIN_NOT_SUFFICIENT
+            -2018 | -2019 => ErrorKind::InsufficientFunds,
+            // CANCEL_ALL_FAIL / UNABLE_TO_FILL / ORDER_WOULD_IMMEDIATELY_TRIGGER
+            // REDUCE_ONLY_REJECT / POSITION_NOT_SUFFICIENT / MAX_OPEN_ORDER_EXCEEDED
+            -2012 | -2022..=-2020 | -2025..=-2024 => ErrorKind::InvalidOrder,
 
-            -1016 => ExchangeUnavailableError::with_message(message).into(),
-            -1021 => ExchangeError::Message("Time out".to_string()),
-            -1121 => CurrencyPairNotValidError::with_message(message).into(),
-            -1122 => ExchangeSecurityError::with_message(message).into(),
+            // 40xx 合约订单和仓位设置
+            // REDUCE_ONLY_ORDER_PERMISSION / NO_PLACE_ORDER_PERMISSION
+            -4087 | -4088 => ErrorKind::Auth,
+            // 杠杆、保证金模式、持仓模式设置
+            -4028 | -4046 | -4047 | -4048 | -4059 | -4161 => ErrorKind::InvalidRequest,
+            // 价格 / 数量越界、reduceOnly 被拒绝、positionSide 不匹配等
+            -4999..=-4000 => ErrorKind::InvalidOrder,
 
-            _ => ExchangeError::Message(message),
+            _ => e
+                .http_status
+                .map_or(ErrorKind::Other, ErrorKind::from_http_status),
         }
     }
 
-    fn create_order_not_valid_exception(message: String, e: BinanceException) -> ExchangeError {
-        if e.msg.contains("MIN_NOTIONAL") {
-            OrderAmountUnderMinimumError::with_message(message).into()
+    /// -1010 / -2010 / -2011 通用拒绝码，按错误信息区分
+    fn rejection_kind(msg: &str) -> ErrorKind {
+        let msg = msg.to_ascii_lowercase();
+        if msg.contains("insufficient balance") {
+            ErrorKind::InsufficientFunds
+        } else if msg.contains("unknown order") || msg.contains("order does not exist") {
+            ErrorKind::UnknownOrder
+        } else if msg.contains("market is closed") || msg.contains("trading is disabled") {
+            ErrorKind::MarketSuspended
+        } else if msg.contains("action is disabled on this account") {
+            ErrorKind::Auth
+        } else if msg.contains("too many new orders") {
+            ErrorKind::RateLimit
         } else {
-            OrderNotValidError::with_message(message).into()
+            ErrorKind::InvalidOrder
         }
     }
+
+    /// "Filter failure: LOT_SIZE" → Some("LOT_SIZE")
+    pub fn filter_failure(msg: &str) -> Option<&str> {
+        let (_, filter) = msg.split_once("Filter failure:")?;
+        filter.split_whitespace().next()
+    }
+
+    /// 数量或名义价值低于最小值。
+    /// NOTIONAL 过滤器同时有上下限，但 maxNotional 极少设置，按低于最小值处理
+    fn is_under_minimum(e: &BinanceException) -> bool {
+        matches!(e.code, -4004 | -4164) // QTY_LESS_THAN_MIN_QTY / MIN_NOTIONAL
+            || matches!(
+                Self::filter_failure(&e.msg),
+                Some("MIN_NOTIONAL" | "NOTIONAL")
+            )
+    }
 }
 
 /// --------------------------
@@ -59,6 +192,10 @@ impl BinanceErrorAdapter {
 pub struct BinanceAdapters;
 
 impl BinanceAdapters {
+    /// 现货 / 合约账户对应的 Wallet id
+    pub const SPOT_WALLET_ID: &'static str = "spot";
+    pub const FUTURES_WALLET_ID: &'static str = "futures";
+
     /// "yyyy-MM-dd HH:mm:ss" UTC string → DateTime<Utc>
     pub fn to_utc_datetime(s: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
         let fmt = "%Y-%m-%d %H:%M:%S";
@@ -127,4 +264,271 @@ impl BinanceAdapters {
             cur.code.clone()
         }
     }
+
+    /// 常见计价币，按匹配优先级排列（USDT 需在 USD 之前）
+    const QUOTE_ASSETS: [&'static str; 14] = [
+        "USDT", "USDC", "FDUSD", "BUSD", "TUSD", "USD", "BTC", "ETH", "BNB", "EUR", "TRY", "BRL",
+        "JPY", "DAI",
+    ];
+
+    /// Binance symbol → InstrumentDTO，按计价币后缀拆分，没有 meta data 时使用
+    ///
+    /// BTCUSDT → BTC/USDT，BTCUSD_PERP → BTC/USD（合约 prompt 为 PERP）
+    pub fn adapt_symbol(symbol: &str, futures: bool) -> Option<InstrumentDTO> {
+        let (pair, prompt) = match symbol.split_once('_') {
+            Some((pair, prompt)) => (pair, Some(prompt)),
+            None => (symbol, None),
+        };
+
+        let (base, counter) = Self::QUOTE_ASSETS.iter().find_map(|quote| {
+            pair.strip_suffix(quote)
+                .filter(|base| !base.is_empty())
+                .map(|base| (base.to_string(), quote.to_string()))
+        })?;
+
+        if futures {
+            Some(InstrumentDTO::Futures {
+                base,
+                counter,
+                prompt: Some(prompt.unwrap_or("PERP").to_string()),
+            })
+        } else {
+            Some(InstrumentDTO::Spot { base, counter })
+        }
+    }
+
+    /// Binance symbol → InstrumentDTO，按 exchangeInfo 加载的 meta data 取 base / quote，
+    /// meta data 中没有该 symbol 时退回 `adapt_symbol` 的计价币后缀规则
+    pub fn adapt_symbol_with_meta_data(
+        symbol: &str,
+        futures: bool,
+        meta_data: &ExchangeMetaData,
+    ) -> Option<InstrumentDTO> {
+        // 与 adapt_meta_instrument 相反：合约 prompt 取 symbol 后缀，永续为 PERP
+        let (pair, prompt) = match symbol.split_once('_') {
+            Some((pair, prompt)) => (pair, Some(prompt)),
+            None => (symbol, None),
+        };
+        let matches = |base: &str, counter: &str| {
+            pair.len() == base.len() + counter.len()
+                && pair.starts_with(base)
+                && pair.ends_with(counter)
+        };
+
+        meta_data
+            .instruments
+            .keys()
+            .find(|instrument| match instrument {
+                InstrumentDTO::Spot { base, counter } => {
+                    !futures && prompt.is_none() && matches(base, counter)
+                }
+                InstrumentDTO::Futures {
+                    base,
+                    counter,
+                    prompt: instrument_prompt,
+                } => {
+                    futures
+                        && instrument_prompt.as_deref() == Some(prompt.unwrap_or("PERP"))
+                        && matches(base, counter)
+                }
+                InstrumentDTO::Options { .. } => false,
+            })
+            .cloned()
+            .or_else(|| Self::adapt_symbol(symbol, futures))
+    }
+
+    /// Binance 订单状态 → OrderStatus
+    pub fn adapt_order_status(status: &str) -> OrderStatus {
+        match status {
+            "NEW" => OrderStatus::NEW,
+            "PARTIALLY_FILLED" => OrderStatus::PartiallyFilled,
+            "FILLED" => OrderStatus::FILLED,
+            "PENDING_CANCEL" => OrderStatus::PendingCancel,
+            "CANCELED" => OrderStatus::CANCELED,
+            "REJECTED" => OrderStatus::REJECTED,
+            "EXPIRED" | "EXPIRED_IN_MATCH" => OrderStatus::EXPIRED,
+            _ => OrderStatus::UNKNOWN,
+        }
+    }
+
+    /// BUY / SELL → OrderType，合约 reduceOnly 订单为平仓方向
+    pub fn adapt_order_type(side: &str, reduce_only: bool) -> OrderType {
+        match (side, reduce_only) {
+            ("BUY", false) => OrderType::Bid,
+            ("BUY", true) => OrderType::ExitAsk,
+            ("SELL", true) => OrderType::ExitBid,
+            _ => OrderType::Ask,
+        }
+    }
+
+    /// exchangeInfo → ExchangeMetaData
+    pub fn adapt_exchange_meta_data(
+        info: &BinanceExchangeInfo,
+        exchange_type: &ExchangeType,
+    ) -> ExchangeMetaData {
+        let mut instruments = HashMap::new();
+        let mut currencies = HashMap::new();
+
+        for symbol in &info.symbols {
+            let Some(instrument) = Self::adapt_meta_instrument(symbol, exchange_type) else {
+                continue;
+            };
+            instruments.insert(instrument, Self::adapt_instrument_meta_data(symbol));
+
+            currencies
+                .entry(Currency::new(&symbol.base_asset))
+                .or_insert_with(|| {
+                    CurrencyMetaData::new(Some(symbol.base_asset_precision as u32), None)
+                });
+            currencies
+                .entry(Currency::new(&symbol.quote_asset))
+                .or_insert_with(|| {
+                    let scale = symbol
+                        .quote_asset_precision
+                        .unwrap_or(symbol.quote_precision);
+                    CurrencyMetaData::new(Some(scale as u32), None)
+                });
+        }
+
+        let mut public_rate_limits = Vec::new();
+        let mut private_rate_limits = Vec::new();
+        for limit in &info.rate_limits {
+            let Some(rate_limit) = Self::adapt_rate_limit(limit) else {
+                continue;
+            };
+            // ORDERS 只作用于下单接口，其余按 IP 计算
+            if limit.rate_limit_type == "ORDERS" {
+                private_rate_limits.push(rate_limit);
+            } else {
+                public_rate_limits.push(rate_limit);
+            }
+        }
+
+        ExchangeMetaData {
+            instruments,
+            currencies,
+            public_rate_limits,
+            private_rate_limits,
+            share_rate_limits: false,
+        }
+    }
+
+    /// Symbol → InstrumentDTO，合约 prompt 取 symbol 后缀，永续为 PERP；
+    /// 币本位合约的计价币为 USD（BTCUSD_PERP → BTC/USD/PERP），与 U 本位区分
+    fn adapt_meta_instrument(
+        symbol: &Symbol,
+        exchange_type: &ExchangeType,
+    ) -> Option<InstrumentDTO> {
+        if symbol.base_asset.is_empty() || symbol.quote_asset.is_empty() {
+            return None;
+        }
+
+        let base = symbol.base_asset.clone();
+        let counter = symbol.quote_asset.clone();
+        // 期权 symbol：BTC-240628-60000-C
+        if let [_, expire, strike, option_type] = symbol.symbol.split('-').collect::<Vec<_>>()[..] {
+            return Some(InstrumentDTO::Options {
+                base,
+                counter,
+                strike: strike.parse().ok()?,
+                expire_date: NaiveDate::parse_from_str(expire, "%y%m%d").ok()?,
+                option_type: option_type.parse().ok()?,
+            });
+        }
+        match exchange_type {
+            ExchangeType::Spot => Some(InstrumentDTO::Spot { base, counter }),
+            _ => {
+                let prompt = match symbol.symbol.split_once('_') {
+                    Some((_, prompt)) => prompt,
+                    None => "PERP",
+                };
+                Some(InstrumentDTO::Futures {
+                    base,
+                    counter,
+                    prompt: Some(prompt.to_string()),
+                })
+            }
+        }
+    }
+
+    /// Symbol filters → InstrumentMetaData
+    pub fn adapt_instrument_meta_data(symbol: &Symbol) -> InstrumentMetaData {
+        let mut builder = InstrumentMetaData::builder()
+            .market_order_enabled(symbol.order_types.iter().any(|t| t == "MARKET"));
+
+        let mut price_scale = symbol.price_precision.map(u32::from);
+        let mut volume_scale = symbol.quantity_precision.map(u32::from);
+
+        for filter in &symbol.filters {
+            match filter.filter_type.as_deref() {
+                Some("PRICE_FILTER") => {
+                    if let Some(tick) = Self::filter_decimal(&filter.tick_size) {
+                        builder = builder.price_step_size(tick);
+                        price_scale = Some(tick.normalize().scale());
+                    }
+                }
+                Some("LOT_SIZE") => {
+                    if let Some(min) = Self::filter_decimal(&filter.min_qty) {
+                        builder = builder.minimum_amount(min);
+                    }
+                    if let Some(max) = Self::filter_decimal(&filter.max_qty) {
+                        builder = builder.maximum_amount(max);
+                    }
+                    if let Some(step) = Self::filter_decimal(&filter.step_size) {
+                        builder = builder.amount_step_size(step);
+                        volume_scale = Some(step.normalize().scale());
+                    }
+                }
+                // 现货 MIN_NOTIONAL / NOTIONAL 用 minNotional，合约 MIN_NOTIONAL 用 notional
+                Some("MIN_NOTIONAL") | Some("NOTIONAL") => {
+                    let min = Self::filter_decimal(&filter.min_notional)
+                        .or_else(|| Self::filter_decimal(&filter.notional));
+                    if let Some(min) = min {
+                        builder = builder.counter_minimum_amount(min);
+                    }
+                    if let Some(max) = Self::filter_decimal(&filter.max_notional) {
+                        builder = builder.counter_maximum_amount(max);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        builder = builder
+            .price_scale(price_scale.unwrap_or(symbol.quote_precision as u32))
+            .volume_scale(volume_scale.unwrap_or(symbol.base_asset_precision as u32));
+        if let Some(contract_size) = symbol.contract_size {
+            builder = builder.contract_value(contract_size);
+        }
+
+        builder.build()
+    }
+
+    /// exchangeInfo rateLimits → RateLimit，未知的 interval 返回 None
+    pub fn adapt_rate_limit(limit: &BinanceRateLimit) -> Option<RateLimit> {
+        let time_unit = match limit.interval.as_str() {
+            "SECOND" => TimeUnit::Seconds,
+            "MINUTE" => TimeUnit::Minutes,
+            "HOUR" => TimeUnit::Hours,
+            "DAY" => TimeUnit::Days,
+            _ => return None,
+        };
+        if limit.limit <= 0 || limit.interval_num <= 0 {
+            return None;
+        }
+
+        Some(RateLimit::with(
+            limit.limit as u32,
+            limit.interval_num as u64,
+            time_unit,
+        ))
+    }
+
+    /// filter 中的数值字段，0 视为不限制
+    fn filter_decimal(value: &Option<String>) -> Option<Decimal> {
+        value
+            .as_deref()
+            .and_then(|v| v.parse::<Decimal>().ok())
+            .filter(|v| !v.is_zero())
+    }
 }
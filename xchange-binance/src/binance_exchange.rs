@@ -1,23 +1,46 @@
+use crate::binance::BinanceAdapters;
+use crate::binance_resilience::{
+    BinanceRateLimitTracker, BinanceRateLimitUsage, BinanceResilience,
+};
 use crate::binance_time_provider::BinanceTimeProvider;
+use crate::client::binance_websocket::BinanceWebSocketClient;
 use crate::dto::BinanceError;
+use crate::dto::meta::exchange_info::BinanceExchangeInfo;
 use crate::service::account_service::BinanceAccountService;
+use crate::service::binance_base_service::BinanceBaseService;
+use crate::service::binance_user_data_stream::BinanceUserDataStream;
 use crate::service::market_data_service::BinanceMarketDataService;
+use crate::service::streaming_account_service::BinanceStreamingAccountService;
+use crate::service::streaming_market_data_service::BinanceStreamingMarketDataService;
+use crate::service::streaming_trade_service::BinanceStreamingTradeService;
 use parking_lot::RwLock;
 use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tracing::warn;
 use xchange_core::ValueFactory;
-use xchange_core::client::ResilienceRegistries;
+use xchange_core::client::{ResiliencePolicy, ResilienceRegistries, ResilientCall};
 use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
 use xchange_core::error::exchange_error::{ExchangeError, ExchangeUnavailableError};
 use xchange_core::exchange::{BaseExchange, Exchange, ExchangeType};
-use xchange_core::exchange_specification::{ExchangeParam, ExchangeSpecification};
+use xchange_core::exchange_specification::{
+    ExchangeParam, ExchangeSpecification, ResilienceSpecification,
+};
 use xchange_core::instrument::Instrument;
+use xchange_core::rescu::transport::HttpTransport;
 use xchange_core::service::account::account_service::AccountService;
+use xchange_core::service::account::streaming_account_service::StreamingAccountService;
 use xchange_core::service::marketdata::market_data_service::MarketDataService;
+use xchange_core::service::marketdata::streaming_market_data_service::StreamingMarketDataService;
+use xchange_core::service::trade::streaming_trade_service::StreamingTradeService;
 use xchange_core::service::trade::trade_service::TradeService;
 use xchange_core::utils::auth_utils::AuthUtils;
+use xchange_core::utils::service_ref;
 
 // ----------------- 常量 -----------------
 pub const EXCHANGE_TYPE_KEY: &str = "Exchange_Type";
+/// 后台同步服务器时间的间隔（毫秒），0 表示不同步
+pub const TIME_SYNC_INTERVAL_KEY: &str = "timeSyncInterval";
+pub const DEFAULT_TIME_SYNC_INTERVAL: Duration = Duration::from_secs(60);
 
 pub const SPOT_URL: &str = "https://api.binance.com";
 pub const FUTURES_URL: &str = "https://fapi.binance.com";
@@ -28,6 +51,14 @@ pub const SANDBOX_SPOT_URL: &str = "https://testnet.binance.vision";
 pub const SANDBOX_FUTURES_URL: &str = "https://testnet.binancefuture.com";
 pub const SANDBOX_INVERSE_FUTURES_URL: &str = "https://testnet.binancefuture.com";
 
+pub const SPOT_STREAM_URL: &str = "wss://stream.binance.com:9443";
+pub const FUTURES_STREAM_URL: &str = "wss://fstream.binance.com";
+pub const INVERSE_FUTURES_STREAM_URL: &str = "wss://dstream.binance.com";
+
+pub const SANDBOX_SPOT_STREAM_URL: &str = "wss://stream.testnet.binance.vision";
+pub const SANDBOX_FUTURES_STREAM_URL: &str = "wss://stream.binancefuture.com";
+pub const SANDBOX_INVERSE_FUTURES_STREAM_URL: &str = "wss://dstream.binancefuture.com";
+
 // ----------------- BinanceExchange -----------------
 pub struct BinanceExchange {
     self_arc: Weak<BinanceExchange>,
@@ -38,43 +69,20 @@ pub struct BinanceExchange {
     /// 异步缓存服务器时间
     pub timestamp_provider: Arc<BinanceTimeProvider>,
 
-    /// Resilience 注册表
-    pub resilience_registries: Arc<ResilienceRegistries>,
+    /// Resilience 注册表，随 ExchangeType 在 apply_specification 时重建
+    resilience_registries: RwLock<Arc<ResilienceRegistries>>,
+
+    /// 服务端限流用量，由响应头同步
+    pub rate_limit_tracker: Arc<BinanceRateLimitTracker>,
+
+    /// 自定义 HTTP transport，None 时使用默认的 HTTP client
+    http_transport: RwLock<Option<Arc<dyn HttpTransport>>>,
 }
 
 impl BinanceExchange {
     pub async fn new() -> Result<Arc<Self>, BinanceError> {
-        let resilience_registries = Arc::new(ResilienceRegistries::new());
         let spec = Self::default_exchange_specification();
-
-        // 创建 Arc<Self>，不在闭包内部初始化服务
-        let exchange = Arc::new_cyclic(|weak_self| {
-            let base = Arc::new(BaseExchange {
-                spec: Arc::new(RwLock::new(spec.clone())),
-                meta_data: Arc::new(RwLock::new(ExchangeMetaData::default())),
-                nonce_factory: Arc::new(BinanceTimeProvider::new(
-                    spec.resilience.clone(),
-                    resilience_registries.clone(),
-                )),
-                market_service: RwLock::new(None),
-                trade_service: RwLock::new(None),
-                account_service: RwLock::new(None),
-            });
-
-            let timestamp_provider = Arc::new(BinanceTimeProvider::new(
-                spec.resilience.clone(),
-                resilience_registries.clone(),
-            ));
-
-            Self {
-                base,
-                timestamp_provider,
-                resilience_registries,
-                self_arc: weak_self.clone(),
-            }
-        });
-
-        Ok(exchange)
+        Ok(Self::build(&spec))
     }
 
     /// 默认初始化（开箱即用）
@@ -84,43 +92,142 @@ impl BinanceExchange {
     }
 
     pub async fn with_specification(
-        mut spec: ExchangeSpecification,
+        spec: ExchangeSpecification,
     ) -> Result<Arc<Self>, BinanceError> {
-        let resilience_registries = Arc::new(ResilienceRegistries::new());
+        Self::with_transport(spec, None).await
+    }
 
+    /// 使用自定义 HTTP transport 初始化，REST client 的请求都经由该 transport 发送
+    pub async fn with_transport(
+        mut spec: ExchangeSpecification,
+        transport: Option<Arc<dyn HttpTransport>>,
+    ) -> Result<Arc<Self>, BinanceError> {
         Self::conclude_host_params(&mut spec);
 
         // 占位 Arc<Self> 用于初始化服务
-        let exchange = Arc::new_cyclic(|weak_self| {
+        let exchange = Self::build(&spec);
+        *exchange.http_transport.write() = transport;
+
+        exchange.init_services()?;
+
+        // 先加载本地快照 / override，远程不可用时仍可使用最后已知的 meta data
+        exchange.base.load_local_meta_data()?;
+        if spec.should_load_remote_meta_data {
+            exchange.load_remote_meta_data().await;
+        }
+
+        Ok(exchange)
+    }
+
+    /// 创建 Arc<Self>，不在闭包内部初始化服务
+    fn build(spec: &ExchangeSpecification) -> Arc<Self> {
+        let resilience_registries = Self::build_resilience_registries(spec);
+
+        Arc::new_cyclic(|weak_self| {
+            // nonce 与签名时间戳共用同一个 provider，apply_specification 时一起更新
+            let timestamp_provider = Arc::new(BinanceTimeProvider::new(
+                spec.resilience,
+                resilience_registries.clone(),
+            ));
+
             let base = Arc::new(BaseExchange {
                 spec: Arc::new(RwLock::new(spec.clone())),
                 meta_data: Arc::new(RwLock::new(ExchangeMetaData::default())),
-                nonce_factory: Arc::new(BinanceTimeProvider::new(
-                    spec.resilience,
-                    resilience_registries.clone(),
-                )),
+                exchange_instruments: RwLock::new(Arc::new(Vec::new())),
+                nonce_factory: timestamp_provider.clone(),
 
                 market_service: RwLock::new(None),
                 trade_service: RwLock::new(None),
                 account_service: RwLock::new(None),
+                streaming_market_service: RwLock::new(None),
+                streaming_trade_service: RwLock::new(None),
+                streaming_account_service: RwLock::new(None),
             });
 
-            let timestamp_provider = Arc::new(BinanceTimeProvider::new(
-                spec.resilience,
-                resilience_registries.clone(),
-            ));
+            let rate_limit_tracker =
+                Arc::new(BinanceRateLimitTracker::new(resilience_registries.clone()));
 
             Self {
                 base,
                 timestamp_provider,
-                resilience_registries,
+                resilience_registries: RwLock::new(resilience_registries),
+                rate_limit_tracker,
+                http_transport: RwLock::new(None),
                 self_arc: weak_self.clone(),
             }
-        });
+        })
+    }
 
-        exchange.init_services()?;
+    pub fn http_transport(&self) -> Option<Arc<dyn HttpTransport>> {
+        self.http_transport.read().clone()
+    }
 
-        Ok(exchange)
+    /// 替换 HTTP transport 并重建服务
+    pub fn set_http_transport(
+        &self,
+        transport: Option<Arc<dyn HttpTransport>>,
+    ) -> Result<(), BinanceError> {
+        *self.http_transport.write() = transport;
+        self.init_services()
+    }
+
+    /// 按 spec 中的 ExchangeType 构建对应的限流额度 / 权重 / 熔断配置
+    fn build_resilience_registries(spec: &ExchangeSpecification) -> Arc<ResilienceRegistries> {
+        Arc::new(BinanceResilience::for_exchange_type(&Self::exchange_type(spec)).registries)
+    }
+
+    /// 按 spec 的开关为调用装配 retry / rate limiter / circuit breaker / bulkhead。
+    /// policy 未指定单次超时时，使用 http_conn_timeout + http_read_timeout
+    pub fn resilient<T>(
+        &self,
+        call: ResilientCall<T>,
+        policy: &ResiliencePolicy<'_>,
+    ) -> ResilientCall<T>
+    where
+        T: Send + 'static,
+    {
+        let (resilience, attempt_timeout) = {
+            let spec = self.base.spec.read();
+            (
+                spec.resilience,
+                spec.http_conn_timeout
+                    .saturating_add(spec.http_read_timeout),
+            )
+        };
+
+        let mut policy = *policy;
+        if policy.attempt_timeout.is_none() && attempt_timeout > 0 {
+            policy = policy.attempt_timeout(Duration::from_millis(attempt_timeout));
+        }
+        self.get_resilience_registries()
+            .decorate(call, &resilience, &policy)
+    }
+
+    /// 加载远程 ExchangeMetaData，失败时保留本地 meta data
+    pub async fn load_remote_meta_data(&self) {
+        if let Err(e) = self.remote_init().await {
+            warn!("load remote meta data failed: {}", e);
+        }
+    }
+
+    /// 按 ExchangeType 请求对应的 exchangeInfo
+    pub async fn fetch_exchange_info(&self) -> Result<BinanceExchangeInfo, BinanceError> {
+        let market_service = self
+            .base
+            .market_service
+            .read()
+            .clone()
+            .ok_or_else(|| BinanceError::ServiceNotInitialized("MarketDataService".into()))?;
+        let market = service_ref::<BinanceMarketDataService, _>(&market_service);
+
+        let exchange_type = Self::exchange_type(&self.base.spec.read());
+        match exchange_type {
+            ExchangeType::Spot => market.exchange_info().await,
+            ExchangeType::Inverse => market.inverse_exchange_info().await,
+            ExchangeType::Futures | ExchangeType::PortfolioMargin => {
+                market.future_exchange_info().await
+            }
+        }
     }
 
     pub fn init_services(&self) -> Result<(), BinanceError> {
@@ -131,9 +238,72 @@ impl BinanceExchange {
         *self.base.market_service.write() = Some(Arc::new(market_service));
 
         // 2. 初始化 AccountService
-        let account_service = BinanceAccountService::new(exchange_ref)?;
+        let account_service = BinanceAccountService::new(exchange_ref.clone())?;
         *self.base.account_service.write() = Some(Arc::new(account_service));
 
+        // 3. 初始化 StreamingMarketDataService（连接在首次订阅时建立）
+        let (stream_url, exchange_type, authenticated, time_sync_interval) = {
+            let spec = self.base.spec.read();
+            (
+                Self::stream_url(&spec),
+                Self::exchange_type(&spec),
+                spec.api_key.is_some(),
+                Self::time_sync_interval(&spec),
+            )
+        };
+        let ws_client = Arc::new(BinanceWebSocketClient::new(&stream_url));
+        let streaming_market_service =
+            BinanceStreamingMarketDataService::new(ws_client.clone(), exchange_type.clone());
+        *self.base.streaming_market_service.write() = Some(Arc::new(streaming_market_service));
+
+        // 4. 初始化 StreamingTradeService / StreamingAccountService
+        //    listenKey 需要 API Key；两者共用同一个 user data stream，与行情共用同一连接
+        self.timestamp_provider.stop_refresh();
+        let base = if authenticated {
+            let base = Arc::new(BinanceBaseService::new(exchange_ref)?);
+
+            // 有 secret 时签名请求使用校正后的时间戳，后台定期同步时钟偏移
+            if base.digest.is_some()
+                && tokio::runtime::Handle::try_current().is_ok()
+                && let Some(interval) = time_sync_interval
+            {
+                self.timestamp_provider.start_refresh(interval);
+            }
+            Some(base)
+        } else {
+            None
+        };
+
+        // 统一账户的 listenKey 使用 papi 接口，暂不支持，不为其打开 fapi 的 user data stream
+        match base {
+            Some(base) if !matches!(exchange_type, ExchangeType::PortfolioMargin) => {
+                let user_data = Arc::new(BinanceUserDataStream::new(
+                    base,
+                    ws_client,
+                    exchange_type.clone(),
+                ));
+                let streaming_trade_service = BinanceStreamingTradeService::new(
+                    user_data.clone(),
+                    exchange_type.clone(),
+                    self.base.meta_data.clone(),
+                );
+                let streaming_account_service = BinanceStreamingAccountService::new(
+                    user_data,
+                    exchange_type,
+                    self.base.meta_data.clone(),
+                );
+                *self.base.streaming_trade_service.write() =
+                    Some(Arc::new(streaming_trade_service));
+                *self.base.streaming_account_service.write() =
+                    Some(Arc::new(streaming_account_service));
+            }
+            _ => {
+                // 不再保留上一个账户的 user data 服务
+                *self.base.streaming_trade_service.write() = None;
+                *self.base.streaming_account_service.write() = None;
+            }
+        }
+
         Ok(())
     }
 
@@ -178,6 +348,49 @@ impl BinanceExchange {
         }
     }
 
+    /// --------------------------
+    /// WebSocket 地址：优先使用 override_websocket_api_uri
+    /// --------------------------
+    pub fn stream_url(spec: &ExchangeSpecification) -> String {
+        if let Some(uri) = &spec.override_websocket_api_uri {
+            return uri.clone();
+        }
+
+        let url = match (Self::exchange_type(spec), spec.use_sandbox) {
+            (ExchangeType::Spot, false) => SPOT_STREAM_URL,
+            (ExchangeType::Spot, true) => SANDBOX_SPOT_STREAM_URL,
+            (ExchangeType::Futures | ExchangeType::PortfolioMargin, false) => FUTURES_STREAM_URL,
+            (ExchangeType::Futures | ExchangeType::PortfolioMargin, true) => {
+                SANDBOX_FUTURES_STREAM_URL
+            }
+            (ExchangeType::Inverse, false) => INVERSE_FUTURES_STREAM_URL,
+            (ExchangeType::Inverse, true) => SANDBOX_INVERSE_FUTURES_STREAM_URL,
+        };
+        url.to_string()
+    }
+
+    /// 后台同步服务器时间的间隔，未设置时为 DEFAULT_TIME_SYNC_INTERVAL
+    pub fn time_sync_interval(spec: &ExchangeSpecification) -> Option<Duration> {
+        let millis = match spec
+            .exchange_specific_parameters
+            .get(TIME_SYNC_INTERVAL_KEY)
+        {
+            None => return Some(DEFAULT_TIME_SYNC_INTERVAL),
+            Some(ExchangeParam::Number(n)) => (*n).max(0) as u64,
+            Some(ExchangeParam::String(s)) => s.parse().unwrap_or(0),
+            Some(_) => 0,
+        };
+        (millis > 0).then(|| Duration::from_millis(millis))
+    }
+
+    /// spec 中的 ExchangeType，未设置时默认 Spot
+    pub fn exchange_type(spec: &ExchangeSpecification) -> ExchangeType {
+        spec.exchange_specific_parameters
+            .get(EXCHANGE_TYPE_KEY)
+            .and_then(|param| param.as_exchange_type())
+            .unwrap_or(ExchangeType::Spot)
+    }
+
     /// --------------------------
     /// 工具方法：FUTURES?
     /// --------------------------
@@ -206,12 +419,17 @@ impl BinanceExchange {
     /// --------------------------
     pub async fn authenticated(&self) -> bool {
         let spec = self.base.spec.read();
-        spec.api_key.is_some() && spec.secret_key.is_some()
+        spec.api_key.is_some() && (spec.secret_key.is_some() || spec.private_key_file.is_some())
     }
 
-    /// 获取 Resilience 注册表（可懒初始化）
+    /// 当前 ExchangeType 对应的 Resilience 注册表
     pub fn get_resilience_registries(&self) -> Arc<ResilienceRegistries> {
-        self.resilience_registries.clone()
+        self.resilience_registries.read().clone()
+    }
+
+    /// 服务端返回的最近一次限流用量
+    pub fn rate_limit_usage(&self) -> BinanceRateLimitUsage {
+        self.rate_limit_tracker.usage()
     }
 
     pub fn default_exchange_specification() -> ExchangeSpecification {
@@ -221,9 +439,12 @@ impl BinanceExchange {
             ssl_uri: Some(SPOT_URL.into()),
             host: Some("www.binance.com".into()),
             port: 80,
+            resilience: ResilienceSpecification::enabled(),
 
-            // 其余 Option 字段保持默认
-            ..ExchangeSpecification::builder().build()
+            // 其余 Option 字段保持默认，凭证来自 BINANCE_* 环境变量或 binance-secret.keys
+            ..ExchangeSpecification::builder()
+                .credential_provider(AuthUtils::credential_provider("binance"))
+                .build()
         };
 
         // 设置交易所特定参数
@@ -234,9 +455,6 @@ impl BinanceExchange {
         spec.exchange_specific_parameters
             .insert("USE_SANDBOX".into(), ExchangeParam::Boolean(false));
 
-        // 加载 API Key / Secret
-        AuthUtils::set_api_and_secret_key(&mut spec, Some("binance"));
-
         spec
     }
 }
@@ -253,7 +471,7 @@ impl Exchange for BinanceExchange {
     }
 
     fn exchange_instruments(&self) -> Arc<Vec<Arc<dyn Instrument + Send + Sync>>> {
-        Arc::new(vec![])
+        self.base.exchange_instruments.read().clone()
     }
 
     fn nonce_factory(&self) -> Arc<dyn ValueFactory<u64>> {
@@ -273,15 +491,41 @@ impl Exchange for BinanceExchange {
     ) -> Result<(), ExchangeError> {
         Self::conclude_host_params(&mut spec);
 
+        // 按新的 ExchangeType 重建注册表，限流器 / 熔断器状态随之重置
+        let registries = Self::build_resilience_registries(&spec);
+        *self.resilience_registries.write() = registries.clone();
+        self.rate_limit_tracker.set_registries(registries.clone());
+        self.timestamp_provider
+            .set_resilience(spec.resilience, registries);
+
         // 更新 spec
         *self.base.spec.write() = spec;
 
         // 重新初始化依赖服务
         self.init_services()?;
+        self.base.load_local_meta_data()?;
+
+        // apply_specification 为同步接口，远程 meta data 在后台加载
+        let should_load = self.base.spec.read().should_load_remote_meta_data;
+        if should_load && let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let exchange = self.clone();
+            handle.spawn(async move { exchange.load_remote_meta_data().await });
+        }
 
         Ok(())
     }
 
+    async fn remote_init(&self) -> Result<(), ExchangeError> {
+        let info = self.fetch_exchange_info().await?;
+        let exchange_type = Self::exchange_type(&self.base.spec.read());
+        let meta_data = BinanceAdapters::adapt_exchange_meta_data(&info, &exchange_type);
+        self.base.apply_remote_meta_data(meta_data)
+    }
+
+    async fn resilience_registries(&self) -> Result<Arc<ResilienceRegistries>, ExchangeError> {
+        Ok(self.get_resilience_registries())
+    }
+
     fn market_data_service(
         &self,
     ) -> Result<Arc<dyn MarketDataService + Send + Sync>, ExchangeError> {
@@ -304,4 +548,31 @@ impl Exchange for BinanceExchange {
             ExchangeUnavailableError::with_message("AccountService".to_string()).into()
         })
     }
+
+    fn streaming_market_data_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingMarketDataService + Send + Sync>, ExchangeError> {
+        let guard = self.base.streaming_market_service.read();
+        guard.as_ref().cloned().ok_or_else(|| {
+            ExchangeUnavailableError::with_message("StreamingMarketDataService".to_string()).into()
+        })
+    }
+
+    fn streaming_trade_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingTradeService + Send + Sync>, ExchangeError> {
+        let guard = self.base.streaming_trade_service.read();
+        guard.as_ref().cloned().ok_or_else(|| {
+            ExchangeUnavailableError::with_message("StreamingTradeService".to_string()).into()
+        })
+    }
+
+    fn streaming_account_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingAccountService + Send + Sync>, ExchangeError> {
+        let guard = self.base.streaming_account_service.read();
+        guard.as_ref().cloned().ok_or_else(|| {
+            ExchangeUnavailableError::with_message("StreamingAccountService".to_string()).into()
+        })
+    }
 }
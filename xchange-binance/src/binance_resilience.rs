@@ -1,7 +1,16 @@
+use crate::dto::BinanceException;
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::error::Error;
 use std::sync::Arc;
 use std::time::Duration;
 use xchange_core::client::RateLimiter;
 use xchange_core::client::ResilienceRegistries;
+use xchange_core::client::{
+    Bulkhead, CircuitBreaker, CircuitBreakerConfig, HttpStatusError, RetryConfig, RetryDecision,
+};
+use xchange_core::exchange::ExchangeType;
 
 pub const REQUEST_WEIGHT_RATE_LIMITER: &'static str = "requestWeight";
 
@@ -13,6 +22,24 @@ pub const RAW_REQUESTS: &'static str = "rawRequests";
 pub const ORDERS_PER_10_SECONDS: &'static str = "ordersPer10Seconds";
 pub const ORDERS_PER_MINUTE: &'static str = "ordersPerMinute";
 
+// Endpoint 分组，对应各自的熔断器和并发隔离
+pub const MARKET_DATA_GROUP: &'static str = "marketData";
+pub const TRADE_GROUP: &'static str = "trade";
+pub const ACCOUNT_GROUP: &'static str = "account";
+pub const USER_DATA_STREAM_GROUP: &'static str = "userDataStream";
+
+// Retry，服务按限流器名称查找对应的 RetryConfig
+pub const TIME_RETRY: &'static str = "time";
+
+// Endpoint，用于在注册表中声明请求权重
+pub const ENDPOINT_PING: &'static str = "ping";
+pub const ENDPOINT_TIME: &'static str = "time";
+pub const ENDPOINT_EXCHANGE_INFO: &'static str = "exchangeInfo";
+pub const ENDPOINT_KLINES: &'static str = "klines";
+pub const ENDPOINT_SYSTEM_STATUS: &'static str = "systemStatus";
+pub const ENDPOINT_LISTEN_KEY: &'static str = "listenKey";
+pub const ENDPOINT_ORDER: &'static str = "order";
+
 /// ========================
 /// Binance 默认 Resilience 注册表
 /// ========================
@@ -21,39 +48,69 @@ pub struct BinanceResilience {
 }
 
 impl BinanceResilience {
+    /// 按 ExchangeType 选择限流额度和权重，合约 / 币本位 / 统一账户共用合约配置
+    pub fn for_exchange_type(exchange_type: &ExchangeType) -> Self {
+        match exchange_type {
+            ExchangeType::Spot => Self::new_spot(),
+            ExchangeType::Futures | ExchangeType::Inverse | ExchangeType::PortfolioMargin => {
+                Self::new_futures()
+            }
+        }
+    }
+
     pub fn new_spot() -> Self {
         let mut registries = ResilienceRegistries::new();
+        Self::register_retry_configs(&mut registries);
+        Self::register_circuit_breakers(&mut registries);
 
+        // Binance 按固定窗口计数
         registries.rate_limiters.insert(
             REQUEST_WEIGHT_RATE_LIMITER.into(),
-            Arc::new(RateLimiter::new(6000, Duration::from_secs(60))),
+            Arc::new(RateLimiter::fixed_window(6000, Duration::from_secs(60))),
         );
         registries.rate_limiters.insert(
             ORDERS_PER_SECOND.into(),
-            Arc::new(RateLimiter::new(10, Duration::from_secs(1))),
+            Arc::new(RateLimiter::fixed_window(10, Duration::from_secs(1))),
         );
         registries.rate_limiters.insert(
-            REQUEST_WEIGHT_RATE_LIMITER.into(),
-            Arc::new(RateLimiter::new(61000, Duration::from_secs(5 * 60))),
+            ORDERS_PER_10_SECONDS.into(),
+            Arc::new(RateLimiter::fixed_window(100, Duration::from_secs(10))),
+        );
+        registries.rate_limiters.insert(
+            RAW_REQUESTS.into(),
+            Arc::new(RateLimiter::fixed_window(
+                61000,
+                Duration::from_secs(5 * 60),
+            )),
         );
 
+        registries.set_weight(ENDPOINT_PING, 1);
+        registries.set_weight(ENDPOINT_TIME, 1);
+        registries.set_weight(ENDPOINT_EXCHANGE_INFO, 20);
+        registries.set_weight(ENDPOINT_KLINES, 2);
+        registries.set_weight(ENDPOINT_SYSTEM_STATUS, 1);
+        registries.set_weight(ENDPOINT_LISTEN_KEY, 2);
+        registries.set_weight(ENDPOINT_ORDER, 1);
+
         Self { registries }
     }
 
     pub fn new_futures() -> Self {
         let mut registries = ResilienceRegistries::new();
+        Self::register_retry_configs(&mut registries);
+        Self::register_circuit_breakers(&mut registries);
 
         registries.rate_limiters.insert(
             REQUEST_WEIGHT_RATE_LIMITER.into(),
-            Arc::new(RateLimiter::new(2400, Duration::from_secs(60))),
+            Arc::new(RateLimiter::fixed_window(2400, Duration::from_secs(60))),
         );
         registries.rate_limiters.insert(
             ORDERS_PER_10_SECONDS.into(),
-            Arc::new(RateLimiter::new(300, Duration::from_secs(10))),
+            Arc::new(RateLimiter::fixed_window(300, Duration::from_secs(10))),
         );
         registries.rate_limiters.insert(
             ORDERS_PER_MINUTE.into(),
-            Arc::new(RateLimiter::new(1200, Duration::from_secs(60))),
+            Arc::new(RateLimiter::fixed_window(1200, Duration::from_secs(60))),
         );
 
         // Spot limiters unlimited for compatibility
@@ -66,6 +123,257 @@ impl BinanceResilience {
             Arc::new(RateLimiter::new(u32::MAX as usize, Duration::from_secs(1))),
         );
 
+        registries.set_weight(ENDPOINT_PING, 1);
+        registries.set_weight(ENDPOINT_TIME, 1);
+        registries.set_weight(ENDPOINT_EXCHANGE_INFO, 1);
+        registries.set_weight(ENDPOINT_KLINES, Self::futures_klines_weight(500));
+        registries.set_weight(ENDPOINT_SYSTEM_STATUS, 1);
+        registries.set_weight(ENDPOINT_LISTEN_KEY, 1);
+        registries.set_weight(ENDPOINT_ORDER, 1);
+
         Self { registries }
     }
+
+    fn register_retry_configs(registries: &mut ResilienceRegistries) {
+        let idempotent = Arc::new(
+            RetryConfig::new(3, Duration::from_millis(200), 2.0)
+                .with_max_delay(Duration::from_secs(10))
+                .with_predicate(Self::transient_errors),
+        );
+        for name in [
+            ResilienceRegistries::DEFAULT_RETRY,
+            REQUEST_WEIGHT_RATE_LIMITER,
+            TIME_RETRY,
+        ] {
+            registries
+                .retry_configs
+                .insert(name.into(), idempotent.clone());
+        }
+
+        registries.retry_configs.insert(
+            ResilienceRegistries::NON_IDEMPOTENT.into(),
+            Arc::new(
+                RetryConfig::new(3, Duration::from_millis(200), 2.0)
+                    .with_max_delay(Duration::from_secs(10))
+                    .with_predicate(Self::not_sent_errors),
+            ),
+        );
+    }
+
+    /// 每个 endpoint 分组一个熔断器和并发隔离。
+    /// 下单不等待空位，宁可快速失败也不要发出过期的订单。
+    fn register_circuit_breakers(registries: &mut ResilienceRegistries) {
+        for (group, max_concurrent_calls, max_wait) in [
+            (MARKET_DATA_GROUP, 64, Duration::from_secs(1)),
+            (TRADE_GROUP, 16, Duration::ZERO),
+            (ACCOUNT_GROUP, 16, Duration::from_secs(1)),
+            (USER_DATA_STREAM_GROUP, 4, Duration::from_secs(1)),
+        ] {
+            registries.circuit_breakers.insert(
+                group.into(),
+                Arc::new(CircuitBreaker::new(
+                    group,
+                    CircuitBreakerConfig {
+                        failure_predicate: Arc::new(|e| {
+                            Self::transient_errors(e) != RetryDecision::Abort
+                        }),
+                        ..Default::default()
+                    },
+                )),
+            );
+            registries.bulkheads.insert(
+                group.into(),
+                Arc::new(Bulkhead::new(group, max_concurrent_calls, max_wait)),
+            );
+        }
+    }
+
+    /// 在核心的瞬时错误之外，按 Binance 错误码判断：
+    /// -1001 连接断开、-1003 请求过多、-1008 服务繁忙、-1016 服务下线可重试
+    pub fn transient_errors(error: &(dyn Error + Send + Sync + 'static)) -> RetryDecision {
+        match Self::binance_exception(error) {
+            Some(e) => match e.code {
+                -1001 | -1003 | -1008 | -1016 => RetryDecision::Retry,
+                _ => RetryDecision::Abort,
+            },
+            None => RetryConfig::transient_errors(error),
+        }
+    }
+
+    /// 非幂等请求只在被限流拒绝（-1003 / -1015，订单未被处理）时重试
+    pub fn not_sent_errors(error: &(dyn Error + Send + Sync + 'static)) -> RetryDecision {
+        match Self::binance_exception(error) {
+            Some(e) => match e.code {
+                -1003 | -1015 => RetryDecision::Retry,
+                _ => RetryDecision::Abort,
+            },
+            None => RetryConfig::not_sent_errors(error),
+        }
+    }
+
+    pub(crate) fn binance_exception<'a>(
+        error: &'a (dyn Error + 'static),
+    ) -> Option<&'a BinanceException> {
+        let mut current = Some(error);
+        while let Some(e) = current {
+            if let Some(e) = e.downcast_ref::<BinanceException>() {
+                return Some(e);
+            }
+            current = e.source();
+        }
+        None
+    }
+
+    /// 合约 K 线的权重随 limit 变化
+    pub fn futures_klines_weight(limit: u16) -> u32 {
+        match limit {
+            0..100 => 1,
+            100..500 => 2,
+            500..=1000 => 5,
+            _ => 10,
+        }
+    }
+}
+
+/// ========================
+/// 服务端限流用量（X-MBX-USED-WEIGHT-* / X-MBX-ORDER-COUNT-*）
+/// ========================
+pub const USED_WEIGHT_HEADER_PREFIX: &'static str = "x-mbx-used-weight-";
+pub const ORDER_COUNT_HEADER_PREFIX: &'static str = "x-mbx-order-count-";
+pub const RETRY_AFTER_HEADER: &'static str = "retry-after";
+
+/// 最近一次响应头中的服务端用量，key 为区间（如 `1m`、`10s`、`1d`）
+#[derive(Debug, Clone, Default)]
+pub struct BinanceRateLimitUsage {
+    pub used_weight: HashMap<String, u64>,
+    pub order_count: HashMap<String, u64>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl BinanceRateLimitUsage {
+    /// 1 分钟内已用的请求权重
+    pub fn used_weight_1m(&self) -> Option<u64> {
+        self.used_weight.get("1m").copied()
+    }
+}
+
+/// 记录每次响应的限流头，并据此校正 `BinanceResilience` 中对应的 `RateLimiter`。
+///
+/// 多个进程共用同一 IP 时，只有服务端的计数是准确的。
+#[derive(Debug)]
+pub struct BinanceRateLimitTracker {
+    registries: RwLock<Arc<ResilienceRegistries>>,
+    usage: RwLock<BinanceRateLimitUsage>,
+    last_headers: RwLock<HashMap<String, Vec<String>>>,
+}
+
+impl BinanceRateLimitTracker {
+    pub fn new(registries: Arc<ResilienceRegistries>) -> Self {
+        Self {
+            registries: RwLock::new(registries),
+            usage: RwLock::new(BinanceRateLimitUsage::default()),
+            last_headers: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 注册表重建后需要重新绑定
+    pub fn set_registries(&self, registries: Arc<ResilienceRegistries>) {
+        *self.registries.write() = registries;
+    }
+
+    pub fn usage(&self) -> BinanceRateLimitUsage {
+        self.usage.read().clone()
+    }
+
+    /// 最近一次响应的全部响应头，header 名为小写
+    pub fn last_headers(&self) -> HashMap<String, Vec<String>> {
+        self.last_headers.read().clone()
+    }
+
+    /// 处理一次响应的响应头
+    pub fn record<'a, I>(&self, headers: I)
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut all: HashMap<String, Vec<String>> = HashMap::new();
+        let mut used_weight = HashMap::new();
+        let mut order_count = HashMap::new();
+
+        for (name, value) in headers {
+            let name = name.to_ascii_lowercase();
+            all.entry(name.clone()).or_default().push(value.to_string());
+
+            let Ok(count) = value.trim().parse::<u64>() else {
+                continue;
+            };
+            if let Some(interval) = name.strip_prefix(USED_WEIGHT_HEADER_PREFIX) {
+                used_weight.insert(interval.to_string(), count);
+            } else if let Some(interval) = name.strip_prefix(ORDER_COUNT_HEADER_PREFIX) {
+                order_count.insert(interval.to_string(), count);
+            }
+        }
+
+        let retry_after = all
+            .get(RETRY_AFTER_HEADER)
+            .and_then(|values| values.first())
+            .and_then(|value| HttpStatusError::parse_retry_after(value));
+        *self.last_headers.write() = all;
+
+        let registries = self.registries.read().clone();
+        // 429 / 418 时服务端返回 Retry-After，在此之前不再发出请求
+        if let Some(retry_after) = retry_after
+            && let Some(limiter) = registries.rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        {
+            limiter.pause_for(retry_after);
+        }
+        if used_weight.is_empty() && order_count.is_empty() {
+            return;
+        }
+
+        for (interval, used) in &used_weight {
+            Self::sync(&registries, &[REQUEST_WEIGHT_RATE_LIMITER], interval, *used);
+        }
+        for (interval, count) in &order_count {
+            Self::sync(
+                &registries,
+                &[ORDERS_PER_SECOND, ORDERS_PER_10_SECONDS, ORDERS_PER_MINUTE],
+                interval,
+                *count,
+            );
+        }
+
+        let mut usage = self.usage.write();
+        usage.used_weight.extend(used_weight);
+        usage.order_count.extend(order_count);
+        usage.updated_at = Some(Utc::now());
+    }
+
+    /// 校正区间与 header 一致的限流器
+    fn sync(registries: &ResilienceRegistries, names: &[&str], interval: &str, used: u64) {
+        let Some(period) = Self::parse_interval(interval) else {
+            return;
+        };
+        for name in names {
+            if let Some(limiter) = registries.rate_limiter(name)
+                && limiter.period() == period
+            {
+                limiter.sync_used(used);
+            }
+        }
+    }
+
+    /// "1m" / "10s" / "1h" / "1d" → Duration
+    pub fn parse_interval(interval: &str) -> Option<Duration> {
+        let interval = interval.trim().to_ascii_lowercase();
+        let unit = interval.chars().last()?;
+        let num: u64 = interval[..interval.len() - unit.len_utf8()].parse().ok()?;
+        let secs = match unit {
+            's' => num,
+            'm' => num * 60,
+            'h' => num * 60 * 60,
+            'd' => num * 24 * 60 * 60,
+            _ => return None,
+        };
+        Some(Duration::from_secs(secs))
+    }
 }
@@ -1,19 +1,73 @@
-use crate::dto::BinanceError;
+use crate::binance_resilience::{
+    BinanceResilience, ENDPOINT_TIME, REQUEST_WEIGHT_RATE_LIMITER, TIME_RETRY,
+};
 use crate::dto::meta::binance_system::BinanceTime;
-use futures::future::FutureExt;
-use std::sync::Arc;
+use crate::dto::{BinanceError, BinanceException};
+use futures::future::{BoxFuture, FutureExt};
+use parking_lot::{Mutex, RwLock};
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
 use std::time::{Duration, SystemTime};
-use tracing::trace;
+use tokio::task::JoinHandle;
+use tracing::{trace, warn};
 use xchange_core::ValueFactory;
-use xchange_core::client::{ResilienceRegistries, ResilientCall};
+use xchange_core::client::{ResiliencePolicy, ResilienceRegistries, ResilientCall};
 use xchange_core::exchange_specification::ResilienceSpecification;
 
-#[derive(Debug)]
+/// 获取服务器时间（`/api/v3/time`、`/fapi/v1/time` 等）
+pub type ServerTimeFetcher =
+    Arc<dyn Fn() -> BoxFuture<'static, Result<BinanceTime, BinanceError>> + Send + Sync>;
+
+/// 每次同步的采样次数，取往返时间最短的一次
+pub const TIME_SYNC_SAMPLES: usize = 3;
+
+/// 时间差有效期，过期后按本地时间签名
+pub const TIME_SYNC_TTL: Duration = Duration::from_secs(600);
+
+/// 一次采样得到的本地时钟偏移
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockOffset {
+    /// 服务器时间 - 本地时间（毫秒）
+    pub offset_millis: i64,
+    /// 采样请求的往返时间（毫秒）
+    pub rtt_millis: i64,
+    /// 有效期截止时间
+    pub expire: SystemTime,
+}
+
+impl ClockOffset {
+    /// 按请求发出 / 收到响应时的本地时间估算偏移，假设服务器时间位于往返的中点
+    pub fn sample(sent_millis: i64, server_time_millis: i64, received_millis: i64) -> Self {
+        let rtt_millis = (received_millis - sent_millis).max(0);
+        Self {
+            offset_millis: server_time_millis - (sent_millis + rtt_millis / 2),
+            rtt_millis,
+            expire: SystemTime::now() + TIME_SYNC_TTL,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() >= self.expire
+    }
+}
+
+/// 签名用的时间戳。
+///
+/// 定期采样服务器时间估算本地时钟偏移，`create()` 返回校正后的时间；
+/// 请求返回 -1021（timestamp 超出 recvWindow）时可通过 `with_resync` 立即重新同步并重试一次。
 pub struct BinanceTimeProvider {
-    resilience_specification: ResilienceSpecification,
-    registries: Arc<ResilienceRegistries>,
-    delta_server_time: Option<i64>,
-    delta_server_time_expire: Option<SystemTime>,
+    /// apply_specification 时随 exchange 一起更新
+    resilience_specification: RwLock<ResilienceSpecification>,
+    registries: RwLock<Arc<ResilienceRegistries>>,
+    fetcher: RwLock<Option<ServerTimeFetcher>>,
+    offset: RwLock<Option<ClockOffset>>,
+    /// 每次设置时钟偏移加一，等待 `sync_lock` 期间已有新的偏移时不再重复同步
+    generation: AtomicU64,
+    /// 并发的 -1021 只触发一次同步
+    sync_lock: tokio::sync::Mutex<()>,
+    refresh_task: Mutex<Option<JoinHandle<()>>>,
 }
 
 impl BinanceTimeProvider {
@@ -22,13 +76,30 @@ impl BinanceTimeProvider {
         registries: Arc<ResilienceRegistries>,
     ) -> Self {
         Self {
-            resilience_specification,
-            registries,
-            delta_server_time: None,
-            delta_server_time_expire: None,
+            resilience_specification: RwLock::new(resilience_specification),
+            registries: RwLock::new(registries),
+            fetcher: RwLock::new(None),
+            offset: RwLock::new(None),
+            generation: AtomicU64::new(0),
+            sync_lock: tokio::sync::Mutex::new(()),
+            refresh_task: Mutex::new(None),
         }
     }
 
+    pub fn set_resilience(
+        &self,
+        resilience_specification: ResilienceSpecification,
+        registries: Arc<ResilienceRegistries>,
+    ) {
+        *self.resilience_specification.write() = resilience_specification;
+        *self.registries.write() = registries;
+    }
+
+    /// 设置 `sync()` / 后台刷新使用的服务器时间接口
+    pub fn set_server_time_fetcher(&self, fetcher: ServerTimeFetcher) {
+        *self.fetcher.write() = Some(fetcher);
+    }
+
     /// 对应 Java createValue()，返回当前系统时间毫秒
     pub fn create_value(&self) -> Result<i64, BinanceError> {
         let duration = SystemTime::now()
@@ -37,94 +108,208 @@ impl BinanceTimeProvider {
         Ok(duration.as_millis() as i64)
     }
 
+    /// 未过期的时钟偏移
+    pub fn offset(&self) -> Option<ClockOffset> {
+        self.offset.read().filter(|offset| !offset.is_expired())
+    }
+
+    /// 直接设置时钟偏移，用于外部校时
+    pub fn set_offset(&self, offset: ClockOffset) {
+        let mut current = self.offset.write();
+        *current = Some(offset);
+        self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
     /// 清空 delta，强制下一次刷新
-    pub fn clear_delta_server_time(&mut self) {
-        self.delta_server_time = None;
-        self.delta_server_time_expire = None;
+    pub fn clear_delta_server_time(&self) {
+        *self.offset.write() = None;
+    }
+
+    /// 返回服务器时间：有未过期的时间差时直接推算，否则通过 `fetch` 重新同步
+    pub async fn delta_server_time<F, Fut>(&self, fetch: F) -> Result<i64, BinanceError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<BinanceTime, BinanceError>> + Send + 'static,
+    {
+        let offset = match self.offset() {
+            Some(offset) => offset,
+            None => self.sync_with(fetch).await?,
+        };
+        Ok(self.create_value()? + offset.offset_millis)
+    }
+
+    /// 使用已设置的服务器时间接口同步
+    pub async fn sync(&self) -> Result<ClockOffset, BinanceError> {
+        self.sync_after(self.generation.load(Ordering::SeqCst))
+            .await
+    }
+
+    /// 采样 `TIME_SYNC_SAMPLES` 次，取往返时间最短的一次作为时钟偏移。
+    /// 等待其他同步完成后，如果已有新的未过期偏移则直接返回
+    pub async fn sync_with<F, Fut>(&self, fetch: F) -> Result<ClockOffset, BinanceError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<BinanceTime, BinanceError>> + Send + 'static,
+    {
+        self.sync_with_after(self.generation.load(Ordering::SeqCst), fetch)
+            .await
+    }
+
+    /// 使用已设置的服务器时间接口，在 `generation` 之后没有新的偏移时才同步
+    async fn sync_after(&self, generation: u64) -> Result<ClockOffset, BinanceError> {
+        let fetcher = self
+            .fetcher
+            .read()
+            .clone()
+            .ok_or_else(|| BinanceError::ClientNotInitialized("server time fetcher".into()))?;
+        self.sync_with_after(generation, move || fetcher()).await
     }
 
-    /// 异步获取服务器时间并缓存 delta
-    pub async fn delta_server_time<F, Fut>(&mut self, fetch: F) -> Result<i64, BinanceError>
+    async fn sync_with_after<F, Fut>(
+        &self,
+        generation: u64,
+        fetch: F,
+    ) -> Result<ClockOffset, BinanceError>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<BinanceTime, BinanceError>> + Send + 'static,
     {
-        let now = SystemTime::now();
-        if let (Some(delta), Some(expire)) = (self.delta_server_time, self.delta_server_time_expire)
+        let fetch = Arc::new(fetch);
+        let _guard = self.sync_lock.lock().await;
+        if self.generation.load(Ordering::SeqCst) != generation
+            && let Some(offset) = self.offset()
         {
-            if now < expire {
-                return Ok(delta
-                    + now
-                        .duration_since(SystemTime::UNIX_EPOCH)
-                        .unwrap()
-                        .as_millis() as i64);
-            }
+            return Ok(offset);
         }
 
-        let binance_time = self.binance_time(fetch).await?;
-        let server_time_millis = binance_time.server_time;
+        let mut best: Option<ClockOffset> = None;
+        for _ in 0..TIME_SYNC_SAMPLES {
+            let sent = self.create_value()?;
+            let binance_time = self.binance_time(fetch.clone()).await?;
+            let received = self.create_value()?;
 
-        let system_millis = now
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as i64;
-
-        // 缓存 10 分钟
-        self.delta_server_time = Some(server_time_millis - system_millis);
-        self.delta_server_time_expire = Some(now + Duration::from_secs(600));
+            let sample = ClockOffset::sample(sent, binance_time.server_time, received);
+            if best.is_none_or(|best| sample.rtt_millis < best.rtt_millis) {
+                best = Some(sample);
+            }
+        }
 
-        // trace log
+        let offset = best.expect("TIME_SYNC_SAMPLES > 0");
+        self.set_offset(offset);
         trace!(
-            "delta_server_time updated: server={} system={} delta={}",
-            server_time_millis,
-            system_millis,
-            self.delta_server_time.unwrap()
+            "clock offset updated: offset={}ms rtt={}ms",
+            offset.offset_millis, offset.rtt_millis
         );
+        Ok(offset)
+    }
 
-        Ok(server_time_millis)
+    /// 后台按 `interval` 刷新时钟偏移（立即执行第一次），替换之前的刷新任务。
+    /// 需要在 tokio runtime 中调用，provider 释放后任务自动结束
+    pub fn start_refresh(self: &Arc<Self>, interval: Duration) {
+        let provider: Weak<Self> = Arc::downgrade(self);
+        let task = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(provider) = provider.upgrade() else {
+                    break;
+                };
+                if let Err(e) = provider.sync().await {
+                    warn!("sync server time failed: {}", e);
+                }
+            }
+        });
+        if let Some(previous) = self.refresh_task.lock().replace(task) {
+            previous.abort();
+        }
     }
 
-    /// 核心 Resilience 调用（限流 + 重试）
-    async fn binance_time<F, Fut>(&self, fetch: F) -> Result<BinanceTime, BinanceError>
+    pub fn stop_refresh(&self) {
+        if let Some(task) = self.refresh_task.lock().take() {
+            task.abort();
+        }
+    }
+
+    /// 执行签名请求，返回 -1021 时重新同步时间并重试一次
+    pub async fn with_resync<T, F, Fut>(&self, call: F) -> Result<T, BinanceError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, BinanceError>>,
+    {
+        // 请求发出前的偏移版本，其他请求已经重新同步过时直接重试
+        let generation = self.generation.load(Ordering::SeqCst);
+        match call().await {
+            Err(e) if Self::is_timestamp_error(&e) => {
+                warn!("{}, resync server time and retry", e);
+                if let Err(sync_error) = self.sync_after(generation).await {
+                    warn!("sync server time failed: {}", sync_error);
+                    return Err(e);
+                }
+                call().await
+            }
+            result => result,
+        }
+    }
+
+    /// 是否为 -1021（timestamp 超出 recvWindow）
+    pub fn is_timestamp_error(error: &(dyn Error + 'static)) -> bool {
+        BinanceResilience::binance_exception(error)
+            .is_some_and(|e| e.code == BinanceException::TIMESTAMP_OUTSIDE_RECV_WINDOW)
+    }
+
+    /// 核心 Resilience 调用，按 ResilienceSpecification 决定是否限流 / 重试
+    async fn binance_time<F, Fut>(&self, fetch: Arc<F>) -> Result<BinanceTime, BinanceError>
     where
         F: Fn() -> Fut + Send + Sync + 'static,
         Fut: Future<Output = Result<BinanceTime, BinanceError>> + Send + 'static,
     {
-        // 使用 Arc 包装 fetch，避免 move 错误
-        let fetch = Arc::new(fetch);
-
-        let retry_cfg = self
-            .registries
-            .retry("time")
-            .ok_or(BinanceError::RetryConfigNotFound)?;
-        let limiter = self.registries.rate_limiter("REQUEST_WEIGHT");
+        let spec = *self.resilience_specification.read();
+        let registries = self.registries.read().clone();
 
-        let mut call: ResilientCall<BinanceTime> = ResilientCall::new({
+        let call: ResilientCall<BinanceTime> = ResilientCall::new(move || {
             let fetch = fetch.clone();
-            move || {
-                let fetch = fetch.clone();
-                async move {
-                    fetch()
-                        .await
-                        .map_err(|e| Box::<dyn std::error::Error + Send + Sync>::from(e))
-                }
-                .boxed()
+            async move {
+                fetch()
+                    .await
+                    .map_err(Box::<dyn std::error::Error + Send + Sync>::from)
             }
-        })
-        .with_retry(retry_cfg);
+            .boxed()
+        });
+        let call = registries.decorate(
+            call,
+            &spec,
+            &ResiliencePolicy::new()
+                .retry(TIME_RETRY)
+                .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+                .endpoint(ENDPOINT_TIME),
+        );
 
-        if let Some(limiter) = limiter {
-            call = call.with_rate_limiter(limiter);
-        }
+        call.call().await.map_err(BinanceError::TimeProvider)
+    }
+}
+
+impl fmt::Debug for BinanceTimeProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinanceTimeProvider")
+            .field("resilience_specification", &self.resilience_specification)
+            .field("offset", &self.offset)
+            .finish_non_exhaustive()
+    }
+}
 
-        call.call().await.map_err(|e| BinanceError::TimeProvider(e))
+impl Drop for BinanceTimeProvider {
+    fn drop(&mut self) {
+        self.stop_refresh();
     }
 }
 
 impl ValueFactory<u64> for BinanceTimeProvider {
+    /// 签名用的 timestamp，有未过期的时钟偏移时按服务器时间校正
     fn create(&self) -> u64 {
+        let offset = self.offset().map_or(0, |offset| offset.offset_millis);
         self.create_value()
-            .map(|v| v.max(0) as u64) // i64 转 u64，如果负数则取 0
+            .map(|v| (v + offset).max(0) as u64) // i64 转 u64，如果负数则取 0
             .unwrap_or(0)
     }
 }
@@ -1,13 +1,24 @@
+use crate::client::binance_http::BinanceHttpClient;
+use crate::dto::meta::binance_system::BinanceTime;
 use crate::dto::meta::exchange_info::BinanceExchangeInfo;
-use retrofit_rs::{Query, Retrofit, RetrofitError, api, get};
+use crate::dto::trade::binance_user_data_event::BinanceListenKey;
+use async_trait::async_trait;
+use retrofit_rs::{Query, RetrofitError};
 
-#[api("https://fapi.binance.com")]
+#[async_trait]
 pub trait BinanceFuturesAuthed {
+    /// Get server time
+    async fn time(&self) -> Result<BinanceTime, RetrofitError>;
+
+    /// COIN-M server time
+    async fn inverse_time(&self) -> Result<BinanceTime, RetrofitError>;
+
     /// Exchange info
-    #[get("/fapi/v1/exchangeInfo")]
     async fn exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError>;
 
-    #[get("/fapi/v1/klines")]
+    /// COIN-M exchange info
+    async fn inverse_exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError>;
+
     #[allow(non_snake_case)]
     async fn klines(
         &self,
@@ -17,10 +28,107 @@ pub trait BinanceFuturesAuthed {
         startTime: Query<u64>,
         endTime: Query<u64>,
     ) -> Result<Vec<Vec<serde_json::Value>>, RetrofitError>;
+
+    /// USDT-M listenKey：已存在时返回同一个 key 并续期
+    async fn start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError>;
+
+    async fn keep_alive_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError>;
+
+    async fn close_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError>;
+
+    /// COIN-M listenKey
+    async fn inverse_start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError>;
+
+    async fn inverse_keep_alive_user_data_stream(&self)
+    -> Result<serde_json::Value, RetrofitError>;
+
+    async fn inverse_close_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError>;
+}
+
+/// USDT-M / COIN-M REST client，请求由 `BinanceHttpClient` 的 transport 发送
+#[derive(Debug, Clone)]
+pub struct BinanceFuturesAuthedClient {
+    client: BinanceHttpClient,
 }
 
 impl BinanceFuturesAuthedClient {
-    pub fn retrofit(&self) -> &Retrofit {
+    pub fn new(client: BinanceHttpClient) -> Self {
+        Self { client }
+    }
+
+    pub fn http_client(&self) -> &BinanceHttpClient {
         &self.client
     }
 }
+
+#[async_trait]
+impl BinanceFuturesAuthed for BinanceFuturesAuthedClient {
+    async fn time(&self) -> Result<BinanceTime, RetrofitError> {
+        self.client.execute("GET", "/fapi/v1/time", &[]).await
+    }
+
+    async fn inverse_time(&self) -> Result<BinanceTime, RetrofitError> {
+        self.client.execute("GET", "/dapi/v1/time", &[]).await
+    }
+
+    async fn exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError> {
+        self.client
+            .execute("GET", "/fapi/v1/exchangeInfo", &[])
+            .await
+    }
+
+    async fn inverse_exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError> {
+        self.client
+            .execute("GET", "/dapi/v1/exchangeInfo", &[])
+            .await
+    }
+
+    #[allow(non_snake_case)]
+    async fn klines(
+        &self,
+        symbol: Query<&str>,
+        interval: Query<&str>,
+        limit: Query<u16>,
+        startTime: Query<u64>,
+        endTime: Query<u64>,
+    ) -> Result<Vec<Vec<serde_json::Value>>, RetrofitError> {
+        let query = [
+            ("symbol", symbol.to_string()),
+            ("interval", interval.to_string()),
+            ("limit", limit.to_string()),
+            ("startTime", startTime.to_string()),
+            ("endTime", endTime.to_string()),
+        ];
+        self.client.execute("GET", "/fapi/v1/klines", &query).await
+    }
+
+    async fn start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError> {
+        self.client.execute("POST", "/fapi/v1/listenKey", &[]).await
+    }
+
+    async fn keep_alive_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError> {
+        self.client.execute("PUT", "/fapi/v1/listenKey", &[]).await
+    }
+
+    async fn close_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError> {
+        self.client
+            .execute("DELETE", "/fapi/v1/listenKey", &[])
+            .await
+    }
+
+    async fn inverse_start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError> {
+        self.client.execute("POST", "/dapi/v1/listenKey", &[]).await
+    }
+
+    async fn inverse_keep_alive_user_data_stream(
+        &self,
+    ) -> Result<serde_json::Value, RetrofitError> {
+        self.client.execute("PUT", "/dapi/v1/listenKey", &[]).await
+    }
+
+    async fn inverse_close_user_data_stream(&self) -> Result<serde_json::Value, RetrofitError> {
+        self.client
+            .execute("DELETE", "/dapi/v1/listenKey", &[])
+            .await
+    }
+}
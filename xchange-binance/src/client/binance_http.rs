@@ -0,0 +1,94 @@
+use retrofit_rs::RetrofitError;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+use url::Url;
+use xchange_core::rescu::HttpError;
+use xchange_core::rescu::transport::{HttpRequest, HttpTransport};
+
+/// Binance REST 请求的执行器：拼接 URL 和 query，交给 `HttpTransport` 发送并解析响应。
+///
+/// API key、签名和限流头由 transport 上的中间件处理，非 2xx 响应转换为 `RetrofitError::HttpError`
+#[derive(Debug, Clone)]
+pub struct BinanceHttpClient {
+    base_url: String,
+    transport: Arc<dyn HttpTransport>,
+}
+
+impl BinanceHttpClient {
+    pub fn new(base_url: impl Into<String>, transport: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            transport,
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn transport(&self) -> &Arc<dyn HttpTransport> {
+        &self.transport
+    }
+
+    /// 发送请求并把响应体解析为 `T`，query 按传入顺序编码
+    pub async fn execute<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<T, RetrofitError> {
+        let raw_url = format!("{}{}", self.base_url, path);
+        let mut url = Url::parse(&raw_url).map_err(|e| RetrofitError::UrlParseError {
+            url: raw_url,
+            message: e.to_string(),
+        })?;
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        let request = HttpRequest::new(method, url);
+        let method = request.method.clone();
+        let url = request.url.to_string();
+
+        let response = self
+            .transport
+            .execute(request)
+            .await
+            .map_err(|e| Self::retrofit_error(&method, &url, e))?;
+        if !response.is_success() {
+            let headers: HashMap<String, String> = response.headers.iter().cloned().collect();
+            return Err(RetrofitError::http_error_with_headers(
+                response.status,
+                method,
+                url,
+                headers,
+                Some(response.text()),
+            ));
+        }
+
+        response.json().map_err(|e| RetrofitError::JsonError {
+            context: format!("deserializing response from {}", url),
+            message: e.to_string(),
+        })
+    }
+
+    /// 超时转换为 TimeoutError，签名失败等本地错误转换为 RequestBuildError
+    fn retrofit_error(method: &str, url: &str, e: HttpError) -> RetrofitError {
+        match &e {
+            HttpError::Io(io_error) if io_error.kind() == io::ErrorKind::TimedOut => {
+                RetrofitError::timeout_error(method, url, Duration::ZERO)
+            }
+            HttpError::Io(_) => RetrofitError::connection_error(url, e.to_string()),
+            _ => RetrofitError::RequestBuildError {
+                method: method.to_string(),
+                path: url
+                    .split_once('?')
+                    .map_or(url, |(path, _)| path)
+                    .to_string(),
+                message: e.to_string(),
+            },
+        }
+    }
+}
@@ -1,26 +1,24 @@
+use crate::client::binance_http::BinanceHttpClient;
 use crate::dto::meta::binance_system::{BinanceSystemStatus, BinanceTime};
 use crate::dto::meta::exchange_info::BinanceExchangeInfo;
-use retrofit_rs::{Body, Header, Query, Retrofit, RetrofitError, api, get, post};
+use crate::dto::trade::binance_user_data_event::BinanceListenKey;
+use async_trait::async_trait;
+use retrofit_rs::{Query, RetrofitError};
 
-#[api("https://api.binance.com")]
+#[async_trait]
 pub trait BinanceAuthed {
     /// Fetch system status
-    #[get("/sapi/v1/system/status")]
     async fn system_status(&self) -> Result<BinanceSystemStatus, RetrofitError>;
 
     /// Ping
-    #[get("/api/v3/ping")]
     async fn ping(&self) -> Result<serde_json::Value, RetrofitError>;
 
     /// Get server time
-    #[get("/api/v3/time")]
     async fn time(&self) -> Result<BinanceTime, RetrofitError>;
 
     /// Exchange info
-    #[get("/api/v3/exchangeInfo")]
     async fn exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError>;
 
-    #[get("/api/v3/order")]
     async fn order_status(
         &self,
         symbol: Query<&str>,
@@ -30,7 +28,6 @@ pub trait BinanceAuthed {
         timestamp: Query<u64>,
     ) -> Result<serde_json::Value, RetrofitError>;
 
-    #[get("/api/v3/klines")]
     #[allow(non_snake_case)]
     async fn klines(
         &self,
@@ -41,11 +38,123 @@ pub trait BinanceAuthed {
         endTime: Query<u64>,
     ) -> Result<Vec<Vec<serde_json::Value>>, RetrofitError>;
 
-    // DELETE, PUT 等方法同理
+    /// 创建 user data stream 的 listenKey，只需要 X-MBX-APIKEY
+    async fn start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError>;
+
+    /// listenKey 续期（有效期 60 分钟）
+    #[allow(non_snake_case)]
+    async fn keep_alive_user_data_stream(
+        &self,
+        listenKey: Query<&str>,
+    ) -> Result<serde_json::Value, RetrofitError>;
+
+    #[allow(non_snake_case)]
+    async fn close_user_data_stream(
+        &self,
+        listenKey: Query<&str>,
+    ) -> Result<serde_json::Value, RetrofitError>;
+}
+
+/// Spot REST client，请求由 `BinanceHttpClient` 的 transport 发送
+#[derive(Debug, Clone)]
+pub struct BinanceAuthedClient {
+    client: BinanceHttpClient,
 }
 
 impl BinanceAuthedClient {
-    pub fn retrofit(&self) -> &Retrofit {
+    pub fn new(client: BinanceHttpClient) -> Self {
+        Self { client }
+    }
+
+    pub fn http_client(&self) -> &BinanceHttpClient {
         &self.client
     }
 }
+
+#[async_trait]
+impl BinanceAuthed for BinanceAuthedClient {
+    async fn system_status(&self) -> Result<BinanceSystemStatus, RetrofitError> {
+        self.client
+            .execute("GET", "/sapi/v1/system/status", &[])
+            .await
+    }
+
+    async fn ping(&self) -> Result<serde_json::Value, RetrofitError> {
+        self.client.execute("GET", "/api/v3/ping", &[]).await
+    }
+
+    async fn time(&self) -> Result<BinanceTime, RetrofitError> {
+        self.client.execute("GET", "/api/v3/time", &[]).await
+    }
+
+    async fn exchange_info(&self) -> Result<BinanceExchangeInfo, RetrofitError> {
+        self.client
+            .execute("GET", "/api/v3/exchangeInfo", &[])
+            .await
+    }
+
+    async fn order_status(
+        &self,
+        symbol: Query<&str>,
+        order_id: Query<u64>,
+        orig_client_order_id: Query<&str>,
+        recv_window: Query<u64>,
+        timestamp: Query<u64>,
+    ) -> Result<serde_json::Value, RetrofitError> {
+        let query = [
+            ("symbol", symbol.to_string()),
+            ("orderId", order_id.to_string()),
+            ("origClientOrderId", orig_client_order_id.to_string()),
+            ("recvWindow", recv_window.to_string()),
+            ("timestamp", timestamp.to_string()),
+        ];
+        self.client.execute("GET", "/api/v3/order", &query).await
+    }
+
+    #[allow(non_snake_case)]
+    async fn klines(
+        &self,
+        symbol: Query<&str>,
+        interval: Query<&str>,
+        limit: Query<u16>,
+        startTime: Query<u64>,
+        endTime: Query<u64>,
+    ) -> Result<Vec<Vec<serde_json::Value>>, RetrofitError> {
+        let query = [
+            ("symbol", symbol.to_string()),
+            ("interval", interval.to_string()),
+            ("limit", limit.to_string()),
+            ("startTime", startTime.to_string()),
+            ("endTime", endTime.to_string()),
+        ];
+        self.client.execute("GET", "/api/v3/klines", &query).await
+    }
+
+    async fn start_user_data_stream(&self) -> Result<BinanceListenKey, RetrofitError> {
+        self.client
+            .execute("POST", "/api/v3/userDataStream", &[])
+            .await
+    }
+
+    #[allow(non_snake_case)]
+    async fn keep_alive_user_data_stream(
+        &self,
+        listenKey: Query<&str>,
+    ) -> Result<serde_json::Value, RetrofitError> {
+        let query = [("listenKey", listenKey.to_string())];
+        self.client
+            .execute("PUT", "/api/v3/userDataStream", &query)
+            .await
+    }
+
+    #[allow(non_snake_case)]
+    async fn close_user_data_stream(
+        &self,
+        listenKey: Query<&str>,
+    ) -> Result<serde_json::Value, RetrofitError> {
+        let query = [("listenKey", listenKey.to_string())];
+        self.client
+            .execute("DELETE", "/api/v3/userDataStream", &query)
+            .await
+    }
+}
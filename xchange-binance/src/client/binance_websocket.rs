@@ -0,0 +1,298 @@
+use futures::stream::BoxStream;
+use futures::{SinkExt, StreamExt};
+use parking_lot::{Mutex, RwLock};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, trace, warn};
+
+/// 每个 stream 的广播缓冲区大小
+const CHANNEL_CAPACITY: usize = 1024;
+
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_millis(500);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(30);
+
+/// 后台连接任务的控制命令
+enum Command {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+    Close,
+}
+
+/// 订阅流中的单条数据：`Ok(data)` 或者丢失的消息数（消费者处理过慢）
+pub type StreamEvent = Result<Value, u64>;
+
+/// Combined stream 推送的消息格式：`{"stream":"btcusdt@trade","data":{...}}`
+#[derive(Debug, Deserialize)]
+struct CombinedStreamMessage {
+    stream: String,
+    data: Value,
+}
+
+struct Shared {
+    url: String,
+    channels: RwLock<HashMap<String, broadcast::Sender<Value>>>,
+    commands: Mutex<Option<mpsc::UnboundedSender<Command>>>,
+    next_id: AtomicU64,
+}
+
+impl Shared {
+    fn send(&self, command: Command) {
+        if let Some(tx) = self.commands.lock().as_ref() {
+            let _ = tx.send(command);
+        }
+    }
+
+    /// 最后一个订阅者离开时取消订阅
+    fn release(&self, stream: &str) {
+        let mut channels = self.channels.write();
+        if let Some(sender) = channels.get(stream)
+            && sender.receiver_count() == 0
+        {
+            channels.remove(stream);
+            drop(channels);
+            self.send(Command::Unsubscribe(vec![stream.to_string()]));
+        }
+    }
+}
+
+/// 订阅流被 drop 时自动释放对应 stream
+struct SubscriptionGuard {
+    shared: Arc<Shared>,
+    stream: String,
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        self.shared.release(&self.stream);
+    }
+}
+
+/// --------------------------
+/// BinanceWebSocketClient
+/// --------------------------
+///
+/// 基于 Binance combined stream（`<base>/stream`）的单连接多路复用客户端：
+/// - 首次订阅时建立连接，之后通过 `SUBSCRIBE` / `UNSUBSCRIBE` 增减 stream
+/// - 按 `stream` 字段把消息分发给对应订阅者
+/// - 断线后自动重连并重新订阅当前所有 stream
+pub struct BinanceWebSocketClient {
+    shared: Arc<Shared>,
+}
+
+impl BinanceWebSocketClient {
+    /// `base_url` 例如 `wss://stream.binance.com:9443`，自动补全 `/stream`
+    pub fn new(base_url: &str) -> Self {
+        let base_url = base_url.trim_end_matches('/');
+        let url = if base_url.ends_with("/stream") {
+            base_url.to_string()
+        } else {
+            format!("{}/stream", base_url)
+        };
+
+        Self {
+            shared: Arc::new(Shared {
+                url,
+                channels: RwLock::new(HashMap::new()),
+                commands: Mutex::new(None),
+                next_id: AtomicU64::new(1),
+            }),
+        }
+    }
+
+    /// combined stream 完整地址
+    pub fn url(&self) -> &str {
+        &self.shared.url
+    }
+
+    /// 当前已订阅的 stream 名称
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.shared.channels.read().keys().cloned().collect()
+    }
+
+    /// 订阅一个 stream（例如 `btcusdt@trade`），返回原始 `data` 字段流。
+    ///
+    /// 同一 stream 的多个订阅者共享一个上游订阅，全部 drop 后自动退订。
+    /// 需要在 tokio runtime 中调用。
+    pub fn subscribe(&self, stream: &str) -> BoxStream<'static, StreamEvent> {
+        let receiver = {
+            let mut channels = self.shared.channels.write();
+            match channels.get(stream) {
+                Some(sender) => sender.subscribe(),
+                None => {
+                    let (sender, receiver) = broadcast::channel(CHANNEL_CAPACITY);
+                    channels.insert(stream.to_string(), sender);
+                    receiver
+                }
+            }
+        };
+
+        self.ensure_connected();
+        self.shared
+            .send(Command::Subscribe(vec![stream.to_string()]));
+
+        let guard = SubscriptionGuard {
+            shared: self.shared.clone(),
+            stream: stream.to_string(),
+        };
+
+        futures::stream::unfold((receiver, guard), |(mut receiver, guard)| async move {
+            match receiver.recv().await {
+                Ok(value) => Some((Ok(value), (receiver, guard))),
+                Err(broadcast::error::RecvError::Lagged(n)) => Some((Err(n), (receiver, guard))),
+                Err(broadcast::error::RecvError::Closed) => None,
+            }
+        })
+        .boxed()
+    }
+
+    /// 主动退订，所有订阅者的流随之结束
+    pub fn unsubscribe(&self, stream: &str) {
+        if self.shared.channels.write().remove(stream).is_some() {
+            self.shared
+                .send(Command::Unsubscribe(vec![stream.to_string()]));
+        }
+    }
+
+    /// 关闭连接并结束所有订阅流
+    pub fn disconnect(&self) {
+        if let Some(tx) = self.shared.commands.lock().take() {
+            let _ = tx.send(Command::Close);
+        }
+        self.shared.channels.write().clear();
+    }
+
+    fn ensure_connected(&self) {
+        let mut commands = self.shared.commands.lock();
+        if commands.as_ref().is_some_and(|tx| !tx.is_closed()) {
+            return;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        *commands = Some(tx);
+        tokio::spawn(run(self.shared.clone(), rx));
+    }
+}
+
+impl Drop for BinanceWebSocketClient {
+    fn drop(&mut self) {
+        self.disconnect();
+    }
+}
+
+/// 后台连接任务：连接 → 重新订阅 → 收发消息，断线后指数退避重连
+async fn run(shared: Arc<Shared>, mut commands: mpsc::UnboundedReceiver<Command>) {
+    let mut delay = INITIAL_RECONNECT_DELAY;
+
+    loop {
+        let ws = match connect_async(shared.url.as_str()).await {
+            Ok((ws, _)) => {
+                debug!("websocket connected: {}", shared.url);
+                delay = INITIAL_RECONNECT_DELAY;
+                ws
+            }
+            Err(e) => {
+                warn!("websocket connect to {} failed: {}", shared.url, e);
+                if wait_reconnect(&mut commands, delay).await {
+                    return;
+                }
+                delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+                continue;
+            }
+        };
+
+        let (mut sink, mut source) = ws.split();
+
+        // 重连后恢复全部订阅
+        let streams: Vec<String> = shared.channels.read().keys().cloned().collect();
+        if !streams.is_empty() {
+            let payload = request(&shared, "SUBSCRIBE", streams);
+            if let Err(e) = sink.send(Message::Text(payload.into())).await {
+                warn!("websocket subscribe failed: {}", e);
+            }
+        }
+
+        loop {
+            tokio::select! {
+                command = commands.recv() => match command {
+                    Some(Command::Subscribe(streams)) => {
+                        let payload = request(&shared, "SUBSCRIBE", streams);
+                        if sink.send(Message::Text(payload.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Command::Unsubscribe(streams)) => {
+                        let payload = request(&shared, "UNSUBSCRIBE", streams);
+                        if sink.send(Message::Text(payload.into())).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Command::Close) | None => {
+                        let _ = sink.close().await;
+                        return;
+                    }
+                },
+                message = source.next() => match message {
+                    Some(Ok(Message::Text(text))) => dispatch(&shared, text.as_str()),
+                    Some(Ok(Message::Ping(payload))) => {
+                        let _ = sink.send(Message::Pong(payload)).await;
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        debug!("websocket closed by server: {:?}", frame);
+                        break;
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => {
+                        warn!("websocket read error: {}", e);
+                        break;
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        if wait_reconnect(&mut commands, delay).await {
+            return;
+        }
+    }
+}
+
+/// 等待重连，期间收到 Close 返回 true。
+/// 订阅类命令无需处理，重连后会按 channels 重新订阅。
+async fn wait_reconnect(commands: &mut mpsc::UnboundedReceiver<Command>, delay: Duration) -> bool {
+    let sleep = tokio::time::sleep(delay);
+    tokio::pin!(sleep);
+
+    loop {
+        tokio::select! {
+            _ = &mut sleep => return false,
+            command = commands.recv() => match command {
+                Some(Command::Close) | None => return true,
+                Some(_) => {}
+            },
+        }
+    }
+}
+
+fn request(shared: &Shared, method: &str, streams: Vec<String>) -> String {
+    let id = shared.next_id.fetch_add(1, Ordering::Relaxed);
+    json!({ "method": method, "params": streams, "id": id }).to_string()
+}
+
+fn dispatch(shared: &Shared, text: &str) {
+    match serde_json::from_str::<CombinedStreamMessage>(text) {
+        Ok(message) => {
+            if let Some(sender) = shared.channels.read().get(&message.stream) {
+                let _ = sender.send(message.data);
+            }
+        }
+        // SUBSCRIBE 等请求的响应：{"result":null,"id":1}
+        Err(_) => trace!("websocket non-stream message: {}", text),
+    }
+}
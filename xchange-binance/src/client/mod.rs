@@ -1,13 +1,30 @@
 use crate::binance_exchange::{FUTURES_URL, INVERSE_FUTURES_URL};
+use crate::binance_resilience::BinanceRateLimitTracker;
 use crate::client::binance_futures::BinanceFuturesAuthedClient;
+use crate::client::binance_http::BinanceHttpClient;
 use crate::client::binance_spot::BinanceAuthedClient;
-use retrofit_rs::async_client::interceptors::AuthInterceptor;
-use retrofit_rs::{Retrofit, RetrofitError};
+use crate::client::rate_limit_interceptor::RateLimitHeaderInterceptor;
+use crate::client::signature_interceptor::{BinanceRequestSigner, SignatureInterceptor};
+use crate::dto::BinanceError;
+use async_trait::async_trait;
 use std::sync::Arc;
 use xchange_core::exchange::ExchangeType;
+use xchange_core::rescu::HttpError;
+use xchange_core::rescu::client_config::HttpClientConfig;
+use xchange_core::rescu::transport::{
+    HttpMiddleware, HttpRequest, HttpResponse, HttpTransport, MiddlewareTransport, Next,
+    ReqwestTransport,
+};
+use xchange_core::utils::credentials::Secret;
 
 pub(crate) mod binance_futures;
+pub mod binance_http;
 pub mod binance_spot;
+pub mod binance_websocket;
+pub mod rate_limit_interceptor;
+pub mod signature_interceptor;
+
+pub const API_KEY_HEADER: &str = "X-MBX-APIKEY";
 
 pub struct BinanceClient {
     /// Spot API（带鉴权，默认会创建）
@@ -25,6 +42,10 @@ pub struct BinanceClientBuilder<'a> {
     base_url: &'a str,
     api_key: Option<&'a str>,
     exchange_type: ExchangeType,
+    rate_limit_tracker: Option<Arc<BinanceRateLimitTracker>>,
+    signer: Option<Arc<BinanceRequestSigner>>,
+    http_config: HttpClientConfig,
+    transport: Option<Arc<dyn HttpTransport>>,
 }
 
 impl<'a> BinanceClientBuilder<'a> {
@@ -34,6 +55,10 @@ impl<'a> BinanceClientBuilder<'a> {
             base_url,
             api_key: None,
             exchange_type: ExchangeType::Spot,
+            rate_limit_tracker: None,
+            signer: None,
+            http_config: HttpClientConfig::default(),
+            transport: None,
         }
     }
 
@@ -49,50 +74,77 @@ impl<'a> BinanceClientBuilder<'a> {
         self
     }
 
+    /// 记录响应中的 X-MBX-USED-WEIGHT-* / X-MBX-ORDER-COUNT-* 并校正限流器
+    pub fn rate_limit_tracker(mut self, tracker: Arc<BinanceRateLimitTracker>) -> Self {
+        self.rate_limit_tracker = Some(tracker);
+        self
+    }
+
+    /// 为声明了 timestamp 参数的 SIGNED / USER_DATA 请求签名
+    pub fn signer(mut self, signer: Arc<BinanceRequestSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// 代理、超时、连接池、keep-alive 和 User-Agent，应用到所有 client
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
+        self
+    }
+
+    /// 通过自定义的 transport 发送请求（中间件、fake 等），
+    /// 未设置时使用按 http_config 创建的 `ReqwestTransport`
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// 按 http_config 创建 HTTP client，spot / futures / inverse 共用连接池
+    pub fn http_client(config: &HttpClientConfig) -> Result<reqwest::Client, HttpError> {
+        ReqwestTransport::client(config)
+    }
+
     /// 构建客户端
-    pub fn build(self) -> Result<BinanceClient, RetrofitError> {
-        // 内部辅助函数：创建 client
-        fn make_client<T>(
-            base_url: &str,
-            api_key: Option<&str>,
-            constructor: impl Fn(Retrofit) -> T,
-        ) -> Result<Arc<T>, RetrofitError> {
-            let mut builder = Retrofit::builder().base_url(base_url);
-            if let Some(key) = api_key {
-                builder = builder.add_interceptor(AuthInterceptor::api_key("X-MBX-APIKEY", key));
-            }
-            let retrofit = builder.build()?;
-            Ok(Arc::new(constructor(retrofit)))
+    pub fn build(self) -> Result<BinanceClient, BinanceError> {
+        // 请求依次经过 API key、签名和限流头中间件，再由注入的 transport 发送，
+        // 未注入时使用按 http_config 创建的 reqwest client，spot / futures / inverse 共用连接池
+        let base: Arc<dyn HttpTransport> = match &self.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(ReqwestTransport::new(&self.http_config)?),
+        };
+        let mut transport = MiddlewareTransport::new(base);
+        if let Some(key) = self.api_key {
+            transport = transport.with(ApiKeyInterceptor(Secret::new(key)));
+        }
+        if let Some(signer) = &self.signer {
+            transport = transport.with(SignatureInterceptor::new(signer.clone()));
         }
+        if let Some(tracker) = &self.rate_limit_tracker {
+            transport = transport.with(RateLimitHeaderInterceptor::new(tracker.clone()));
+        }
+        let transport: Arc<dyn HttpTransport> = Arc::new(transport);
+        let http_client = |base_url: &str| BinanceHttpClient::new(base_url, transport.clone());
 
         // ---------------------
         // 1) Spot client
         // ---------------------
-        let spot = make_client(
-            self.base_url,
-            self.api_key,
-            BinanceAuthedClient::with_client,
-        )?;
+        let spot = Arc::new(BinanceAuthedClient::new(http_client(self.base_url)));
 
         // ---------------------
         // 2) Futures / Inverse client
         // ---------------------
         let (futures, futures_inverse) = match self.exchange_type {
             ExchangeType::Futures | ExchangeType::PortfolioMargin => (
-                Some(make_client(
+                Some(Arc::new(BinanceFuturesAuthedClient::new(http_client(
                     FUTURES_URL,
-                    self.api_key,
-                    BinanceFuturesAuthedClient::with_client,
-                )?),
+                )))),
                 None,
             ),
             ExchangeType::Inverse => (
                 None,
-                Some(make_client(
+                Some(Arc::new(BinanceFuturesAuthedClient::new(http_client(
                     INVERSE_FUTURES_URL,
-                    self.api_key,
-                    BinanceFuturesAuthedClient::with_client,
-                )?),
+                )))),
             ),
             ExchangeType::Spot => (None, None),
         };
@@ -104,3 +156,19 @@ impl<'a> BinanceClientBuilder<'a> {
         })
     }
 }
+
+/// 为每个请求加上 X-MBX-APIKEY
+#[derive(Debug)]
+struct ApiKeyInterceptor(Secret);
+
+#[async_trait]
+impl HttpMiddleware for ApiKeyInterceptor {
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, HttpError> {
+        next.run(request.header(API_KEY_HEADER, self.0.expose()))
+            .await
+    }
+}
@@ -0,0 +1,37 @@
+use crate::binance_resilience::BinanceRateLimitTracker;
+use async_trait::async_trait;
+use std::sync::Arc;
+use xchange_core::rescu::HttpError;
+use xchange_core::rescu::transport::{HttpMiddleware, HttpRequest, HttpResponse, Next};
+
+/// 把每个响应（包括错误响应）的响应头交给 `BinanceRateLimitTracker`
+#[derive(Debug)]
+pub struct RateLimitHeaderInterceptor {
+    tracker: Arc<BinanceRateLimitTracker>,
+}
+
+impl RateLimitHeaderInterceptor {
+    pub fn new(tracker: Arc<BinanceRateLimitTracker>) -> Self {
+        Self { tracker }
+    }
+}
+
+#[async_trait]
+impl HttpMiddleware for RateLimitHeaderInterceptor {
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, HttpError> {
+        let response = next.run(request).await?;
+
+        self.tracker.record(
+            response
+                .headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        );
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::dto::BinanceError;
+use async_trait::async_trait;
+use std::fmt;
+use std::sync::Arc;
+use xchange_core::ValueFactory;
+use xchange_core::rescu::HttpError;
+use xchange_core::rescu::params_digest::ParamsDigest;
+use xchange_core::rescu::transport::{HttpMiddleware, HttpRequest, HttpResponse, Next};
+
+pub const TIMESTAMP_PARAM: &str = "timestamp";
+pub const RECV_WINDOW_PARAM: &str = "recvWindow";
+pub const SIGNATURE_PARAM: &str = "signature";
+
+/// SIGNED / USER_DATA 请求签名。
+///
+/// 接口通过声明 `timestamp` query 参数表示需要签名，传入的值只是占位：
+/// 每次发送（包括重试）都会换成 timestamp factory 的当前时间，补上 `recvWindow`，
+/// 再对实际发送的 query string + body 签名并追加 `signature`。
+pub struct BinanceRequestSigner {
+    digest: Arc<dyn ParamsDigest + Send + Sync>,
+    timestamp_factory: Arc<dyn ValueFactory<u64> + Send + Sync>,
+    recv_window: Option<u64>,
+}
+
+impl BinanceRequestSigner {
+    pub fn new(
+        digest: Arc<dyn ParamsDigest + Send + Sync>,
+        timestamp_factory: Arc<dyn ValueFactory<u64> + Send + Sync>,
+    ) -> Self {
+        Self {
+            digest,
+            timestamp_factory,
+            recv_window: None,
+        }
+    }
+
+    /// 请求未携带 recvWindow（或为 0）时使用的值，None 使用服务端默认的 5000ms
+    pub fn recv_window(mut self, recv_window: Option<u64>) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// 返回签名后的 query string，不需要签名的请求返回 None。
+    /// `query` 为已编码的 query string（不含 `?`），`body` 为原始请求体
+    pub fn sign_query(
+        &self,
+        method: &str,
+        query: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<Option<String>, BinanceError> {
+        if !matches!(
+            method.to_ascii_uppercase().as_str(),
+            "GET" | "POST" | "PUT" | "DELETE"
+        ) {
+            return Err(BinanceError::InvalidParam(format!(
+                "unsupported method for signed request: {}",
+                method
+            )));
+        }
+
+        let pairs: Vec<&str> = query
+            .unwrap_or("")
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .collect();
+        if !pairs.iter().any(|pair| param_name(pair) == TIMESTAMP_PARAM) {
+            return Ok(None);
+        }
+
+        let has_recv_window = pairs.iter().any(|pair| {
+            param_name(pair) == RECV_WINDOW_PARAM && !matches!(param_value(pair), "" | "0")
+        });
+        let mut signed: Vec<String> = pairs
+            .into_iter()
+            .filter(|pair| match param_name(pair) {
+                TIMESTAMP_PARAM | SIGNATURE_PARAM => false,
+                RECV_WINDOW_PARAM => has_recv_window,
+                _ => true,
+            })
+            .map(str::to_string)
+            .collect();
+        if !has_recv_window && let Some(recv_window) = self.recv_window {
+            signed.push(format!("{}={}", RECV_WINDOW_PARAM, recv_window));
+        }
+        signed.push(format!(
+            "{}={}",
+            TIMESTAMP_PARAM,
+            self.timestamp_factory.create()
+        ));
+
+        let mut query = signed.join("&");
+        let payload = format!("{}{}", query, body.unwrap_or(""));
+        let signature = self
+            .digest
+            .digest_payload(&payload)
+            .map_err(|e| BinanceError::InvalidKey(e.to_string()))?;
+
+        // Ed25519 签名是 base64，需要 URL 编码；HMAC 的十六进制不受影响
+        query.push('&');
+        query.push_str(SIGNATURE_PARAM);
+        query.push('=');
+        query.extend(form_urlencoded::byte_serialize(signature.as_bytes()));
+        Ok(Some(query))
+    }
+}
+
+impl fmt::Debug for BinanceRequestSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinanceRequestSigner")
+            .field("recv_window", &self.recv_window)
+            .finish_non_exhaustive()
+    }
+}
+
+fn param_name(pair: &str) -> &str {
+    pair.split_once('=').map_or(pair, |(name, _)| name)
+}
+
+fn param_value(pair: &str) -> &str {
+    pair.split_once('=').map_or("", |(_, value)| value)
+}
+
+/// 在请求发出前调用 `BinanceRequestSigner`，改写 URL 的 query string
+#[derive(Debug)]
+pub struct SignatureInterceptor {
+    signer: Arc<BinanceRequestSigner>,
+}
+
+impl SignatureInterceptor {
+    pub fn new(signer: Arc<BinanceRequestSigner>) -> Self {
+        Self { signer }
+    }
+}
+
+#[async_trait]
+impl HttpMiddleware for SignatureInterceptor {
+    async fn handle(
+        &self,
+        mut request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, HttpError> {
+        let body = request
+            .body
+            .as_deref()
+            .map(|bytes| String::from_utf8_lossy(bytes).into_owned());
+        // 签名失败时不发送请求
+        let signed = self
+            .signer
+            .sign_query(&request.method, request.url.query(), body.as_deref())
+            .map_err(|e| HttpError::InvalidKey(e.to_string()))?;
+        if let Some(query) = signed {
+            request.url.set_query(Some(&query));
+        }
+
+        next.run(request).await
+    }
+}
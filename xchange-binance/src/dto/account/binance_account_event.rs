@@ -0,0 +1,263 @@
+use crate::binance::BinanceAdapters;
+use chrono::DateTime;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::Arc;
+use xchange_core::currency::currency::Currency;
+use xchange_core::dto::account::balance::Balance;
+use xchange_core::dto::account::balance_update::BalanceUpdate;
+use xchange_core::dto::account::open_position::{MarginMode, OpenPosition, PositionType};
+use xchange_core::instrument::{InstrumentDTO, InstrumentKind};
+
+/// 现货 `outboundAccountPosition`：发生变化的资产的最新余额
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceOutboundAccountPosition {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "u")]
+    pub last_update_time: i64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceStreamBalance>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceStreamBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "f")]
+    pub free: Decimal,
+
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
+
+impl BinanceOutboundAccountPosition {
+    pub fn to_balance_updates(&self) -> Vec<BalanceUpdate> {
+        let timestamp = DateTime::from_timestamp_millis(self.last_update_time);
+        self.balances
+            .iter()
+            .map(|b| {
+                let balance = Balance::new_full(
+                    Currency::new(&b.asset),
+                    b.free + b.locked,
+                    b.free,
+                    b.locked,
+                    Decimal::ZERO,
+                    Decimal::ZERO,
+                    Decimal::ZERO,
+                    Decimal::ZERO,
+                    timestamp,
+                );
+                BalanceUpdate::with_balance(BinanceAdapters::SPOT_WALLET_ID, balance, timestamp)
+            })
+            .collect()
+    }
+}
+
+/// 现货 `balanceUpdate`：充值、提现、划转引起的余额变化，只有变化量
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceBalanceUpdate {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "d")]
+    pub delta: Decimal,
+
+    #[serde(rename = "T")]
+    pub clear_time: i64,
+}
+
+impl BinanceBalanceUpdate {
+    pub fn to_balance_update(&self) -> BalanceUpdate {
+        BalanceUpdate::with_delta(
+            BinanceAdapters::SPOT_WALLET_ID,
+            Currency::new(&self.asset),
+            self.delta,
+            DateTime::from_timestamp_millis(self.clear_time),
+        )
+    }
+}
+
+/// 合约 `ACCOUNT_UPDATE`：余额与持仓变化
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceAccountUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+
+    #[serde(rename = "a")]
+    pub update: BinanceAccountUpdate,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceAccountUpdate {
+    /// 事件原因：ORDER / FUNDING_FEE / DEPOSIT / WITHDRAW ...
+    #[serde(rename = "m")]
+    pub reason: String,
+
+    #[serde(rename = "B", default)]
+    pub balances: Vec<BinanceFuturesStreamBalance>,
+
+    #[serde(rename = "P", default)]
+    pub positions: Vec<BinanceFuturesStreamPosition>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceFuturesStreamBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "wb")]
+    pub wallet_balance: Decimal,
+
+    /// 全仓钱包余额，不是可用余额
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Decimal,
+
+    /// 除盈亏与手续费以外的余额变化
+    #[serde(rename = "bc")]
+    pub balance_change: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceFuturesStreamPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "pa")]
+    pub position_amount: Decimal,
+
+    #[serde(rename = "ep")]
+    pub entry_price: Decimal,
+
+    #[serde(rename = "up")]
+    pub unrealized_pnl: Decimal,
+
+    /// isolated / cross
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+
+    /// BOTH / LONG / SHORT
+    #[serde(rename = "ps")]
+    pub position_side: String,
+}
+
+impl BinanceAccountUpdateEvent {
+    pub fn to_balance_updates(&self) -> Vec<BalanceUpdate> {
+        let timestamp = DateTime::from_timestamp_millis(self.transaction_time);
+        self.update
+            .balances
+            .iter()
+            .map(|b| {
+                // 推送只有钱包余额和全仓余额，没有可用 / 冻结金额，这两项保持未知
+                let balance = Balance {
+                    currency: Currency::new(&b.asset),
+                    total: Some(b.wallet_balance),
+                    available: None,
+                    frozen: None,
+                    borrowed: Decimal::ZERO,
+                    loaned: Decimal::ZERO,
+                    withdrawing: Decimal::ZERO,
+                    depositing: Decimal::ZERO,
+                    timestamp,
+                };
+                let mut update = BalanceUpdate::with_balance(
+                    BinanceAdapters::FUTURES_WALLET_ID,
+                    balance,
+                    timestamp,
+                );
+                update.delta = b.balance_change;
+                update
+            })
+            .collect()
+    }
+
+    /// 持仓变化，instrument 由调用方根据 symbol 解析
+    pub fn to_open_positions(
+        &self,
+        resolve: impl Fn(&str) -> Option<InstrumentDTO>,
+    ) -> Vec<OpenPosition> {
+        let timestamp = DateTime::from_timestamp_millis(self.transaction_time);
+        self.update
+            .positions
+            .iter()
+            .filter_map(|p| {
+                let instrument = resolve(&p.symbol)?;
+                let type_ = match p.position_side.as_str() {
+                    "LONG" => PositionType::Long,
+                    "SHORT" => PositionType::Short,
+                    // 单向持仓模式按数量正负判断方向
+                    _ if p.position_amount.is_sign_negative() => PositionType::Short,
+                    _ => PositionType::Long,
+                };
+                let margin_mode = match p.margin_type.as_str() {
+                    "isolated" => Some(MarginMode::Isolated),
+                    "cross" => Some(MarginMode::Cross),
+                    _ => None,
+                };
+
+                Some(OpenPosition {
+                    id: None,
+                    instrument: Arc::new(InstrumentKind::from(instrument)),
+                    type_,
+                    margin_mode,
+                    size: Some(p.position_amount.abs()),
+                    price: Some(p.entry_price),
+                    liquidation_price: None,
+                    un_realised_pnl: Some(p.unrealized_pnl),
+                    created_at: None,
+                    updated_at: timestamp,
+                })
+            })
+            .collect()
+    }
+}
+
+/// user data stream 中的账户事件
+#[derive(Debug, Clone)]
+pub enum BinanceAccountEvent {
+    AccountPosition(BinanceOutboundAccountPosition),
+    BalanceUpdate(BinanceBalanceUpdate),
+    AccountUpdate(BinanceAccountUpdateEvent),
+}
+
+impl BinanceAccountEvent {
+    /// 按 `e` 字段识别事件，非账户事件返回 None
+    pub fn from_value(data: Value) -> Option<Result<Self, serde_json::Error>> {
+        match data.get("e").and_then(Value::as_str) {
+            Some("outboundAccountPosition") => {
+                Some(serde_json::from_value(data).map(Self::AccountPosition))
+            }
+            Some("balanceUpdate") => Some(serde_json::from_value(data).map(Self::BalanceUpdate)),
+            Some("ACCOUNT_UPDATE") => Some(serde_json::from_value(data).map(Self::AccountUpdate)),
+            _ => None,
+        }
+    }
+
+    pub fn to_balance_updates(&self) -> Vec<BalanceUpdate> {
+        match self {
+            Self::AccountPosition(event) => event.to_balance_updates(),
+            Self::BalanceUpdate(event) => vec![event.to_balance_update()],
+            Self::AccountUpdate(event) => event.to_balance_updates(),
+        }
+    }
+
+    pub fn to_open_positions(
+        &self,
+        resolve: impl Fn(&str) -> Option<InstrumentDTO>,
+    ) -> Vec<OpenPosition> {
+        match self {
+            Self::AccountUpdate(event) => event.to_open_positions(resolve),
+            _ => Vec::new(),
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod binance_account_event;
 pub mod binance_currency_info;
@@ -0,0 +1,283 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use xchange_core::dto::marketdata::candle_stick::CandleStick;
+use xchange_core::dto::marketdata::order_book::OrderBook;
+use xchange_core::dto::marketdata::order_book_update::OrderBookUpdate;
+use xchange_core::dto::marketdata::ticker::Ticker;
+use xchange_core::dto::marketdata::trade::Trade;
+use xchange_core::dto::order::OrderType;
+use xchange_core::dto::trade::limit_order::LimitOrder;
+use xchange_core::instrument::InstrumentDTO;
+
+fn to_datetime(millis: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis)
+}
+
+/// `<symbol>@ticker` 24hr 滚动窗口 ticker
+///
+/// 合约 ticker 不带买一卖一，因此 bid/ask 字段可选
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceTickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub last_price: Decimal,
+
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+
+    #[serde(rename = "w")]
+    pub weighted_avg_price: Decimal,
+
+    #[serde(rename = "P")]
+    pub price_change_percent: Decimal,
+
+    #[serde(rename = "b")]
+    pub bid_price: Option<Decimal>,
+
+    #[serde(rename = "B")]
+    pub bid_qty: Option<Decimal>,
+
+    #[serde(rename = "a")]
+    pub ask_price: Option<Decimal>,
+
+    #[serde(rename = "A")]
+    pub ask_qty: Option<Decimal>,
+}
+
+impl BinanceTickerEvent {
+    pub fn to_ticker(&self, instrument: InstrumentDTO) -> Ticker {
+        Ticker::new(
+            instrument,
+            self.open_price,
+            self.last_price,
+            self.bid_price.unwrap_or_default(),
+            self.ask_price.unwrap_or_default(),
+            self.high_price,
+            self.low_price,
+            self.weighted_avg_price,
+            Some(self.volume),
+            Some(self.quote_volume),
+            to_datetime(self.event_time),
+            self.bid_qty.unwrap_or_default(),
+            self.ask_qty.unwrap_or_default(),
+            Some(self.price_change_percent),
+        )
+    }
+}
+
+/// 现货 `<symbol>@trade` / 合约 `<symbol>@aggTrade`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceTradeEvent {
+    /// trade 的成交 ID
+    #[serde(rename = "t")]
+    pub trade_id: Option<i64>,
+
+    /// aggTrade 的归集成交 ID
+    #[serde(rename = "a")]
+    pub agg_trade_id: Option<i64>,
+
+    #[serde(rename = "p")]
+    pub price: Decimal,
+
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+
+    /// 买方是否为 maker，true 表示主动卖出
+    #[serde(rename = "m")]
+    pub buyer_maker: bool,
+}
+
+impl BinanceTradeEvent {
+    pub fn to_trade(&self, instrument: InstrumentDTO) -> Trade {
+        let order_type = if self.buyer_maker {
+            OrderType::Ask
+        } else {
+            OrderType::Bid
+        };
+
+        Trade::new(
+            order_type,
+            self.quantity,
+            instrument,
+            self.price,
+            to_datetime(self.trade_time),
+            self.trade_id
+                .or(self.agg_trade_id)
+                .map(|id| id.to_string())
+                .unwrap_or_default(),
+            String::new(),
+            String::new(),
+        )
+    }
+}
+
+/// `<symbol>@depth<levels>@100ms` 有限档深度
+///
+/// 现货字段为 `bids`/`asks`，合约为 `b`/`a` 且带事件时间
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinancePartialDepthEvent {
+    #[serde(rename = "E")]
+    pub event_time: Option<i64>,
+
+    #[serde(rename = "lastUpdateId", alias = "u")]
+    pub last_update_id: i64,
+
+    #[serde(rename = "bids", alias = "b")]
+    pub bids: Vec<(Decimal, Decimal)>,
+
+    #[serde(rename = "asks", alias = "a")]
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl BinancePartialDepthEvent {
+    pub fn to_order_book(&self, instrument: InstrumentDTO) -> OrderBook {
+        let timestamp = self.event_time.and_then(to_datetime);
+        let to_orders = |levels: &[(Decimal, Decimal)], order_type: OrderType| {
+            levels
+                .iter()
+                .map(|(price, qty)| {
+                    LimitOrder::new(
+                        order_type.clone(),
+                        Some(*qty),
+                        instrument.clone(),
+                        String::new(),
+                        timestamp,
+                        Some(*price),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        OrderBook::new(
+            timestamp,
+            to_orders(&self.asks, OrderType::Ask),
+            to_orders(&self.bids, OrderType::Bid),
+        )
+    }
+}
+
+/// `<symbol>@depth@100ms` 增量深度，数量为 0 表示该档位被移除
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceDepthUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+
+    #[serde(rename = "u")]
+    pub final_update_id: i64,
+
+    #[serde(rename = "b")]
+    pub bids: Vec<(Decimal, Decimal)>,
+
+    #[serde(rename = "a")]
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl BinanceDepthUpdateEvent {
+    pub fn to_order_book_updates(&self, instrument: InstrumentDTO) -> Vec<OrderBookUpdate> {
+        let timestamp = to_datetime(self.event_time);
+        let bids = self.bids.iter().map(|level| (OrderType::Bid, level));
+        let asks = self.asks.iter().map(|level| (OrderType::Ask, level));
+
+        bids.chain(asks)
+            .map(|(order_type, (price, qty))| {
+                OrderBookUpdate::new(
+                    order_type,
+                    *qty,
+                    instrument.clone(),
+                    *price,
+                    timestamp,
+                    *qty,
+                )
+            })
+            .collect()
+    }
+}
+
+/// `<symbol>@kline_<interval>`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceKlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "k")]
+    pub kline: BinanceKlineEventData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceKlineEventData {
+    #[serde(rename = "t")]
+    pub open_time: i64,
+
+    #[serde(rename = "T")]
+    pub close_time: i64,
+
+    #[serde(rename = "i")]
+    pub interval: String,
+
+    #[serde(rename = "o")]
+    pub open: Decimal,
+
+    #[serde(rename = "c")]
+    pub close: Decimal,
+
+    #[serde(rename = "h")]
+    pub high: Decimal,
+
+    #[serde(rename = "l")]
+    pub low: Decimal,
+
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+
+    /// 该 K 线是否已收盘
+    #[serde(rename = "x")]
+    pub closed: bool,
+}
+
+impl BinanceKlineEvent {
+    pub fn to_candle_stick(&self) -> CandleStick {
+        let k = &self.kline;
+        CandleStick::new(
+            to_datetime(k.open_time).unwrap_or_default(),
+            k.open,
+            k.close,
+            k.high,
+            k.low,
+            k.close,
+            k.volume,
+            k.quote_volume,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+}
@@ -1,4 +1,5 @@
 pub mod binance_kline;
+pub mod binance_stream_event;
 
 use serde::Deserializer;
 use serde::{Deserialize, Serialize};
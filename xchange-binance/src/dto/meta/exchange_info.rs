@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +58,8 @@ impl std::fmt::Display for RateLimit {
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
+    /// 币本位合约返回 contractStatus
+    #[serde(alias = "contractStatus")]
     pub status: String,
     pub base_asset: String,
     pub quote_asset: String,
@@ -70,7 +73,13 @@ pub struct Symbol {
     pub is_margin_trading_allowed: Option<bool>,
     pub is_spot_trading_allowed: Option<bool>,
 
+    /// 合约价格 / 数量精度
+    pub price_precision: Option<u8>,
+    pub quantity_precision: Option<u8>,
+
     pub contract_type: Option<String>,
+    /// 币本位合约面值
+    pub contract_size: Option<Decimal>,
     pub delivery_date: Option<i64>,
     pub onboard_date: Option<i64>,
 
@@ -1,13 +1,19 @@
 pub mod account;
 pub mod marketdata;
 pub mod meta;
+pub mod trade;
 
+use crate::binance::BinanceErrorAdapter;
+use crate::binance_resilience::BinanceResilience;
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
-use xchange_core::error::ExchangeErrorDetail;
-use xchange_core::error::exchange_error::ExchangeError;
+use xchange_core::client::HttpStatusError;
+use xchange_core::error::exchange_error::{ExchangeApiError, ExchangeError};
+use xchange_core::error::{ErrorKind, ExchangeErrorDetail};
+use xchange_core::rescu::HttpError;
 use xchange_core::rescu::params_digest::DigestError;
 
 #[derive(Debug, Error)]
@@ -24,6 +30,9 @@ pub enum BinanceError {
     #[error("Retrofit error: {0}")]
     Retrofit(#[from] retrofit_rs::RetrofitError),
 
+    #[error("HTTP client error: {0}")]
+    Http(#[from] HttpError),
+
     #[error("Time provider error: {0}")]
     TimeProvider(#[source] Box<dyn std::error::Error + Send + Sync>),
 
@@ -64,11 +73,56 @@ impl From<Box<dyn std::error::Error + Send + Sync>> for BinanceError {
     }
 }
 
-impl ExchangeErrorDetail for BinanceError {}
+impl ExchangeErrorDetail for BinanceError {
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+
+    fn kind(&self) -> ErrorKind {
+        BinanceError::kind(self)
+    }
+}
+
+impl BinanceError {
+    /// 错误分类，Binance 业务错误按错误码分类
+    pub fn kind(&self) -> ErrorKind {
+        if let Some(e) = BinanceResilience::binance_exception(self) {
+            return BinanceErrorAdapter::error_kind(e);
+        }
+        match self {
+            BinanceError::Json(_) => ErrorKind::Decode,
+            BinanceError::AcquireRateLimiter(_) => ErrorKind::RateLimit,
+            BinanceError::Digest(_) | BinanceError::InvalidKey(_) => ErrorKind::Auth,
+            BinanceError::InvalidParam(_) => ErrorKind::InvalidRequest,
+            BinanceError::Http(HttpError::Io(_)) => ErrorKind::Transport,
+            // 代理等配置错误
+            BinanceError::Http(_) => ErrorKind::InvalidRequest,
+            BinanceError::Exchange(e) => e.kind(),
+            // retrofit 的连接错误无法进一步区分
+            BinanceError::Io(_) | BinanceError::Retrofit(_) => match ErrorKind::of(self) {
+                ErrorKind::Other => ErrorKind::Transport,
+                kind => kind,
+            },
+            _ => ErrorKind::of(self),
+        }
+    }
+}
 
 impl From<BinanceError> for ExchangeError {
+    /// Binance 业务错误按错误码转换，其他错误按 kind 包装，保留原始错误作为 source
     fn from(err: BinanceError) -> Self {
-        ExchangeError::Custom(Box::new(err))
+        if let Some(e) = BinanceResilience::binance_exception(&err) {
+            return BinanceErrorAdapter::adapt(e.clone());
+        }
+        match err {
+            BinanceError::Exchange(e) => e,
+            err => {
+                let kind = err.kind();
+                ExchangeApiError::new(kind, err.to_string())
+                    .with_source(err)
+                    .into()
+            }
+        }
     }
 }
 
@@ -92,6 +146,14 @@ pub struct BinanceException {
     /// HTTP 响应头（可选，因为非所有错误都有）
     #[serde(skip)]
     pub headers: Option<HashMap<String, Vec<String>>>,
+
+    /// HTTP 状态码（由 client 在解析错误响应时填充）
+    #[serde(skip)]
+    pub http_status: Option<u16>,
+
+    /// 请求路径，如 /api/v3/order
+    #[serde(skip)]
+    pub path: Option<String>,
 }
 
 impl fmt::Display for BinanceException {
@@ -101,16 +163,50 @@ impl fmt::Display for BinanceException {
 }
 
 impl BinanceException {
+    /// timestamp 超出 recvWindow，或比服务器时间快 1 秒以上
+    pub const TIMESTAMP_OUTSIDE_RECV_WINDOW: i32 = -1021;
+
     pub fn new(code: i32, msg: impl Into<String>) -> Self {
         Self {
             code,
             msg: msg.into(),
             headers: None,
+            http_status: None,
+            path: None,
         }
     }
 
+    /// 设置 HTTP 状态码
+    pub fn set_http_status(&mut self, status: u16) {
+        self.http_status = Some(status);
+    }
+
+    /// 设置请求路径
+    pub fn set_path(&mut self, path: impl Into<String>) {
+        self.path = Some(path.into());
+    }
+
     /// 设置 HTTP 响应头（对应 Java 的 setResponseHeaders）
     pub fn set_headers(&mut self, headers: HashMap<String, Vec<String>>) {
         self.headers = Some(headers);
     }
+
+    /// 读取响应头，header 名不区分大小写
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.as_ref()?.iter().find_map(|(key, values)| {
+            key.eq_ignore_ascii_case(name)
+                .then(|| values.first().map(String::as_str))
+                .flatten()
+        })
+    }
+
+    /// 出错时 1 分钟内已用的请求权重
+    pub fn used_weight_1m(&self) -> Option<u64> {
+        self.header("X-MBX-USED-WEIGHT-1M")?.trim().parse().ok()
+    }
+
+    /// 429 / 418 时 Retry-After 头指定的等待时间
+    pub fn retry_after(&self) -> Option<Duration> {
+        HttpStatusError::parse_retry_after(self.header("Retry-After")?)
+    }
 }
@@ -0,0 +1,399 @@
+use crate::binance::BinanceAdapters;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use xchange_core::currency::currency::Currency;
+use xchange_core::dto::marketdata::trade::Trade;
+use xchange_core::dto::order::{Order, OrderStatus, OrderType};
+use xchange_core::dto::trade::limit_order::LimitOrder;
+use xchange_core::dto::trade::market_order::MarketOrder;
+use xchange_core::dto::trade::user_trade::UserTrade;
+use xchange_core::instrument::InstrumentDTO;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BinanceListenKey {
+    #[serde(rename = "listenKey")]
+    pub listen_key: String,
+}
+
+/// 现货 `executionReport`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceExecutionReport {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    /// 撤单事件中为撤单请求的 id
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    /// 撤单事件中被撤订单的 client order id，其它事件为空
+    #[serde(rename = "C", default)]
+    pub orig_client_order_id: String,
+
+    /// BUY / SELL
+    #[serde(rename = "S")]
+    pub side: String,
+
+    /// LIMIT / MARKET / STOP_LOSS_LIMIT ...
+    #[serde(rename = "o")]
+    pub order_type: String,
+
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+
+    #[serde(rename = "p")]
+    pub price: Decimal,
+
+    /// 本次事件类型：NEW / CANCELED / REPLACED / REJECTED / TRADE / EXPIRED
+    #[serde(rename = "x")]
+    pub execution_type: String,
+
+    /// 订单当前状态
+    #[serde(rename = "X")]
+    pub order_status: String,
+
+    #[serde(rename = "i")]
+    pub order_id: i64,
+
+    #[serde(rename = "l")]
+    pub last_executed_quantity: Decimal,
+
+    #[serde(rename = "z")]
+    pub cumulative_filled_quantity: Decimal,
+
+    #[serde(rename = "L")]
+    pub last_executed_price: Decimal,
+
+    #[serde(rename = "n")]
+    pub commission: Decimal,
+
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "m")]
+    pub maker: bool,
+
+    #[serde(rename = "Z")]
+    pub cumulative_quote_quantity: Decimal,
+}
+
+impl BinanceExecutionReport {
+    pub fn to_order(&self, instrument: InstrumentDTO) -> Order {
+        // 现货推送不带均价，由累计成交额 / 累计成交量计算
+        let average_price = if self.cumulative_filled_quantity.is_zero() {
+            None
+        } else {
+            Some(self.cumulative_quote_quantity / self.cumulative_filled_quantity)
+        };
+
+        build_order(OrderFields {
+            instrument,
+            order_type: BinanceAdapters::adapt_order_type(&self.side, false),
+            kind: &self.order_type,
+            id: self.order_id.to_string(),
+            client_order_id: &self.client_order_id,
+            orig_client_order_id: &self.orig_client_order_id,
+            timestamp: DateTime::from_timestamp_millis(self.transaction_time),
+            quantity: self.quantity,
+            price: self.price,
+            status: BinanceAdapters::adapt_order_status(&self.order_status),
+            cumulative_amount: self.cumulative_filled_quantity,
+            average_price,
+        })
+    }
+
+    /// 仅 `x = TRADE` 的事件包含成交
+    pub fn to_user_trade(&self, instrument: InstrumentDTO) -> Option<UserTrade> {
+        if self.execution_type != "TRADE" {
+            return None;
+        }
+
+        Some(build_user_trade(TradeFields {
+            instrument,
+            order_type: BinanceAdapters::adapt_order_type(&self.side, false),
+            order_id: self.order_id.to_string(),
+            client_order_id: &self.client_order_id,
+            orig_client_order_id: &self.orig_client_order_id,
+            trade_id: self.trade_id,
+            timestamp: DateTime::from_timestamp_millis(self.transaction_time),
+            quantity: self.last_executed_quantity,
+            price: self.last_executed_price,
+            maker: self.maker,
+            commission: self.commission,
+            commission_asset: self.commission_asset.as_deref(),
+        }))
+    }
+}
+
+/// 合约 `ORDER_TRADE_UPDATE`
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceOrderTradeUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: i64,
+
+    #[serde(rename = "T")]
+    pub transaction_time: i64,
+
+    #[serde(rename = "o")]
+    pub order: BinanceFuturesOrderUpdate,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceFuturesOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    /// 撤单事件中为撤单请求的 id
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    /// 撤单事件中被撤订单的 client order id，其它事件为空
+    #[serde(rename = "C", default)]
+    pub orig_client_order_id: String,
+
+    #[serde(rename = "S")]
+    pub side: String,
+
+    #[serde(rename = "o")]
+    pub order_type: String,
+
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+
+    #[serde(rename = "p")]
+    pub price: Decimal,
+
+    #[serde(rename = "ap")]
+    pub average_price: Decimal,
+
+    #[serde(rename = "x")]
+    pub execution_type: String,
+
+    #[serde(rename = "X")]
+    pub order_status: String,
+
+    #[serde(rename = "i")]
+    pub order_id: i64,
+
+    #[serde(rename = "l")]
+    pub last_filled_quantity: Decimal,
+
+    #[serde(rename = "z")]
+    pub cumulative_filled_quantity: Decimal,
+
+    #[serde(rename = "L")]
+    pub last_filled_price: Decimal,
+
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+
+    #[serde(rename = "n")]
+    pub commission: Option<Decimal>,
+
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+
+    #[serde(rename = "m")]
+    pub maker: bool,
+
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+}
+
+impl BinanceOrderTradeUpdateEvent {
+    pub fn to_order(&self, instrument: InstrumentDTO) -> Order {
+        let o = &self.order;
+        build_order(OrderFields {
+            instrument,
+            order_type: BinanceAdapters::adapt_order_type(&o.side, o.reduce_only),
+            kind: &o.order_type,
+            id: o.order_id.to_string(),
+            client_order_id: &o.client_order_id,
+            orig_client_order_id: &o.orig_client_order_id,
+            timestamp: DateTime::from_timestamp_millis(self.transaction_time),
+            quantity: o.quantity,
+            price: o.price,
+            status: BinanceAdapters::adapt_order_status(&o.order_status),
+            cumulative_amount: o.cumulative_filled_quantity,
+            average_price: Some(o.average_price).filter(|p| !p.is_zero()),
+        })
+    }
+
+    pub fn to_user_trade(&self, instrument: InstrumentDTO) -> Option<UserTrade> {
+        let o = &self.order;
+        if o.execution_type != "TRADE" {
+            return None;
+        }
+
+        Some(build_user_trade(TradeFields {
+            instrument,
+            order_type: BinanceAdapters::adapt_order_type(&o.side, o.reduce_only),
+            order_id: o.order_id.to_string(),
+            client_order_id: &o.client_order_id,
+            orig_client_order_id: &o.orig_client_order_id,
+            trade_id: o.trade_id,
+            timestamp: DateTime::from_timestamp_millis(o.trade_time),
+            quantity: o.last_filled_quantity,
+            price: o.last_filled_price,
+            maker: o.maker,
+            commission: o.commission.unwrap_or_default(),
+            commission_asset: o.commission_asset.as_deref(),
+        }))
+    }
+}
+
+/// user data stream 中的订单事件
+#[derive(Debug, Clone)]
+pub enum BinanceOrderEvent {
+    Spot(BinanceExecutionReport),
+    Futures(BinanceOrderTradeUpdateEvent),
+}
+
+impl BinanceOrderEvent {
+    /// 按 `e` 字段识别事件，非订单事件返回 None
+    pub fn from_value(data: Value) -> Option<Result<Self, serde_json::Error>> {
+        match data.get("e").and_then(Value::as_str) {
+            Some("executionReport") => Some(serde_json::from_value(data).map(Self::Spot)),
+            Some("ORDER_TRADE_UPDATE") => Some(serde_json::from_value(data).map(Self::Futures)),
+            _ => None,
+        }
+    }
+
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Spot(report) => &report.symbol,
+            Self::Futures(update) => &update.order.symbol,
+        }
+    }
+
+    pub fn to_order(&self, instrument: InstrumentDTO) -> Order {
+        match self {
+            Self::Spot(report) => report.to_order(instrument),
+            Self::Futures(update) => update.to_order(instrument),
+        }
+    }
+
+    pub fn to_user_trade(&self, instrument: InstrumentDTO) -> Option<UserTrade> {
+        match self {
+            Self::Spot(report) => report.to_user_trade(instrument),
+            Self::Futures(update) => update.to_user_trade(instrument),
+        }
+    }
+}
+
+/// 现货 / 合约订单事件的公共字段
+struct OrderFields<'a> {
+    instrument: InstrumentDTO,
+    order_type: OrderType,
+    kind: &'a str,
+    id: String,
+    client_order_id: &'a str,
+    orig_client_order_id: &'a str,
+    timestamp: Option<DateTime<Utc>>,
+    quantity: Decimal,
+    price: Decimal,
+    status: OrderStatus,
+    cumulative_amount: Decimal,
+    average_price: Option<Decimal>,
+}
+
+fn build_order(f: OrderFields) -> Order {
+    let user_reference = Some(client_order_id(f.client_order_id, f.orig_client_order_id))
+        .filter(|c| !c.is_empty())
+        .map(str::to_string);
+
+    if f.kind == "MARKET" {
+        return Order::MarketOrder(MarketOrder::new(
+            f.order_type,
+            f.quantity,
+            f.instrument,
+            f.id,
+            f.timestamp,
+            f.average_price,
+            Some(f.cumulative_amount),
+            None,
+            f.status,
+            user_reference,
+        ));
+    }
+
+    let mut order = LimitOrder::new(
+        f.order_type,
+        Some(f.quantity),
+        f.instrument,
+        f.id,
+        f.timestamp,
+        Some(f.price),
+    );
+    order.order_base.status = Some(f.status);
+    order.order_base.cumulative_amount = Some(f.cumulative_amount);
+    order.order_base.remaining_amount = Some(f.quantity - f.cumulative_amount);
+    order.order_base.average_price = f.average_price;
+    order.order_base.user_reference = user_reference;
+    Order::LimitOrder(order)
+}
+
+/// 现货 / 合约成交事件的公共字段
+struct TradeFields<'a> {
+    instrument: InstrumentDTO,
+    order_type: OrderType,
+    order_id: String,
+    client_order_id: &'a str,
+    orig_client_order_id: &'a str,
+    trade_id: i64,
+    timestamp: Option<DateTime<Utc>>,
+    quantity: Decimal,
+    price: Decimal,
+    maker: bool,
+    commission: Decimal,
+    commission_asset: Option<&'a str>,
+}
+
+fn build_user_trade(f: TradeFields) -> UserTrade {
+    let (maker_order_id, taker_order_id) = if f.maker {
+        (f.order_id.clone(), String::new())
+    } else {
+        (String::new(), f.order_id.clone())
+    };
+
+    let trade = Trade::new(
+        f.order_type,
+        f.quantity,
+        f.instrument,
+        f.price,
+        f.timestamp,
+        f.trade_id.to_string(),
+        maker_order_id,
+        taker_order_id,
+    );
+
+    UserTrade::new(
+        trade,
+        f.order_id,
+        f.commission,
+        Currency::new(f.commission_asset.unwrap_or_default()),
+        client_order_id(f.client_order_id, f.orig_client_order_id).to_string(),
+    )
+}
+
+/// 撤单事件优先使用原订单的 client order id，才能和被撤的订单对应
+fn client_order_id<'a>(client_order_id: &'a str, orig_client_order_id: &'a str) -> &'a str {
+    if orig_client_order_id.is_empty() {
+        client_order_id
+    } else {
+        orig_client_order_id
+    }
+}
@@ -0,0 +1 @@
+pub mod binance_user_data_event;
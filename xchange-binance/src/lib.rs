@@ -3,7 +3,7 @@ extern crate core;
 pub mod binance;
 pub mod binance_exchange;
 pub mod binance_resilience;
-mod binance_time_provider;
+pub mod binance_time_provider;
 pub mod client;
 pub mod dto;
 pub mod service;
@@ -1,15 +1,26 @@
 use crate::binance_exchange::{BinanceExchange, EXCHANGE_TYPE_KEY};
+use crate::binance_time_provider::ServerTimeFetcher;
+use crate::client::binance_futures::BinanceFuturesAuthed;
 use crate::client::binance_spot::BinanceAuthed;
+use crate::client::signature_interceptor::BinanceRequestSigner;
 use crate::client::{BinanceClient, BinanceClientBuilder};
 use crate::dto::BinanceError;
 use crate::dto::meta::binance_system::BinanceSystemStatus;
-use crate::service::{BinanceEd25519Digest, BinanceHmacDigest};
+use crate::service::{BinanceEd25519Digest, BinanceHmacDigest, digest_from_pem};
+use futures::future::FutureExt;
 use std::sync::Arc;
 use xchange_core::ValueFactory;
 use xchange_core::client::{ResilientCall, boxed};
 use xchange_core::exchange::ExchangeType;
-use xchange_core::exchange_specification::ExchangeParam;
+use xchange_core::exchange_specification::{ExchangeParam, ExchangeSpecification};
+use xchange_core::rescu::client_config::HttpClientConfig;
 use xchange_core::rescu::params_digest::ParamsDigest;
+use xchange_core::utils::credentials::Secret;
+
+/// exchange_specific_parameters 中的 HMAC secret 编码标记
+pub const SECRET_KEY_BASE64: &str = "secretKeyBase64";
+/// 加密 PEM 私钥的口令
+pub const SECRET_KEY_PASSPHRASE: &str = "secretKeyPassphrase";
 
 pub struct BinanceBaseService {
     pub api_key: Option<String>,
@@ -28,12 +39,12 @@ impl BinanceBaseService {
         // ---------------------
         let spec_read = exchange.base.spec.read();
         let api_key = spec_read.api_key.clone();
-        let secret_key = spec_read.secret_key.clone();
 
         let exchange_type = spec_read
             .exchange_specific_parameters
             .get(EXCHANGE_TYPE_KEY)
-            .and_then(|param| param.as_exchange_type());
+            .and_then(|param| param.as_exchange_type())
+            .unwrap_or(ExchangeType::Spot); // 如果没有传入，默认 Spot
 
         let base_url = spec_read
             .ssl_uri
@@ -41,27 +52,38 @@ impl BinanceBaseService {
             .or(spec_read.plain_text_uri.clone())
             .expect("no base URL provided");
 
-        let use_ed25519 = matches!(
-            spec_read.exchange_specific_parameters.get("ed25519"),
-            Some(ExchangeParam::Boolean(true))
-        );
+        // ---------------------
+        // 2) 构建 digest（RSA / ED25519 / HMAC）
+        // ---------------------
+        let digest = Self::create_digest(&spec_read)?;
+        let http_config = HttpClientConfig::from_specification(&spec_read)?;
 
         drop(spec_read); // 提前释放锁
 
-        let client = BinanceClientBuilder::new(&base_url)
-            .api_key(api_key.as_deref().unwrap_or("")) // 如果有 api_key，就传入，否则可忽略
-            .exchange_type(exchange_type.unwrap_or(ExchangeType::Spot)) // 如果没有传入，默认 Spot
-            .build()?;
-
         // ---------------------
-        // 4) 构建 digest（ED25519 / HMAC）
+        // 3) 构建 client，有 secret 时为 SIGNED / USER_DATA 请求签名，
+        //    代理 / 超时 / 连接池按 spec 设置
         // ---------------------
-        let digest: Option<Arc<dyn ParamsDigest + Send + Sync>> =
-            match (use_ed25519, secret_key.as_ref()) {
-                (true, Some(sk)) => Some(Arc::new(BinanceEd25519Digest::new(sk)?) as _),
-                (_, Some(sk)) => Some(Arc::new(BinanceHmacDigest::new(sk)?) as _),
-                _ => None,
-            };
+        let mut builder = BinanceClientBuilder::new(&base_url)
+            .api_key(api_key.as_deref().unwrap_or("")) // 如果有 api_key，就传入，否则可忽略
+            .exchange_type(exchange_type.clone())
+            .http_config(http_config)
+            .rate_limit_tracker(exchange.rate_limit_tracker.clone());
+        if let Some(digest) = &digest {
+            let signer =
+                BinanceRequestSigner::new(digest.clone(), exchange.timestamp_provider.clone())
+                    .recv_window(Self::recv_window(&exchange)?);
+            builder = builder.signer(Arc::new(signer));
+        }
+        if let Some(transport) = exchange.http_transport() {
+            builder = builder.transport(transport);
+        }
+        let client = builder.build()?;
+        if digest.is_some() {
+            exchange
+                .timestamp_provider
+                .set_server_time_fetcher(Self::server_time_fetcher(&client, &exchange_type));
+        }
 
         Ok(Self {
             exchange,
@@ -71,8 +93,49 @@ impl BinanceBaseService {
         })
     }
 
+    /// 按 secret_key 的内容选择签名方式：
+    /// - PEM 私钥（secret_key 为空时读取 private_key_file）：按算法选择 RSA / Ed25519，
+    ///   加密的 PKCS#8 使用 spec.passphrase 或 `secretKeyPassphrase`
+    /// - `ed25519` 参数为 true：base64 编码的 32 字节 Ed25519 私钥
+    /// - 其余为 HMAC-SHA256，`secretKeyBase64` 为 true 时先 base64 解码
+    pub fn create_digest(
+        spec: &ExchangeSpecification,
+    ) -> Result<Option<Arc<dyn ParamsDigest + Send + Sync>>, BinanceError> {
+        let secret = match (&spec.secret_key, &spec.private_key_file) {
+            (Some(secret), _) => secret.clone(),
+            (None, Some(path)) => Secret::new(std::fs::read_to_string(path).map_err(|e| {
+                BinanceError::InvalidKey(format!("read {} failed: {}", path.display(), e))
+            })?),
+            (None, None) => return Ok(None),
+        };
+        let secret = secret.expose();
+        let params = &spec.exchange_specific_parameters;
+        let flag = |key: &str| matches!(params.get(key), Some(ExchangeParam::Boolean(true)));
+
+        let digest: Arc<dyn ParamsDigest + Send + Sync> =
+            if secret.trim_start().starts_with("-----BEGIN") {
+                let passphrase = match (&spec.passphrase, params.get(SECRET_KEY_PASSPHRASE)) {
+                    (Some(passphrase), _) => Some(passphrase.expose()),
+                    (None, Some(ExchangeParam::String(passphrase))) => Some(passphrase.as_str()),
+                    _ => None,
+                };
+                digest_from_pem(secret, passphrase)?
+            } else if flag("ed25519") {
+                Arc::new(BinanceEd25519Digest::new(secret)?)
+            } else if flag(SECRET_KEY_BASE64) {
+                Arc::new(BinanceHmacDigest::from_base64(secret)?)
+            } else {
+                Arc::new(BinanceHmacDigest::new(secret)?)
+            };
+        Ok(Some(digest))
+    }
+
     pub fn get_recv_window(&self) -> Result<Option<u64>, BinanceError> {
-        let spec_read = self.exchange.base.spec.read();
+        Self::recv_window(&self.exchange)
+    }
+
+    fn recv_window(exchange: &BinanceExchange) -> Result<Option<u64>, BinanceError> {
+        let spec_read = exchange.base.spec.read();
         match spec_read.exchange_specific_parameters.get("recvWindow") {
             None => Ok(None),
             Some(ExchangeParam::Number(n)) => {
@@ -106,6 +169,57 @@ impl BinanceBaseService {
         self.exchange.timestamp_provider.clone()
     }
 
+    /// 执行 SIGNED / USER_DATA 请求，返回 -1021 时重新同步服务器时间并重试一次
+    pub async fn with_time_sync<T, F, Fut>(&self, call: F) -> Result<T, BinanceError>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, BinanceError>>,
+    {
+        self.exchange.timestamp_provider.with_resync(call).await
+    }
+
+    /// 按 ExchangeType 选择服务器时间接口，与签名请求走同一个 host
+    fn server_time_fetcher(
+        client: &BinanceClient,
+        exchange_type: &ExchangeType,
+    ) -> ServerTimeFetcher {
+        match exchange_type {
+            ExchangeType::Spot => {
+                let client = client.spot.clone();
+                Arc::new(move || {
+                    let client = client.clone();
+                    async move { Ok(client.time().await?) }.boxed()
+                })
+            }
+            ExchangeType::Futures | ExchangeType::PortfolioMargin => {
+                let client = client.futures.clone();
+                Arc::new(move || {
+                    let client = client.clone();
+                    async move {
+                        let client = client.ok_or_else(|| {
+                            BinanceError::ClientNotInitialized("futures client".into())
+                        })?;
+                        Ok(client.time().await?)
+                    }
+                    .boxed()
+                })
+            }
+            ExchangeType::Inverse => {
+                let client = client.futures_inverse.clone();
+                Arc::new(move || {
+                    let client = client.clone();
+                    async move {
+                        let client = client.ok_or_else(|| {
+                            BinanceError::ClientNotInitialized("inverse futures client".into())
+                        })?;
+                        Ok(client.inverse_time().await?)
+                    }
+                    .boxed()
+                })
+            }
+        }
+    }
+
     pub async fn system_status(&self) -> Result<BinanceSystemStatus, BinanceError> {
         let spot_client = self.client.spot.clone();
 
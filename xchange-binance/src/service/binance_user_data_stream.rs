@@ -0,0 +1,344 @@
+use crate::binance_resilience::{
+    ENDPOINT_LISTEN_KEY, REQUEST_WEIGHT_RATE_LIMITER, USER_DATA_STREAM_GROUP,
+};
+use crate::client::binance_futures::{BinanceFuturesAuthed, BinanceFuturesAuthedClient};
+use crate::client::binance_spot::BinanceAuthed;
+use crate::client::binance_websocket::{BinanceWebSocketClient, StreamEvent};
+use crate::dto::BinanceError;
+use crate::service::binance_base_service::BinanceBaseService;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use parking_lot::Mutex;
+use retrofit_rs::Query;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, interval_at};
+use tracing::{debug, warn};
+use xchange_core::client::{ResiliencePolicy, ResilientCall, boxed};
+use xchange_core::exchange::ExchangeType;
+
+/// listenKey 有效期 60 分钟，每 30 分钟续期一次
+pub const LISTEN_KEY_KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// 创建 listenKey 失败后的重试间隔
+const LISTEN_KEY_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+const EVENT_CAPACITY: usize = 1024;
+
+#[derive(Default)]
+struct StreamState {
+    task: Option<JoinHandle<()>>,
+    listen_key: Option<String>,
+}
+
+/// --------------------------
+/// BinanceUserDataStream
+/// --------------------------
+///
+/// 私有 user data stream，负责 listenKey 的完整生命周期：
+/// - 首次订阅时创建 listenKey，并通过 combined stream 订阅
+/// - 定时续期；续期失败或收到 `listenKeyExpired` 时重新创建
+/// - 所有订阅者离开后关闭 listenKey
+///
+/// 订单、成交、余额等私有推送共用同一个 listenKey，由上层按事件类型过滤。
+pub struct BinanceUserDataStream {
+    listen_keys: Arc<BinanceListenKeyClient>,
+    ws: Arc<BinanceWebSocketClient>,
+    events: broadcast::Sender<Value>,
+    state: Mutex<StreamState>,
+}
+
+/// 最后一个订阅者离开时停止后台任务
+struct UserDataGuard {
+    stream: Arc<BinanceUserDataStream>,
+}
+
+impl Drop for UserDataGuard {
+    fn drop(&mut self) {
+        if self.stream.events.receiver_count() == 0 {
+            self.stream.stop();
+        }
+    }
+}
+
+impl BinanceUserDataStream {
+    pub fn new(
+        base: Arc<BinanceBaseService>,
+        ws: Arc<BinanceWebSocketClient>,
+        exchange_type: ExchangeType,
+    ) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            listen_keys: Arc::new(BinanceListenKeyClient {
+                base,
+                exchange_type,
+            }),
+            ws,
+            events,
+            state: Mutex::new(StreamState::default()),
+        }
+    }
+
+    /// 当前使用中的 listenKey
+    pub fn listen_key(&self) -> Option<String> {
+        self.state.lock().listen_key.clone()
+    }
+
+    /// 订阅原始 user data 事件（`data` 字段），listenKey 轮换对订阅者透明
+    pub fn subscribe(self: &Arc<Self>) -> BoxStream<'static, StreamEvent> {
+        let receiver = self.events.subscribe();
+
+        {
+            let mut state = self.state.lock();
+            if state.task.as_ref().is_none_or(|task| task.is_finished()) {
+                state.task = Some(tokio::spawn(self.clone().run()));
+            }
+        }
+
+        let guard = UserDataGuard {
+            stream: self.clone(),
+        };
+
+        futures::stream::unfold((receiver, guard), |(mut receiver, guard)| async move {
+            match receiver.recv().await {
+                Ok(value) => Some((Ok(value), (receiver, guard))),
+                Err(broadcast::error::RecvError::Lagged(n)) => Some((Err(n), (receiver, guard))),
+                Err(broadcast::error::RecvError::Closed) => None,
+            }
+        })
+        .boxed()
+    }
+
+    /// 停止后台任务并关闭 listenKey
+    pub fn stop(&self) {
+        let (task, listen_key) = {
+            let mut state = self.state.lock();
+            (state.task.take(), state.listen_key.take())
+        };
+
+        if let Some(task) = task {
+            task.abort();
+        }
+
+        if let (Some(listen_key), Ok(handle)) = (listen_key, tokio::runtime::Handle::try_current())
+        {
+            self.ws.unsubscribe(&listen_key);
+            let listen_keys = self.listen_keys.clone();
+            handle.spawn(async move {
+                if let Err(e) = listen_keys.close(&listen_key).await {
+                    debug!("close listenKey failed: {}", e);
+                }
+            });
+        }
+    }
+
+    async fn run(self: Arc<Self>) {
+        loop {
+            let listen_key = match self.listen_keys.start().await {
+                Ok(key) => key,
+                Err(e) => {
+                    warn!("create listenKey failed: {}", e);
+                    tokio::time::sleep(LISTEN_KEY_RETRY_DELAY).await;
+                    continue;
+                }
+            };
+            self.state.lock().listen_key = Some(listen_key.clone());
+
+            let mut source = self.ws.subscribe(&listen_key);
+            let mut keep_alive = interval_at(
+                Instant::now() + LISTEN_KEY_KEEP_ALIVE_INTERVAL,
+                LISTEN_KEY_KEEP_ALIVE_INTERVAL,
+            );
+
+            loop {
+                tokio::select! {
+                    _ = keep_alive.tick() => {
+                        if let Err(e) = self.listen_keys.keep_alive(&listen_key).await {
+                            warn!("keep alive listenKey failed, recreating: {}", e);
+                            break;
+                        }
+                    }
+                    event = source.next() => match event {
+                        Some(Ok(data)) => {
+                            if data.get("e").and_then(Value::as_str) == Some("listenKeyExpired") {
+                                debug!("listenKey expired, recreating");
+                                break;
+                            }
+                            let _ = self.events.send(data);
+                        }
+                        Some(Err(skipped)) => {
+                            warn!("user data stream lagged, {} events dropped", skipped);
+                        }
+                        None => break,
+                    },
+                }
+            }
+
+            drop(source);
+            self.state.lock().listen_key = None;
+            if let Err(e) = self.listen_keys.close(&listen_key).await {
+                debug!("close listenKey failed: {}", e);
+            }
+        }
+    }
+}
+
+/// listenKey REST 调用，按 ExchangeType 选择现货 / U 本位 / 币本位接口，统一账户暂不支持
+pub struct BinanceListenKeyClient {
+    base: Arc<BinanceBaseService>,
+    exchange_type: ExchangeType,
+}
+
+impl BinanceListenKeyClient {
+    async fn call<T, F, Fut>(&self, func: F) -> Result<T, BinanceError>
+    where
+        T: Send + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, Box<dyn std::error::Error + Send + Sync>>> + Send + 'static,
+    {
+        // listenKey 的创建 / 续期由 keepalive 循环自行重试，这里不叠加 retry
+        let policy = ResiliencePolicy::new()
+            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+            .endpoint(ENDPOINT_LISTEN_KEY)
+            .group(USER_DATA_STREAM_GROUP);
+        let resilient = self
+            .base
+            .exchange
+            .resilient(ResilientCall::new(func), &policy);
+
+        resilient.call().await.map_err(BinanceError::from)
+    }
+
+    fn futures_client(&self) -> Result<Arc<BinanceFuturesAuthedClient>, BinanceError> {
+        let client = match self.exchange_type {
+            ExchangeType::Inverse => self.base.client.futures_inverse.clone(),
+            _ => self.base.client.futures.clone(),
+        };
+        client.ok_or_else(|| BinanceError::ClientNotInitialized("futures client".into()))
+    }
+
+    /// 统一账户需要 papi 的 `/papi/v1/listenKey`，不能使用 fapi 的 listenKey
+    fn portfolio_margin_unsupported() -> BinanceError {
+        BinanceError::InvalidParam(
+            "User data stream is not supported for portfolio margin accounts".into(),
+        )
+    }
+
+    pub async fn start(&self) -> Result<String, BinanceError> {
+        let key = match self.exchange_type {
+            ExchangeType::Spot => {
+                let client = self.base.client.spot.clone();
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.start_user_data_stream().await.map_err(boxed) }
+                })
+                .await?
+            }
+            ExchangeType::Inverse => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.inverse_start_user_data_stream().await.map_err(boxed) }
+                })
+                .await?
+            }
+            ExchangeType::PortfolioMargin => return Err(Self::portfolio_margin_unsupported()),
+            ExchangeType::Futures => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.start_user_data_stream().await.map_err(boxed) }
+                })
+                .await?
+            }
+        };
+
+        Ok(key.listen_key)
+    }
+
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<(), BinanceError> {
+        match self.exchange_type {
+            ExchangeType::Spot => {
+                let client = self.base.client.spot.clone();
+                let listen_key = listen_key.to_string();
+                self.call(move || {
+                    let client = client.clone();
+                    let listen_key = listen_key.clone();
+                    async move {
+                        client
+                            .keep_alive_user_data_stream(Query(&listen_key))
+                            .await
+                            .map_err(boxed)
+                    }
+                })
+                .await?;
+            }
+            ExchangeType::Inverse => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move {
+                        client
+                            .inverse_keep_alive_user_data_stream()
+                            .await
+                            .map_err(boxed)
+                    }
+                })
+                .await?;
+            }
+            ExchangeType::PortfolioMargin => return Err(Self::portfolio_margin_unsupported()),
+            ExchangeType::Futures => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.keep_alive_user_data_stream().await.map_err(boxed) }
+                })
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn close(&self, listen_key: &str) -> Result<(), BinanceError> {
+        match self.exchange_type {
+            ExchangeType::Spot => {
+                let client = self.base.client.spot.clone();
+                let listen_key = listen_key.to_string();
+                self.call(move || {
+                    let client = client.clone();
+                    let listen_key = listen_key.clone();
+                    async move {
+                        client
+                            .close_user_data_stream(Query(&listen_key))
+                            .await
+                            .map_err(boxed)
+                    }
+                })
+                .await?;
+            }
+            ExchangeType::Inverse => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.inverse_close_user_data_stream().await.map_err(boxed) }
+                })
+                .await?;
+            }
+            ExchangeType::PortfolioMargin => return Err(Self::portfolio_margin_unsupported()),
+            ExchangeType::Futures => {
+                let client = self.futures_client()?;
+                self.call(move || {
+                    let client = client.clone();
+                    async move { client.close_user_data_stream().await.map_err(boxed) }
+                })
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -35,6 +35,7 @@ impl BinanceMarketDataService {
             system_status() -> Result<BinanceSystemStatus, BinanceError>,
             exchange_info() -> Result<BinanceExchangeInfo,BinanceError>,
             future_exchange_info() -> Result<BinanceExchangeInfo,BinanceError>,
+            inverse_exchange_info() -> Result<BinanceExchangeInfo,BinanceError>,
             last_kline(pair: CurrencyPair, interval: KlineInterval) -> Result<BinanceKline, BinanceError>,
             klines_default_limit(pair: CurrencyPair,interval: KlineInterval) -> Result<Vec<BinanceKline>, BinanceError>,
             klines(pair: CurrencyPair,interval: KlineInterval,limit: Option<u16>,start_time: Option<u64>,end_time: Option<u64>) -> Result<Vec<BinanceKline>, BinanceError>,
@@ -1,6 +1,9 @@
 use crate::binance::BinanceAdapters;
 use crate::binance_exchange::BinanceExchange;
-use crate::binance_resilience::REQUEST_WEIGHT_RATE_LIMITER;
+use crate::binance_resilience::{
+    BinanceResilience, ENDPOINT_EXCHANGE_INFO, ENDPOINT_KLINES, ENDPOINT_PING, ENDPOINT_TIME,
+    MARKET_DATA_GROUP, REQUEST_WEIGHT_RATE_LIMITER,
+};
 use crate::client::binance_futures::BinanceFuturesAuthed;
 use crate::client::binance_spot::BinanceAuthed;
 use crate::dto::BinanceError;
@@ -11,7 +14,7 @@ use crate::dto::meta::exchange_info::BinanceExchangeInfo;
 use crate::service::binance_base_service::BinanceBaseService;
 use retrofit_rs::Query;
 use std::sync::Arc;
-use xchange_core::client::{ResilientCall, boxed};
+use xchange_core::client::{ResiliencePolicy, ResilientCall, boxed};
 use xchange_core::currency::currency_pair::CurrencyPair;
 use xchange_core::instrument::InstrumentKind;
 
@@ -29,28 +32,38 @@ impl MarketDataInner {
         })
     }
 
-    // todo 通用模板，把这种 auth client + resilient call + rate limiter + retry 封装成 可复用函数/宏，让所有 API 调用都可以一行写完
-    pub async fn ping(&self) -> Result<(), BinanceError> {
-        let limit = self
-            .base
+    /// 行情请求共用的 retry / 限流 / 熔断 / 并发隔离，按 spec 的开关装配
+    fn guard<T: Send + 'static>(
+        &self,
+        resilient: ResilientCall<T>,
+        policy: ResiliencePolicy<'_>,
+    ) -> ResilientCall<T> {
+        self.base
             .exchange
-            .resilience_registries
+            .resilient(resilient, &policy.group(MARKET_DATA_GROUP))
+    }
+
+    /// 按 endpoint 权重计入 requestWeight
+    fn policy(endpoint: &str) -> ResiliencePolicy<'_> {
+        ResiliencePolicy::new()
+            .retry(REQUEST_WEIGHT_RATE_LIMITER)
             .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+            .endpoint(endpoint)
+    }
 
+    pub async fn ping(&self) -> Result<(), BinanceError> {
         let auth_client = self.base.client.spot.clone();
 
-        let mut resilient = ResilientCall::new(move || {
+        let resilient = ResilientCall::new(move || {
             let auth_client = auth_client.clone();
             async move { auth_client.ping().await.map_err(boxed) }
         });
 
-        if let Some(limiter) = limit {
-            resilient = resilient.with_rate_limiter(limiter);
-        }
-
-        resilient.call().await?;
+        // ping 用于探测连通性，不重试
+        let policy = ResiliencePolicy::new()
+            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+            .endpoint(ENDPOINT_PING);
+        self.guard(resilient, policy).call().await?;
 
         Ok(())
     }
@@ -60,99 +73,74 @@ impl MarketDataInner {
     }
 
     pub async fn binance_time(&self) -> Result<BinanceTime, BinanceError> {
-        let retry = self
-            .base
-            .exchange
-            .resilience_registries
-            .retry(REQUEST_WEIGHT_RATE_LIMITER);
-        let limit = self
-            .base
-            .exchange
-            .resilience_registries
-            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+        let policy = Self::policy(ENDPOINT_TIME);
 
         let auth_client = self.base.client.spot.clone();
 
-        let mut resilient = ResilientCall::new(move || {
+        let resilient = ResilientCall::new(move || {
             let auth_client = auth_client.clone();
             async move { auth_client.time().await.map_err(boxed) }
         });
 
-        if let Some(retry) = retry {
-            resilient = resilient.with_retry(retry)
-        }
-        if let Some(limiter) = limit {
-            resilient = resilient.with_rate_limiter(limiter);
-        }
-
-        resilient.call().await.map_err(|e| BinanceError::from(e))
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
     }
 
     pub async fn exchange_info(&self) -> Result<BinanceExchangeInfo, BinanceError> {
-        let retry = self
-            .base
-            .exchange
-            .resilience_registries
-            .retry(REQUEST_WEIGHT_RATE_LIMITER);
-        let limit = self
-            .base
-            .exchange
-            .resilience_registries
-            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+        let policy = Self::policy(ENDPOINT_EXCHANGE_INFO);
 
         let auth_client = self.base.client.spot.clone();
 
-        let mut resilient = ResilientCall::new(move || {
+        let resilient = ResilientCall::new(move || {
             let auth_client = auth_client.clone();
             async move { auth_client.exchange_info().await.map_err(boxed) }
         });
 
-        if let Some(retry) = retry {
-            resilient = resilient.with_retry(retry)
-        }
-        if let Some(limiter) = limit {
-            resilient = resilient.with_rate_limiter(limiter);
-        }
-
-        resilient.call().await.map_err(|e| BinanceError::from(e))
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
     }
 
     pub async fn future_exchange_info(&self) -> Result<BinanceExchangeInfo, BinanceError> {
-        let retry = self
-            .base
-            .exchange
-            .resilience_registries
-            .retry(REQUEST_WEIGHT_RATE_LIMITER);
-        let limit = self
-            .base
-            .exchange
-            .resilience_registries
-            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+        let policy = Self::policy(ENDPOINT_EXCHANGE_INFO);
 
         let auth_client =
             self.base.client.futures.clone().ok_or_else(|| {
                 boxed(BinanceError::ClientNotInitialized("futures client".into()))
             })?;
 
-        let mut resilient = ResilientCall::new(move || {
+        let resilient = ResilientCall::new(move || {
             let auth_client = auth_client.clone();
             async move { auth_client.exchange_info().await.map_err(boxed) }
         });
 
-        if let Some(retry) = retry {
-            resilient = resilient.with_retry(retry)
-        }
-        if let Some(limiter) = limit {
-            resilient = resilient.with_rate_limiter(limiter);
-        }
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
+    }
+
+    pub async fn inverse_exchange_info(&self) -> Result<BinanceExchangeInfo, BinanceError> {
+        let policy = Self::policy(ENDPOINT_EXCHANGE_INFO);
+
+        let auth_client = self.base.client.futures_inverse.clone().ok_or_else(|| {
+            boxed(BinanceError::ClientNotInitialized(
+                "inverse futures client".into(),
+            ))
+        })?;
+
+        let resilient = ResilientCall::new(move || {
+            let auth_client = auth_client.clone();
+            async move { auth_client.inverse_exchange_info().await.map_err(boxed) }
+        });
 
-        resilient.call().await.map_err(|e| BinanceError::from(e))
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
     }
 
     pub async fn last_kline(
@@ -184,19 +172,7 @@ impl MarketDataInner {
         start_time: Option<u64>,
         end_time: Option<u64>,
     ) -> Result<Vec<BinanceKline>, BinanceError> {
-        // Resilience 配置
-        let retry = self
-            .base
-            .exchange
-            .resilience_registries
-            .retry(REQUEST_WEIGHT_RATE_LIMITER);
-        let limiter = self
-            .base
-            .exchange
-            .resilience_registries
-            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+        let policy = Self::policy(ENDPOINT_KLINES);
 
         // 提前准备常量数据
         let spot_client = self.base.client.spot.clone();
@@ -210,7 +186,7 @@ impl MarketDataInner {
         let end_q = end_time.unwrap_or(u64::MAX);
 
         // ResilientCall
-        let mut resilient = ResilientCall::new({
+        let resilient = ResilientCall::new({
             // 全部 clone，闭包里直接 move
             let spot_client = spot_client.clone();
             let instrument_kind = instrument_kind.clone();
@@ -251,15 +227,10 @@ impl MarketDataInner {
             }
         });
 
-        // 应用 retry / rate limiter
-        if let Some(r) = retry {
-            resilient = resilient.with_retry(r);
-        }
-        if let Some(l) = limiter {
-            resilient = resilient.with_rate_limiter(l);
-        }
-
-        resilient.call().await.map_err(|e| BinanceError::from(e))
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
     }
 
     pub async fn future_last_kline(
@@ -293,19 +264,9 @@ impl MarketDataInner {
         start_time: Option<u64>,
         end_time: Option<u64>,
     ) -> Result<Vec<BinanceKline>, BinanceError> {
-        // Resilience 配置
-        let retry = self
-            .base
-            .exchange
-            .resilience_registries
-            .retry(REQUEST_WEIGHT_RATE_LIMITER);
-        let limiter = self
-            .base
-            .exchange
-            .resilience_registries
-            .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
-            .as_ref()
-            .cloned();
+        let policy = Self::policy(ENDPOINT_KLINES).weight(
+            BinanceResilience::futures_klines_weight(limit.unwrap_or(500)),
+        );
 
         // 提前准备常量数据
         let future_client =
@@ -326,7 +287,7 @@ impl MarketDataInner {
         let end_q = end_time.unwrap_or(u64::MAX);
 
         // ResilientCall
-        let mut resilient = ResilientCall::new({
+        let resilient = ResilientCall::new({
             // 全部 clone，闭包里直接 move
             let future_client = future_client.clone();
             let instrument_kind = instrument_kind.clone();
@@ -367,14 +328,9 @@ impl MarketDataInner {
             }
         });
 
-        // 应用 retry / rate limiter
-        if let Some(r) = retry {
-            resilient = resilient.with_retry(r);
-        }
-        if let Some(l) = limiter {
-            resilient = resilient.with_rate_limiter(l);
-        }
-
-        resilient.call().await.map_err(|e| BinanceError::from(e))
+        self.guard(resilient, policy)
+            .call()
+            .await
+            .map_err(|e| BinanceError::from(e))
     }
 }
@@ -1,6 +1,13 @@
 use crate::dto::BinanceError;
 use base64::{Engine as _, engine::general_purpose};
 use ed25519_dalek::{Signer, SigningKey, VerifyingKey};
+use pkcs8::{
+    DecodePrivateKey, EncryptedPrivateKeyInfo, ObjectIdentifier, PrivateKeyInfo, SecretDocument,
+};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::signature::SignatureEncoding;
+use sha2::Sha256;
 use std::sync::Arc;
 use xchange_core::rescu::HttpError;
 use xchange_core::rescu::params_digest::{BaseParamsDigest, HmacAlgorithm, ParamsDigest};
@@ -13,38 +20,16 @@ pub struct BinanceHmacDigest {
 }
 
 impl BinanceHmacDigest {
-    pub fn new(secret_base64: &str) -> Result<Self, BinanceError> {
-        let base = BaseParamsDigest::new(secret_base64, HmacAlgorithm::Sha256)?;
+    /// Binance 的 HMAC secret 是原始字符串，直接作为 key
+    pub fn new(secret: &str) -> Result<Self, BinanceError> {
+        let base = BaseParamsDigest::from_raw(secret, HmacAlgorithm::Sha256)?;
         Ok(Self { inner: base })
     }
 
-    /// 构建交易所特定输入字符串
-    fn build_input_string(method: &str, query: &[(String, String)], body: Option<&str>) -> String {
-        // 1. 构造 query_string（过滤 signature）
-        let query_str = BaseParamsDigest::build_query_string(
-            &query
-                .iter()
-                .filter(|(k, _)| k != "signature")
-                .cloned()
-                .collect::<Vec<_>>(),
-        );
-
-        // 2. Binance 特定规则
-        match method.to_uppercase().as_str() {
-            "GET" | "DELETE" => query_str,
-
-            // POST 场景通常 body 参与签名
-            "POST" => {
-                if let Some(b) = body {
-                    query_str + b
-                } else {
-                    query_str
-                }
-            }
-
-            // 其他方法（PUT, PATCH...）——目前 Binance 不用
-            _ => query_str,
-        }
+    /// secret 以 base64 形式保存时使用
+    pub fn from_base64(secret_base64: &str) -> Result<Self, BinanceError> {
+        let base = BaseParamsDigest::new(secret_base64, HmacAlgorithm::Sha256)?;
+        Ok(Self { inner: base })
     }
 }
 
@@ -55,15 +40,13 @@ impl ParamsDigest for BinanceHmacDigest {
         query: &[(String, String)],
         body: Option<&str>,
     ) -> Result<String, HttpError> {
-        if !["GET", "POST", "DELETE"].contains(&method) {
-            return Err(HttpError::UnsupportedMethod(format!(
-                "Unsupported method: {}",
-                method
-            )));
-        }
+        check_method(method)?;
+        self.digest_payload(&build_input_string(query, body))
+    }
 
-        let input = Self::build_input_string(method, query, body);
-        Ok(self.inner.digest_bytes(input.as_bytes()))
+    /// 十六进制签名
+    fn digest_payload(&self, payload: &str) -> Result<String, HttpError> {
+        Ok(self.inner.digest_str(payload))
     }
 }
 
@@ -83,27 +66,16 @@ impl BinanceEd25519Digest {
             .try_into()
             .map_err(|_| BinanceError::InvalidKey("Secret key must be 32 bytes".to_string()))?;
 
-        let signing_key = SigningKey::from_bytes(&secret_bytes);
-        let verifying_key = VerifyingKey::from(&signing_key);
+        Ok(Self::from_signing_key(SigningKey::from_bytes(
+            &secret_bytes,
+        )))
+    }
 
-        Ok(Self {
+    fn from_signing_key(signing_key: SigningKey) -> Self {
+        let verifying_key = VerifyingKey::from(&signing_key);
+        Self {
             signing_key: Arc::new(signing_key),
             verifying_key: Arc::new(verifying_key),
-        })
-    }
-
-    fn build_input_string(method: &str, query: &[(String, String)], body: Option<&str>) -> String {
-        let query_str = query
-            .iter()
-            .filter(|(k, _)| k != "signature")
-            .map(|(k, v)| format!("{}={}", k, v))
-            .collect::<Vec<_>>()
-            .join("&");
-
-        match method.to_ascii_uppercase().as_str() {
-            "GET" | "DELETE" => query_str,
-            "POST" | "PUT" => query_str + body.unwrap_or(""),
-            _ => query_str,
         }
     }
 
@@ -120,14 +92,142 @@ impl ParamsDigest for BinanceEd25519Digest {
         query: &[(String, String)],
         body: Option<&str>,
     ) -> Result<String, HttpError> {
-        let input = Self::build_input_string(method, query, body);
-        Ok(self.sign(input.as_bytes()))
+        check_method(method)?;
+        self.digest_payload(&build_input_string(query, body))
     }
+
+    /// base64 签名，放入 query 前需要 URL 编码
+    fn digest_payload(&self, payload: &str) -> Result<String, HttpError> {
+        Ok(self.sign(payload.as_bytes()))
+    }
+}
+
+/// -------------------------
+/// BinanceRsaDigest（RSASSA-PKCS1-v1_5 + SHA-256）
+/// -------------------------
+pub struct BinanceRsaDigest {
+    signing_key: rsa::pkcs1v15::SigningKey<Sha256>,
+}
+
+impl BinanceRsaDigest {
+    /// PKCS#1（BEGIN RSA PRIVATE KEY）或 PKCS#8 PEM，加密的 PKCS#8 需要 passphrase
+    pub fn from_pem(pem: &str, passphrase: Option<&str>) -> Result<Self, BinanceError> {
+        let key = if pem.contains("BEGIN RSA PRIVATE KEY") {
+            RsaPrivateKey::from_pkcs1_pem(pem).map_err(invalid_key)?
+        } else {
+            let der = decode_pkcs8_pem(pem, passphrase)?;
+            RsaPrivateKey::from_pkcs8_der(der.as_bytes()).map_err(invalid_key)?
+        };
+        Ok(Self::from_private_key(key))
+    }
+
+    fn from_private_key(key: RsaPrivateKey) -> Self {
+        Self {
+            signing_key: rsa::pkcs1v15::SigningKey::new(key),
+        }
+    }
+}
+
+impl ParamsDigest for BinanceRsaDigest {
+    fn digest_params(
+        &self,
+        method: &str,
+        query: &[(String, String)],
+        body: Option<&str>,
+    ) -> Result<String, HttpError> {
+        check_method(method)?;
+        self.digest_payload(&build_input_string(query, body))
+    }
+
+    /// base64 签名，放入 query 前需要 URL 编码
+    fn digest_payload(&self, payload: &str) -> Result<String, HttpError> {
+        let signature = self.signing_key.sign(payload.as_bytes());
+        Ok(general_purpose::STANDARD.encode(signature.to_bytes()))
+    }
+}
+
+const RSA_ENCRYPTION_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+
+/// 按 PEM 私钥的算法选择 RSA 或 Ed25519 签名
+pub fn digest_from_pem(
+    pem: &str,
+    passphrase: Option<&str>,
+) -> Result<Arc<dyn ParamsDigest + Send + Sync>, BinanceError> {
+    if pem.contains("BEGIN RSA PRIVATE KEY") {
+        return Ok(Arc::new(BinanceRsaDigest::from_pem(pem, passphrase)?));
+    }
+
+    let der = decode_pkcs8_pem(pem, passphrase)?;
+    let algorithm = PrivateKeyInfo::try_from(der.as_bytes())
+        .map_err(invalid_key)?
+        .algorithm
+        .oid;
+    if algorithm == RSA_ENCRYPTION_OID {
+        Ok(Arc::new(BinanceRsaDigest::from_private_key(
+            RsaPrivateKey::from_pkcs8_der(der.as_bytes()).map_err(invalid_key)?,
+        )))
+    } else if algorithm == ED25519_OID {
+        Ok(Arc::new(BinanceEd25519Digest::from_signing_key(
+            SigningKey::from_pkcs8_der(der.as_bytes()).map_err(invalid_key)?,
+        )))
+    } else {
+        Err(BinanceError::InvalidKey(format!(
+            "unsupported private key algorithm: {}",
+            algorithm
+        )))
+    }
+}
+
+/// 解码 PKCS#8 PEM，加密的私钥用 passphrase 解密
+fn decode_pkcs8_pem(pem: &str, passphrase: Option<&str>) -> Result<SecretDocument, BinanceError> {
+    let (label, document) = SecretDocument::from_pem(pem.trim()).map_err(invalid_key)?;
+    match label {
+        "ENCRYPTED PRIVATE KEY" => {
+            let passphrase = passphrase.ok_or_else(|| {
+                BinanceError::InvalidKey("encrypted private key requires a passphrase".into())
+            })?;
+            EncryptedPrivateKeyInfo::try_from(document.as_bytes())
+                .and_then(|info| info.decrypt(passphrase))
+                .map_err(invalid_key)
+        }
+        "PRIVATE KEY" => Ok(document),
+        label => Err(BinanceError::InvalidKey(format!(
+            "unsupported PEM label: {}",
+            label
+        ))),
+    }
+}
+
+fn invalid_key(e: impl std::fmt::Display) -> BinanceError {
+    BinanceError::InvalidKey(e.to_string())
+}
+
+fn check_method(method: &str) -> Result<(), HttpError> {
+    match method.to_ascii_uppercase().as_str() {
+        "GET" | "POST" | "PUT" | "DELETE" => Ok(()),
+        _ => Err(HttpError::UnsupportedMethod(format!(
+            "Unsupported method: {}",
+            method
+        ))),
+    }
+}
+
+/// Binance 的签名输入：query string（不含 signature）后直接拼接 body，所有方法相同
+fn build_input_string(query: &[(String, String)], body: Option<&str>) -> String {
+    let query_str = BaseParamsDigest::build_query_string(
+        &query
+            .iter()
+            .filter(|(k, _)| k != "signature")
+            .cloned()
+            .collect::<Vec<_>>(),
+    );
+    query_str + body.unwrap_or("")
 }
 
 /// 批量代理 Client 方法到 Service
 /// Usage:
-/// ```rust
+/// ```ignore
 ///  delegate_client! {
 ///         inner, {
 ///             ping() -> Result<(), BinanceError>,
@@ -170,5 +270,9 @@ macro_rules! delegate_client {
 pub mod account_service;
 pub mod binance_account_service_raw;
 pub mod binance_base_service;
+pub mod binance_user_data_stream;
 pub mod market_data_service;
 pub mod market_data_service_inner;
+pub mod streaming_account_service;
+pub mod streaming_market_data_service;
+pub mod streaming_trade_service;
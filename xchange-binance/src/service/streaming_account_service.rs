@@ -0,0 +1,132 @@
+use crate::binance::BinanceAdapters;
+use crate::dto::BinanceError;
+use crate::dto::account::binance_account_event::BinanceAccountEvent;
+use crate::service::binance_user_data_stream::BinanceUserDataStream;
+use async_trait::async_trait;
+use futures::{StreamExt, future, stream};
+use parking_lot::RwLock;
+use std::any::Any;
+use std::sync::Arc;
+use xchange_core::currency::currency::Currency;
+use xchange_core::dto::account::balance_update::BalanceUpdate;
+use xchange_core::dto::account::open_position::OpenPosition;
+use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
+use xchange_core::error::exchange_error::ExchangeError;
+use xchange_core::exchange::ExchangeType;
+use xchange_core::instrument::{InstrumentDTO, InstrumentKind};
+use xchange_core::service::account::streaming_account_service::StreamingAccountService;
+use xchange_core::service::{BaseService, ExchangeStream};
+
+/// Binance Streaming Account Service
+///
+/// 现货解析 `outboundAccountPosition` / `balanceUpdate`，合约解析 `ACCOUNT_UPDATE`。
+/// 与 `BinanceStreamingTradeService` 共用同一个 user data stream。
+#[derive(Clone)]
+pub struct BinanceStreamingAccountService {
+    user_data: Arc<BinanceUserDataStream>,
+    exchange_type: ExchangeType,
+    /// 事件未指定 instrument 时按 exchangeInfo 解析 symbol
+    meta_data: Arc<RwLock<ExchangeMetaData>>,
+}
+
+impl BinanceStreamingAccountService {
+    pub fn new(
+        user_data: Arc<BinanceUserDataStream>,
+        exchange_type: ExchangeType,
+        meta_data: Arc<RwLock<ExchangeMetaData>>,
+    ) -> Self {
+        Self {
+            user_data,
+            exchange_type,
+            meta_data,
+        }
+    }
+
+    pub fn user_data_stream(&self) -> Arc<BinanceUserDataStream> {
+        self.user_data.clone()
+    }
+
+    /// 订阅账户事件，每个事件可展开为多条 `T`
+    fn account_events<T, F>(&self, convert: F) -> ExchangeStream<T>
+    where
+        T: Send + 'static,
+        F: Fn(&BinanceAccountEvent) -> Vec<T> + Send + 'static,
+    {
+        self.user_data
+            .subscribe()
+            .flat_map(move |event| {
+                let items: Vec<Result<T, ExchangeError>> = match event {
+                    Ok(data) => match BinanceAccountEvent::from_value(data) {
+                        None => Vec::new(),
+                        Some(Err(e)) => vec![Err(BinanceError::Json(e).into())],
+                        Some(Ok(event)) => convert(&event).into_iter().map(Ok).collect(),
+                    },
+                    Err(skipped) => vec![Err(ExchangeError::Message(format!(
+                        "user data stream lagged behind, {} events dropped",
+                        skipped
+                    )))],
+                };
+                stream::iter(items)
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl StreamingAccountService for BinanceStreamingAccountService {
+    async fn balance_changes(
+        &self,
+        currency: Option<&Currency>,
+    ) -> Result<ExchangeStream<BalanceUpdate>, ExchangeError> {
+        let code = currency.map(|currency| currency.code.clone());
+
+        Ok(self
+            .account_events(|event| event.to_balance_updates())
+            .filter(move |update| {
+                let keep = match (update, &code) {
+                    (Ok(update), Some(code)) => &update.currency.code == code,
+                    _ => true,
+                };
+                future::ready(keep)
+            })
+            .boxed())
+    }
+
+    async fn position_changes(
+        &self,
+        instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<OpenPosition>, ExchangeError> {
+        if matches!(self.exchange_type, ExchangeType::Spot) {
+            return Err(ExchangeError::Message(
+                "position changes are not available for spot".into(),
+            ));
+        }
+
+        let filter = instrument.map(|instrument| {
+            let kind = InstrumentKind::from(instrument.clone());
+            let symbol = match self.exchange_type {
+                ExchangeType::Inverse => BinanceAdapters::to_inverse_symbol(&kind),
+                _ => BinanceAdapters::to_symbol(&kind),
+            };
+            (symbol, instrument.clone())
+        });
+
+        let meta_data = self.meta_data.clone();
+
+        Ok(self.account_events(move |event| {
+            event.to_open_positions(|symbol| match &filter {
+                Some((expected, instrument)) if expected == symbol => Some(instrument.clone()),
+                Some(_) => None,
+                None => {
+                    BinanceAdapters::adapt_symbol_with_meta_data(symbol, true, &meta_data.read())
+                }
+            })
+        }))
+    }
+}
+
+impl BaseService for BinanceStreamingAccountService {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
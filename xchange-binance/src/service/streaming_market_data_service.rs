@@ -0,0 +1,161 @@
+use crate::binance::BinanceAdapters;
+use crate::client::binance_websocket::BinanceWebSocketClient;
+use crate::dto::BinanceError;
+use crate::dto::marketdata::KlineInterval;
+use crate::dto::marketdata::binance_stream_event::{
+    BinanceDepthUpdateEvent, BinanceKlineEvent, BinancePartialDepthEvent, BinanceTickerEvent,
+    BinanceTradeEvent,
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use std::any::Any;
+use std::sync::Arc;
+use xchange_core::dto::marketdata::candle_stick::CandleStick;
+use xchange_core::dto::marketdata::order_book::OrderBook;
+use xchange_core::dto::marketdata::order_book_update::OrderBookUpdate;
+use xchange_core::dto::marketdata::ticker::Ticker;
+use xchange_core::dto::marketdata::trade::Trade;
+use xchange_core::error::exchange_error::ExchangeError;
+use xchange_core::exchange::ExchangeType;
+use xchange_core::instrument::{InstrumentDTO, InstrumentKind};
+use xchange_core::service::marketdata::streaming_market_data_service::StreamingMarketDataService;
+use xchange_core::service::{BaseService, ExchangeStream};
+
+/// 有限档深度的档位数（Binance 支持 5 / 10 / 20）
+const PARTIAL_DEPTH_LEVELS: u32 = 20;
+
+/// Binance Streaming Market Data Service
+///
+/// 所有订阅共用一个 combined stream 连接
+#[derive(Clone)]
+pub struct BinanceStreamingMarketDataService {
+    client: Arc<BinanceWebSocketClient>,
+    exchange_type: ExchangeType,
+}
+
+impl BinanceStreamingMarketDataService {
+    pub fn new(client: Arc<BinanceWebSocketClient>, exchange_type: ExchangeType) -> Self {
+        Self {
+            client,
+            exchange_type,
+        }
+    }
+
+    pub fn client(&self) -> Arc<BinanceWebSocketClient> {
+        self.client.clone()
+    }
+
+    /// stream 名称中的 symbol 需为小写，例如 `btcusdt@trade`
+    fn stream_symbol(&self, instrument: &InstrumentDTO) -> String {
+        let kind = InstrumentKind::from(instrument.clone());
+        let symbol = match self.exchange_type {
+            ExchangeType::Inverse => BinanceAdapters::to_inverse_symbol(&kind),
+            _ => BinanceAdapters::to_symbol(&kind),
+        };
+        symbol.to_lowercase()
+    }
+
+    /// 订阅原始 stream，并把 `data` 反序列化为 `E` 后转换为通用 DTO
+    fn subscribe<E, T, F>(&self, stream: String, convert: F) -> ExchangeStream<T>
+    where
+        E: DeserializeOwned,
+        T: Send + 'static,
+        F: Fn(E) -> T + Send + 'static,
+    {
+        self.client
+            .subscribe(&stream)
+            .map(move |event| match event {
+                Ok(data) => serde_json::from_value::<E>(data)
+                    .map(&convert)
+                    .map_err(|e| BinanceError::Json(e).into()),
+                Err(skipped) => Err(ExchangeError::Message(format!(
+                    "{} lagged behind, {} messages dropped",
+                    stream, skipped
+                ))),
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl StreamingMarketDataService for BinanceStreamingMarketDataService {
+    async fn ticker_stream(
+        &self,
+        instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Ticker>, ExchangeError> {
+        let stream = format!("{}@ticker", self.stream_symbol(instrument));
+        let instrument = instrument.clone();
+        Ok(self.subscribe(stream, move |event: BinanceTickerEvent| {
+            event.to_ticker(instrument.clone())
+        }))
+    }
+
+    async fn trade_stream(
+        &self,
+        instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Trade>, ExchangeError> {
+        // 合约不提供逐笔 trade stream，使用归集成交
+        let suffix = match self.exchange_type {
+            ExchangeType::Spot => "trade",
+            _ => "aggTrade",
+        };
+        let stream = format!("{}@{}", self.stream_symbol(instrument), suffix);
+        let instrument = instrument.clone();
+        Ok(self.subscribe(stream, move |event: BinanceTradeEvent| {
+            event.to_trade(instrument.clone())
+        }))
+    }
+
+    async fn order_book_stream(
+        &self,
+        instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<OrderBook>, ExchangeError> {
+        let stream = format!(
+            "{}@depth{}@100ms",
+            self.stream_symbol(instrument),
+            PARTIAL_DEPTH_LEVELS
+        );
+        let instrument = instrument.clone();
+        Ok(
+            self.subscribe(stream, move |event: BinancePartialDepthEvent| {
+                event.to_order_book(instrument.clone())
+            }),
+        )
+    }
+
+    async fn order_book_update_stream(
+        &self,
+        instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Vec<OrderBookUpdate>>, ExchangeError> {
+        let stream = format!("{}@depth@100ms", self.stream_symbol(instrument));
+        let instrument = instrument.clone();
+        Ok(
+            self.subscribe(stream, move |event: BinanceDepthUpdateEvent| {
+                event.to_order_book_updates(instrument.clone())
+            }),
+        )
+    }
+
+    async fn candle_stick_stream(
+        &self,
+        instrument: &InstrumentDTO,
+        period_secs: u64,
+    ) -> Result<ExchangeStream<CandleStick>, ExchangeError> {
+        let interval = KlineInterval::from_secs(period_secs).ok_or_else(|| {
+            BinanceError::InvalidParam(format!("Unsupported kline period: {}s", period_secs))
+        })?;
+        let stream = format!(
+            "{}@kline_{}",
+            self.stream_symbol(instrument),
+            interval.code()
+        );
+        Ok(self.subscribe(stream, |event: BinanceKlineEvent| event.to_candle_stick()))
+    }
+}
+
+impl BaseService for BinanceStreamingMarketDataService {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
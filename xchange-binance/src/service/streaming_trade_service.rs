@@ -0,0 +1,128 @@
+use crate::binance::BinanceAdapters;
+use crate::dto::BinanceError;
+use crate::dto::trade::binance_user_data_event::BinanceOrderEvent;
+use crate::service::binance_user_data_stream::BinanceUserDataStream;
+use async_trait::async_trait;
+use futures::{StreamExt, future};
+use parking_lot::RwLock;
+use std::any::Any;
+use std::sync::Arc;
+use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
+use xchange_core::dto::order::Order;
+use xchange_core::dto::trade::user_trade::UserTrade;
+use xchange_core::error::exchange_error::ExchangeError;
+use xchange_core::exchange::ExchangeType;
+use xchange_core::instrument::{InstrumentDTO, InstrumentKind};
+use xchange_core::service::trade::streaming_trade_service::StreamingTradeService;
+use xchange_core::service::{BaseService, ExchangeStream};
+
+/// Binance Streaming Trade Service
+///
+/// 现货解析 `executionReport`，合约解析 `ORDER_TRADE_UPDATE`
+#[derive(Clone)]
+pub struct BinanceStreamingTradeService {
+    user_data: Arc<BinanceUserDataStream>,
+    exchange_type: ExchangeType,
+    /// 事件未指定 instrument 时按 exchangeInfo 解析 symbol
+    meta_data: Arc<RwLock<ExchangeMetaData>>,
+}
+
+impl BinanceStreamingTradeService {
+    pub fn new(
+        user_data: Arc<BinanceUserDataStream>,
+        exchange_type: ExchangeType,
+        meta_data: Arc<RwLock<ExchangeMetaData>>,
+    ) -> Self {
+        Self {
+            user_data,
+            exchange_type,
+            meta_data,
+        }
+    }
+
+    pub fn user_data_stream(&self) -> Arc<BinanceUserDataStream> {
+        self.user_data.clone()
+    }
+
+    /// 订阅订单事件并转换为 `T`，instrument 为 None 时按 symbol 推断交易对
+    fn order_events<T, F>(
+        &self,
+        instrument: Option<&InstrumentDTO>,
+        convert: F,
+    ) -> ExchangeStream<T>
+    where
+        T: Send + 'static,
+        F: Fn(&BinanceOrderEvent, InstrumentDTO) -> Option<T> + Send + 'static,
+    {
+        let filter = instrument.map(|instrument| {
+            let kind = InstrumentKind::from(instrument.clone());
+            let symbol = match self.exchange_type {
+                ExchangeType::Inverse => BinanceAdapters::to_inverse_symbol(&kind),
+                _ => BinanceAdapters::to_symbol(&kind),
+            };
+            (symbol, instrument.clone())
+        });
+        let futures = !matches!(self.exchange_type, ExchangeType::Spot);
+        let meta_data = self.meta_data.clone();
+
+        self.user_data
+            .subscribe()
+            .filter_map(move |event| {
+                let item = match event {
+                    Ok(data) => match BinanceOrderEvent::from_value(data) {
+                        None => None,
+                        Some(Err(e)) => Some(Err(BinanceError::Json(e).into())),
+                        Some(Ok(event)) => {
+                            let instrument = match &filter {
+                                Some((symbol, instrument)) if symbol == event.symbol() => {
+                                    Some(instrument.clone())
+                                }
+                                Some(_) => None,
+                                None => BinanceAdapters::adapt_symbol_with_meta_data(
+                                    event.symbol(),
+                                    futures,
+                                    &meta_data.read(),
+                                ),
+                            };
+                            instrument
+                                .and_then(|instrument| convert(&event, instrument))
+                                .map(Ok)
+                        }
+                    },
+                    Err(skipped) => Some(Err(ExchangeError::Message(format!(
+                        "user data stream lagged behind, {} events dropped",
+                        skipped
+                    )))),
+                };
+                future::ready(item)
+            })
+            .boxed()
+    }
+}
+
+#[async_trait]
+impl StreamingTradeService for BinanceStreamingTradeService {
+    async fn order_changes(
+        &self,
+        instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<Order>, ExchangeError> {
+        Ok(self.order_events(instrument, |event, instrument| {
+            Some(event.to_order(instrument))
+        }))
+    }
+
+    async fn user_trades(
+        &self,
+        instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<UserTrade>, ExchangeError> {
+        Ok(self.order_events(instrument, |event, instrument| {
+            event.to_user_trade(instrument)
+        }))
+    }
+}
+
+impl BaseService for BinanceStreamingTradeService {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -0,0 +1,110 @@
+use rust_decimal::Decimal;
+use serde_json::json;
+use xchange_binance::binance::BinanceAdapters;
+use xchange_binance::dto::account::binance_account_event::BinanceAccountEvent;
+use xchange_core::dto::account::open_position::{MarginMode, PositionType};
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_outbound_account_position() {
+    let data = json!({
+        "e": "outboundAccountPosition", "E": 1700000000100i64, "u": 1700000000099i64,
+        "B": [
+            { "a": "BTC", "f": "1.50000000", "l": "0.50000000" },
+            { "a": "USDT", "f": "1000.00", "l": "0.00" }
+        ]
+    });
+
+    let event = BinanceAccountEvent::from_value(data).unwrap().unwrap();
+    let updates = event.to_balance_updates();
+    assert_eq!(updates.len(), 2);
+
+    let btc = &updates[0];
+    assert_eq!(btc.wallet_id, BinanceAdapters::SPOT_WALLET_ID);
+    assert_eq!(btc.currency.code, "BTC");
+    assert_eq!(btc.delta, None);
+    let balance = btc.balance.as_ref().unwrap();
+    assert_eq!(balance.total, Some(dec("2")));
+    assert_eq!(balance.available, Some(dec("1.5")));
+    assert_eq!(balance.frozen, Some(dec("0.5")));
+    assert!(event.to_open_positions(|_| None).is_empty());
+}
+
+#[test]
+fn test_balance_update_delta() {
+    let data = json!({
+        "e": "balanceUpdate", "E": 1700000000100i64, "a": "ETH", "d": "-0.25",
+        "T": 1700000000099i64
+    });
+
+    let event = BinanceAccountEvent::from_value(data).unwrap().unwrap();
+    let updates = event.to_balance_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].currency.code, "ETH");
+    assert_eq!(updates[0].delta, Some(dec("-0.25")));
+    assert!(updates[0].balance.is_none());
+}
+
+#[test]
+fn test_futures_account_update() {
+    let data = json!({
+        "e": "ACCOUNT_UPDATE", "E": 1700000000100i64, "T": 1700000000098i64,
+        "a": {
+            "m": "ORDER",
+            "B": [ { "a": "USDT", "wb": "122624.12", "cw": "100.12", "bc": "50.12" } ],
+            "P": [
+                { "s": "BTCUSDT", "pa": "-0.010", "ep": "31000", "cr": "0", "up": "-1.5",
+                  "mt": "isolated", "iw": "30", "ps": "BOTH" },
+                { "s": "ETHUSDT", "pa": "2", "ep": "1800", "cr": "0", "up": "3",
+                  "mt": "cross", "iw": "0", "ps": "LONG" }
+            ]
+        }
+    });
+
+    let event = BinanceAccountEvent::from_value(data).unwrap().unwrap();
+
+    let updates = event.to_balance_updates();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].wallet_id, BinanceAdapters::FUTURES_WALLET_ID);
+    assert_eq!(updates[0].delta, Some(dec("50.12")));
+    let balance = updates[0].balance.as_ref().unwrap();
+    assert_eq!(balance.total, Some(dec("122624.12")));
+    assert_eq!(balance.available, None);
+    assert_eq!(balance.frozen, None);
+
+    let positions = event.to_open_positions(|symbol| BinanceAdapters::adapt_symbol(symbol, true));
+    assert_eq!(positions.len(), 2);
+
+    // 单向持仓模式下负数量为空头
+    assert_eq!(positions[0].type_, PositionType::Short);
+    assert_eq!(positions[0].margin_mode, Some(MarginMode::Isolated));
+    assert_eq!(positions[0].size, Some(dec("0.01")));
+    assert_eq!(positions[0].price, Some(dec("31000")));
+    assert_eq!(positions[0].un_realised_pnl, Some(dec("-1.5")));
+
+    assert_eq!(positions[1].type_, PositionType::Long);
+    assert_eq!(positions[1].margin_mode, Some(MarginMode::Cross));
+}
+
+#[test]
+fn test_unresolved_symbols_are_skipped() {
+    let data = json!({
+        "e": "ACCOUNT_UPDATE", "E": 1, "T": 1,
+        "a": { "m": "FUNDING_FEE", "P": [
+            { "s": "BTCUSDT", "pa": "1", "ep": "1", "up": "0", "mt": "cross", "ps": "BOTH" }
+        ] }
+    });
+
+    let event = BinanceAccountEvent::from_value(data).unwrap().unwrap();
+    assert!(event.to_balance_updates().is_empty());
+    assert!(event.to_open_positions(|_| None).is_empty());
+}
+
+#[test]
+fn test_non_account_events_are_ignored() {
+    let data = json!({ "e": "executionReport", "E": 1, "s": "BTCUSDT" });
+    assert!(BinanceAccountEvent::from_value(data).is_none());
+}
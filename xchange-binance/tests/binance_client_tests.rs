@@ -1,15 +1,33 @@
 use retrofit_rs::RetrofitError;
-use xchange_binance::client::BinanceClient;
+use std::path::Path;
 use xchange_binance::client::binance_spot::BinanceAuthed;
+use xchange_binance::client::{BinanceClient, BinanceClientBuilder};
 use xchange_core::exchange::ExchangeType;
+use xchange_core::rescu::replay::fixture_transport;
+
+/// 默认回放 tests/fixtures 下录制的响应，`XCHANGE_HTTP_MODE=record` 重新录制，`live` 直连
+fn client(base_url: &str, exchange_type: Option<ExchangeType>, fixture: &str) -> BinanceClient {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/binance_client")
+        .join(fixture);
+
+    let mut builder = BinanceClientBuilder::new(base_url);
+    if let Some(exchange_type) = exchange_type {
+        builder = builder.exchange_type(exchange_type);
+    }
+    if let Some(transport) = fixture_transport(path).expect("load HTTP fixture") {
+        builder = builder.transport(transport);
+    }
+    builder.build().expect("build BinanceClient")
+}
 
 #[tokio::test]
 async fn test_binance_ping() -> Result<(), RetrofitError> {
     let base_url = "https://api.binance.com";
 
-    let client = BinanceClient::new_with_exchange_type(base_url, None, Some(ExchangeType::Spot))?;
+    let client = client(base_url, Some(ExchangeType::Spot), "ping.json");
 
-    // 调用真实 Binance ping
+    // 调用 Binance ping
     let resp = client.spot.ping().await?;
 
     // Binance 的 ping 返回 "{}"
@@ -21,7 +39,7 @@ async fn test_binance_ping() -> Result<(), RetrofitError> {
 #[tokio::test]
 async fn test_binance_system_status() -> Result<(), RetrofitError> {
     let base_url = "https://api.binance.com";
-    let client = BinanceClient::new_with_exchange_type(base_url, None, None)?;
+    let client = client(base_url, None, "system_status.json");
     let status = client.spot.system_status().await?;
 
     println!("system status = {:?}", status);
@@ -36,7 +54,7 @@ async fn test_binance_system_status() -> Result<(), RetrofitError> {
 #[tokio::test]
 async fn test_binance_time() -> Result<(), RetrofitError> {
     let base_url = "https://api.binance.com";
-    let client = BinanceClient::new_with_exchange_type(base_url, None, Some(ExchangeType::Spot))?;
+    let client = client(base_url, Some(ExchangeType::Spot), "time.json");
 
     let binance_time = client.spot.time().await?;
 
@@ -51,7 +69,7 @@ async fn test_binance_time() -> Result<(), RetrofitError> {
 #[tokio::test]
 async fn test_binance_exchange_info() -> Result<(), RetrofitError> {
     let base_url = "https://api.binance.com";
-    let client = BinanceClient::new_with_exchange_type(base_url, None, Some(ExchangeType::Spot))?;
+    let client = client(base_url, Some(ExchangeType::Spot), "exchange_info.json");
 
     let info = client.spot.exchange_info().await?;
 
@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+use std::time::Duration;
+use xchange_binance::binance::BinanceErrorAdapter;
+use xchange_binance::dto::{BinanceError, BinanceException};
+use xchange_core::error::ErrorKind;
+use xchange_core::error::exchange_error::{
+    CurrencyPairNotValidError, ExchangeError, ExchangeSecurityError, ExchangeUnavailableError,
+    FundsExceededError, MarketSuspendedError, NonceError, NotAvailableFromExchangeError,
+    OrderAmountUnderMinimumError, OrderNotFoundError, OrderNotValidError, RateLimitExceededError,
+};
+
+fn exception(code: i32, msg: &str) -> BinanceException {
+    BinanceException::new(code, msg)
+}
+
+#[test]
+fn test_adapt_keeps_code_status_and_path() {
+    let mut e = exception(
+        -1003,
+        "Too many requests; current limit is 1200 request weight.",
+    );
+    e.set_http_status(429);
+    e.set_path("/api/v3/order");
+    e.set_headers(HashMap::from([(
+        "Retry-After".to_string(),
+        vec!["7".to_string()],
+    )]));
+
+    let error = BinanceErrorAdapter::adapt(e);
+    assert_eq!(error.kind(), ErrorKind::RateLimit);
+    assert!(error.is_retryable());
+    assert_eq!(error.code(), Some("-1003"));
+    assert_eq!(error.http_status(), Some(429));
+    assert_eq!(error.path(), Some("/api/v3/order"));
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(7)));
+    assert!(error.downcast_ref::<RateLimitExceededError>().is_some());
+    assert_eq!(
+        error.downcast_ref::<BinanceException>().unwrap().code,
+        -1003
+    );
+}
+
+#[test]
+fn test_adapt_error_kinds() {
+    let error = BinanceErrorAdapter::adapt(exception(
+        -2010,
+        "Account has insufficient balance for requested action.",
+    ));
+    assert_eq!(error.kind(), ErrorKind::InsufficientFunds);
+    assert!(error.downcast_ref::<FundsExceededError>().is_some());
+
+    let error = BinanceErrorAdapter::adapt(exception(-1013, "Filter failure: MIN_NOTIONAL"));
+    assert_eq!(error.kind(), ErrorKind::InvalidOrder);
+    assert!(
+        error
+            .downcast_ref::<OrderAmountUnderMinimumError>()
+            .is_some()
+    );
+
+    let error = BinanceErrorAdapter::adapt(exception(
+        BinanceException::TIMESTAMP_OUTSIDE_RECV_WINDOW,
+        "Timestamp for this request is outside of the recvWindow.",
+    ));
+    assert_eq!(error.kind(), ErrorKind::Nonce);
+    assert!(!error.is_retryable());
+    assert!(error.downcast_ref::<NonceError>().is_some());
+
+    let mut e = exception(
+        -1000,
+        "An unknown error occurred while processing the request.",
+    );
+    e.set_http_status(503);
+    assert_eq!(BinanceErrorAdapter::adapt(e).kind(), ErrorKind::Unavailable);
+    assert_eq!(
+        BinanceErrorAdapter::adapt(exception(-9999, "")).kind(),
+        ErrorKind::Other
+    );
+}
+
+#[test]
+fn test_binance_error_into_exchange_error() {
+    // 包装在 ApiCallFailed 中的 BinanceException 仍按错误码转换
+    let error = ExchangeError::from(BinanceError::ApiCallFailed(Box::new(exception(
+        -2010,
+        "Account has insufficient balance for requested action.",
+    ))));
+    assert_eq!(error.kind(), ErrorKind::InsufficientFunds);
+    assert_eq!(error.code(), Some("-2010"));
+
+    for (error, kind) in [
+        (
+            BinanceError::Io(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "timed out",
+            )),
+            ErrorKind::Timeout,
+        ),
+        (
+            BinanceError::Json(serde_json::from_str::<u64>("x").unwrap_err()),
+            ErrorKind::Decode,
+        ),
+        (BinanceError::InvalidKey("bad key".into()), ErrorKind::Auth),
+        (
+            BinanceError::InvalidParam("symbol".into()),
+            ErrorKind::InvalidRequest,
+        ),
+        (BinanceError::Message("boom".into()), ErrorKind::Other),
+    ] {
+        let error = ExchangeError::from(error);
+        assert_eq!(error.kind(), kind, "{}", error);
+        assert!(error.downcast_ref::<BinanceError>().is_some());
+    }
+}
+
+/// 错误码 / 错误信息 → (ErrorKind, 核心错误类型)
+#[test]
+fn test_error_code_table() {
+    fn is<T: std::error::Error + 'static>(error: &ExchangeError) -> bool {
+        error.downcast_ref::<T>().is_some()
+    }
+    // 没有对应的核心错误类型，source 直接是 BinanceException
+    fn source_only(error: &ExchangeError) -> bool {
+        std::error::Error::source(error).is_some_and(|e| e.is::<BinanceException>())
+    }
+
+    type Case = (i32, &'static str, ErrorKind, fn(&ExchangeError) -> bool);
+    let cases: Vec<Case> = vec![
+        // 10xx
+        (
+            -1001,
+            "Internal error; unable to process your request. Please try again.",
+            ErrorKind::Unavailable,
+            is::<ExchangeUnavailableError>,
+        ),
+        (
+            -1002,
+            "You are not authorized to execute this request.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -1003,
+            "Too many requests queued.",
+            ErrorKind::RateLimit,
+            is::<RateLimitExceededError>,
+        ),
+        (
+            -1004,
+            "Server is busy, please wait and try again",
+            ErrorKind::Unavailable,
+            is::<ExchangeUnavailableError>,
+        ),
+        (
+            -1006,
+            "An unexpected response was received from the message bus. Execution status unknown.",
+            ErrorKind::Timeout,
+            source_only,
+        ),
+        (
+            -1007,
+            "Timeout waiting for response from backend server. Send status unknown; execution status unknown.",
+            ErrorKind::Timeout,
+            source_only,
+        ),
+        (
+            -1008,
+            "Server is currently overloaded with other requests. Please try again in a few minutes.",
+            ErrorKind::Unavailable,
+            is::<ExchangeUnavailableError>,
+        ),
+        (
+            -1014,
+            "Unsupported order combination.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1015,
+            "Too many new orders; current limit is 10 orders per SECOND.",
+            ErrorKind::RateLimit,
+            is::<RateLimitExceededError>,
+        ),
+        (
+            -1016,
+            "This service is no longer available.",
+            ErrorKind::Unavailable,
+            is::<ExchangeUnavailableError>,
+        ),
+        (
+            -1020,
+            "This operation is not supported.",
+            ErrorKind::NotSupported,
+            is::<NotAvailableFromExchangeError>,
+        ),
+        (
+            -1021,
+            "Timestamp for this request is outside of the recvWindow.",
+            ErrorKind::Nonce,
+            is::<NonceError>,
+        ),
+        (
+            -1022,
+            "Signature for this request is not valid.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -1099,
+            "Not found, authenticated, or authorized.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        // 11xx
+        (
+            -1100,
+            "Illegal characters found in a parameter.",
+            ErrorKind::InvalidRequest,
+            source_only,
+        ),
+        (
+            -1102,
+            "Mandatory parameter 'symbol' was not sent, was empty/null, or malformed.",
+            ErrorKind::InvalidRequest,
+            source_only,
+        ),
+        (
+            -1111,
+            "Precision is over the maximum defined for this asset.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1112,
+            "No orders on book for symbol.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1116,
+            "Invalid orderType.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1117,
+            "Invalid side.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1121,
+            "Invalid symbol.",
+            ErrorKind::UnknownInstrument,
+            is::<CurrencyPairNotValidError>,
+        ),
+        (
+            -1122,
+            "Invalid symbol status.",
+            ErrorKind::MarketSuspended,
+            is::<MarketSuspendedError>,
+        ),
+        (
+            -1125,
+            "This listenKey does not exist.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -1131,
+            "recvWindow must be less than 60000",
+            ErrorKind::InvalidRequest,
+            source_only,
+        ),
+        // 20xx
+        (
+            -2010,
+            "Account has insufficient balance for requested action.",
+            ErrorKind::InsufficientFunds,
+            is::<FundsExceededError>,
+        ),
+        (
+            -2010,
+            "Market is closed.",
+            ErrorKind::MarketSuspended,
+            is::<MarketSuspendedError>,
+        ),
+        (
+            -2010,
+            "This action is disabled on this account.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -2010,
+            "Order would immediately match and take.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -2011,
+            "Unknown order sent.",
+            ErrorKind::UnknownOrder,
+            is::<OrderNotFoundError>,
+        ),
+        (
+            -2013,
+            "Order does not exist.",
+            ErrorKind::UnknownOrder,
+            is::<OrderNotFoundError>,
+        ),
+        (
+            -2014,
+            "API-key format invalid.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -2015,
+            "Invalid API-key, IP, or permissions for action.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -2018,
+            "Balance is insufficient",
+            ErrorKind::InsufficientFunds,
+            is::<FundsExceededError>,
+        ),
+        (
+            -2019,
+            "Margin is insufficient.",
+            ErrorKind::InsufficientFunds,
+            is::<FundsExceededError>,
+        ),
+        (
+            -2021,
+            "Order would immediately trigger.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -2022,
+            "ReduceOnly Order is rejected.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        // 40xx
+        (
+            -4003,
+            "Quantity less than or equal to zero.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -4004,
+            "Quantity less than min quantity.",
+            ErrorKind::InvalidOrder,
+            is::<OrderAmountUnderMinimumError>,
+        ),
+        (
+            -4028,
+            "Leverage 200 is not valid",
+            ErrorKind::InvalidRequest,
+            source_only,
+        ),
+        (
+            -4061,
+            "Order's position side does not match user's setting.",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -4088,
+            "User can not place order currently.",
+            ErrorKind::Auth,
+            is::<ExchangeSecurityError>,
+        ),
+        (
+            -4161,
+            "Leverage reduction is not supported in Isolated Margin Mode with open positions.",
+            ErrorKind::InvalidRequest,
+            source_only,
+        ),
+        (
+            -4164,
+            "Order's notional must be no smaller than 5 (unless you choose reduce only).",
+            ErrorKind::InvalidOrder,
+            is::<OrderAmountUnderMinimumError>,
+        ),
+        // filter failure
+        (
+            -1013,
+            "Filter failure: LOT_SIZE",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1013,
+            "Filter failure: PRICE_FILTER",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1013,
+            "Filter failure: PERCENT_PRICE",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1013,
+            "Filter failure: MAX_NUM_ORDERS",
+            ErrorKind::InvalidOrder,
+            is::<OrderNotValidError>,
+        ),
+        (
+            -1013,
+            "Filter failure: NOTIONAL",
+            ErrorKind::InvalidOrder,
+            is::<OrderAmountUnderMinimumError>,
+        ),
+        (
+            -2010,
+            "Filter failure: MIN_NOTIONAL",
+            ErrorKind::InvalidOrder,
+            is::<OrderAmountUnderMinimumError>,
+        ),
+    ];
+
+    for (code, msg, kind, check) in cases {
+        let error = BinanceErrorAdapter::adapt(exception(code, msg));
+        assert_eq!(error.kind(), kind, "{}", error);
+        assert_eq!(error.code(), Some(code.to_string().as_str()));
+        assert!(check(&error), "unexpected error type for {}", error);
+        assert_eq!(error.downcast_ref::<BinanceException>().unwrap().code, code);
+    }
+}
+
+#[test]
+fn test_unknown_order_is_not_insufficient_balance() {
+    let unknown = BinanceErrorAdapter::adapt(exception(-2011, "Unknown order sent."));
+    let balance = BinanceErrorAdapter::adapt(exception(
+        -2011,
+        "Account has insufficient balance for requested action.",
+    ));
+    assert_eq!(unknown.kind(), ErrorKind::UnknownOrder);
+    assert_eq!(balance.kind(), ErrorKind::InsufficientFunds);
+    assert!(unknown.downcast_ref::<FundsExceededError>().is_none());
+    assert!(balance.downcast_ref::<OrderNotFoundError>().is_none());
+}
+
+#[test]
+fn test_filter_failure() {
+    assert_eq!(
+        BinanceErrorAdapter::filter_failure("Filter failure: LOT_SIZE"),
+        Some("LOT_SIZE")
+    );
+    assert_eq!(
+        BinanceErrorAdapter::filter_failure("Invalid quantity."),
+        None
+    );
+}
@@ -1,30 +1,69 @@
+use std::path::Path;
 use std::sync::Arc;
 use tokio;
-use xchange_binance::binance_exchange::BinanceExchange;
+use xchange_binance::binance_exchange::{BinanceExchange, EXCHANGE_TYPE_KEY};
 use xchange_binance::dto::BinanceError;
 use xchange_binance::service::account_service::BinanceAccountService;
 use xchange_binance::service::market_data_service::BinanceMarketDataService;
-use xchange_core::exchange::Exchange;
-use xchange_core::exchange_specification::ExchangeParam;
+use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
+use xchange_core::exchange::{Exchange, ExchangeType};
+use xchange_core::exchange_specification::{ExchangeParam, ExchangeSpecification};
+use xchange_core::instrument::InstrumentDTO;
+use xchange_core::rescu::replay::fixture_transport;
+use xchange_core::rescu::transport::HttpTransport;
 
-async fn default_exchange() -> Result<Arc<BinanceExchange>, BinanceError> {
-    BinanceExchange::default().await
+/// 默认回放 tests/fixtures 下录制的响应，`XCHANGE_HTTP_MODE=record` 重新录制，`live` 直连
+fn fixture(name: &str) -> Option<Arc<dyn HttpTransport>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/binance_exchange")
+        .join(name);
+    fixture_transport(path).expect("load HTTP fixture")
 }
 
-async fn new_exchange() -> Result<Arc<BinanceExchange>, BinanceError> {
-    let mut exchange = BinanceExchange::new().await?;
+/// 不使用本地凭证，远程 meta data 从 fixture 中录制的 exchangeInfo 加载
+fn public_specification() -> ExchangeSpecification {
     let mut spec = BinanceExchange::default_exchange_specification();
     spec.api_key = None;
     spec.secret_key = None;
+    spec
+}
+
+async fn default_exchange() -> Result<Arc<BinanceExchange>, BinanceError> {
+    BinanceExchange::with_transport(
+        public_specification(),
+        fixture("default_initialization.json"),
+    )
+    .await
+}
+
+async fn new_exchange() -> Result<Arc<BinanceExchange>, BinanceError> {
+    let exchange =
+        BinanceExchange::with_transport(public_specification(), fixture("new_initialization.json"))
+            .await?;
+    let mut spec = public_specification();
     spec.use_sandbox = true;
     spec.exchange_specific_parameters.insert(
         "Portfolio_Margin_Enabled".into(),
         ExchangeParam::Boolean(true),
     );
     let _ = exchange.apply_specification(spec);
+    // apply_specification 在后台加载远程 meta data，这里等待加载完成
+    exchange.load_remote_meta_data().await;
     Ok(exchange)
 }
 
+/// meta data 来自 fixture 中录制的 exchangeInfo
+fn assert_remote_meta_data_loaded(exchange: &BinanceExchange) {
+    let meta_data = exchange.base.meta_data.read();
+    let btc_usdt = InstrumentDTO::Spot {
+        base: "BTC".into(),
+        counter: "USDT".into(),
+    };
+    assert!(meta_data.instruments.contains_key(&btc_usdt));
+    assert!(!meta_data.public_rate_limits.is_empty());
+    assert!(!exchange.exchange_instruments().is_empty());
+}
+
 #[tokio::test]
 async fn test_default_initialization() {
     let exchange = default_exchange().await.expect("default() should succeed");
@@ -57,6 +96,8 @@ async fn test_default_initialization() {
             .as_any()
             .is::<BinanceAccountService>()
     );
+
+    assert_remote_meta_data_loaded(&exchange);
 }
 
 #[tokio::test]
@@ -91,4 +132,86 @@ async fn test_new_initialization() {
             .as_any()
             .is::<BinanceAccountService>()
     );
+
+    assert_remote_meta_data_loaded(&exchange);
+}
+
+#[tokio::test]
+async fn test_exchange_instruments_from_meta_data() {
+    let path = std::env::temp_dir().join(format!("binance-meta-data-{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"{
+          "currency_pairs": {
+            "ETH/BTC": { "price_scale": 6 },
+            "BTC/USD/PERP": { "contract_value": 100 },
+            "BTC/USDT/240329": {},
+            "BTC/USDT/240628/60000/C": {}
+          },
+          "currencies": {}
+        }"#,
+    )
+    .unwrap();
+
+    let mut spec = BinanceExchange::default_exchange_specification();
+    spec.should_load_remote_meta_data = false;
+    spec.meta_data_json_file_override = Some(path.to_string_lossy().into_owned());
+    let exchange = BinanceExchange::with_specification(spec).await.unwrap();
+    std::fs::remove_file(&path).ok();
+
+    let symbols: Vec<String> = exchange
+        .exchange_instruments()
+        .iter()
+        .map(|instrument| format!("{:?}", instrument))
+        .collect();
+    assert_eq!(symbols.len(), 4);
+    assert!(symbols[0].starts_with("FuturesContract"));
+    assert!(symbols[1].starts_with("FuturesContract"));
+    assert!(symbols[2].starts_with("OptionsContract"));
+    assert!(symbols[3].starts_with("CurrencyPair"));
+
+    // 币本位与 U 本位合约按计价币区分
+    let counters: Vec<String> = exchange
+        .exchange_instruments()
+        .iter()
+        .take(2)
+        .map(|instrument| instrument.counter().code.clone())
+        .collect();
+    assert_eq!(counters, vec!["USD", "USDT"]);
+
+    // meta data 重新加载后同步刷新
+    exchange.base.set_meta_data(ExchangeMetaData::default());
+    assert!(exchange.exchange_instruments().is_empty());
+}
+
+#[tokio::test]
+async fn test_user_data_services_reset_without_api_key() {
+    let mut spec = public_specification();
+    spec.should_load_remote_meta_data = false;
+    spec.api_key = Some("api-key".into());
+    let exchange = BinanceExchange::with_specification(spec).await.unwrap();
+    assert!(exchange.streaming_trade_service().is_ok());
+    assert!(exchange.streaming_account_service().is_ok());
+
+    let mut spec = public_specification();
+    spec.should_load_remote_meta_data = false;
+    exchange.apply_specification(spec).unwrap();
+    assert!(exchange.streaming_trade_service().is_err());
+    assert!(exchange.streaming_account_service().is_err());
+}
+
+#[tokio::test]
+async fn test_portfolio_margin_has_no_user_data_services() {
+    let mut spec = public_specification();
+    spec.should_load_remote_meta_data = false;
+    spec.api_key = Some("api-key".into());
+    spec.exchange_specific_parameters.insert(
+        EXCHANGE_TYPE_KEY.into(),
+        ExchangeParam::ExchangeType(ExchangeType::PortfolioMargin),
+    );
+    let exchange = BinanceExchange::with_specification(spec).await.unwrap();
+
+    assert!(exchange.streaming_market_data_service().is_ok());
+    assert!(exchange.streaming_trade_service().is_err());
+    assert!(exchange.streaming_account_service().is_err());
 }
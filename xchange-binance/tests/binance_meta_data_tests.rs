@@ -0,0 +1,180 @@
+use rust_decimal::Decimal;
+use serde_json::json;
+use xchange_binance::binance::BinanceAdapters;
+use xchange_binance::dto::meta::exchange_info::BinanceExchangeInfo;
+use xchange_core::TimeUnit;
+use xchange_core::currency::currency::Currency;
+use xchange_core::exchange::ExchangeType;
+use xchange_core::instrument::InstrumentDTO;
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+fn spot_exchange_info() -> BinanceExchangeInfo {
+    serde_json::from_value(json!({
+        "timezone": "UTC",
+        "serverTime": 1700000000000i64,
+        "rateLimits": [
+            { "rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000 },
+            { "rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 100 },
+            { "rateLimitType": "RAW_REQUESTS", "interval": "MINUTE", "intervalNum": 5, "limit": 61000 }
+        ],
+        "exchangeFilters": [],
+        "symbols": [{
+            "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "baseAssetPrecision": 8,
+            "quoteAsset": "USDT", "quotePrecision": 8, "quoteAssetPrecision": 8,
+            "orderTypes": ["LIMIT", "LIMIT_MAKER", "MARKET", "STOP_LOSS_LIMIT"],
+            "icebergAllowed": true, "ocoAllowed": true, "isSpotTradingAllowed": true,
+            "isMarginTradingAllowed": true,
+            "filters": [
+                { "filterType": "PRICE_FILTER", "minPrice": "0.01000000", "maxPrice": "1000000.00000000", "tickSize": "0.01000000" },
+                { "filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000.00000000", "stepSize": "0.00001000" },
+                { "filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true, "maxNotional": "9000000.00000000" },
+                { "filterType": "MAX_NUM_ORDERS", "maxNumOrders": 200 }
+            ],
+            "permissions": []
+        }]
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_spot_instrument_meta_data() {
+    let meta =
+        BinanceAdapters::adapt_exchange_meta_data(&spot_exchange_info(), &ExchangeType::Spot);
+
+    let instrument = InstrumentDTO::Spot {
+        base: "BTC".into(),
+        counter: "USDT".into(),
+    };
+    let btc_usdt = meta
+        .instruments
+        .get(&instrument)
+        .expect("BTC/USDT meta data");
+    assert_eq!(btc_usdt.price_step_size, Some(dec("0.01")));
+    assert_eq!(btc_usdt.price_scale, Some(2));
+    assert_eq!(btc_usdt.amount_step_size, Some(dec("0.00001")));
+    assert_eq!(btc_usdt.volume_scale, Some(5));
+    assert_eq!(btc_usdt.minimum_amount, Some(dec("0.00001")));
+    assert_eq!(btc_usdt.maximum_amount, Some(dec("9000")));
+    assert_eq!(btc_usdt.counter_minimum_amount, Some(dec("5")));
+    assert_eq!(btc_usdt.counter_maximum_amount, Some(dec("9000000")));
+    assert_eq!(btc_usdt.market_order_enabled, Some(true));
+
+    assert_eq!(
+        meta.currencies.get(&Currency::new("BTC")).unwrap().scale,
+        Some(8)
+    );
+    assert!(meta.currencies.contains_key(&Currency::new("USDT")));
+}
+
+#[test]
+fn test_rate_limits() {
+    let meta =
+        BinanceAdapters::adapt_exchange_meta_data(&spot_exchange_info(), &ExchangeType::Spot);
+
+    assert_eq!(meta.public_rate_limits.len(), 2);
+    assert_eq!(meta.public_rate_limits[0].calls, 6000);
+    assert_eq!(meta.public_rate_limits[0].time_span, 1);
+    assert!(matches!(
+        meta.public_rate_limits[0].time_unit,
+        TimeUnit::Minutes
+    ));
+
+    assert_eq!(meta.private_rate_limits.len(), 1);
+    assert_eq!(meta.private_rate_limits[0].calls, 100);
+    assert_eq!(meta.private_rate_limits[0].time_span, 10);
+    assert!(matches!(
+        meta.private_rate_limits[0].time_unit,
+        TimeUnit::Seconds
+    ));
+}
+
+#[test]
+fn test_futures_instrument_meta_data() {
+    let info: BinanceExchangeInfo = serde_json::from_value(json!({
+        "timezone": "UTC",
+        "serverTime": 1700000000000i64,
+        "rateLimits": [],
+        "exchangeFilters": [],
+        "symbols": [
+            {
+                "symbol": "BTCUSDT", "pair": "BTCUSDT", "contractType": "PERPETUAL",
+                "deliveryDate": 4133404800000i64, "onboardDate": 1569398400000i64,
+                "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT", "marginAsset": "USDT",
+                "pricePrecision": 2, "quantityPrecision": 3, "baseAssetPrecision": 8,
+                "quotePrecision": 8, "orderTypes": ["LIMIT", "MARKET", "STOP"],
+                "filters": [
+                    { "filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10" },
+                    { "filterType": "LOT_SIZE", "stepSize": "0.001", "maxQty": "1000", "minQty": "0.001" },
+                    { "filterType": "MIN_NOTIONAL", "notional": "100" }
+                ]
+            },
+            {
+                "symbol": "BTCUSD_240329", "pair": "BTCUSD", "contractType": "CURRENT_QUARTER",
+                "contractStatus": "TRADING", "contractSize": 100, "baseAsset": "BTC",
+                "quoteAsset": "USD", "marginAsset": "BTC", "pricePrecision": 1,
+                "quantityPrecision": 0, "baseAssetPrecision": 8, "quotePrecision": 8,
+                "orderTypes": ["LIMIT"],
+                "filters": [
+                    { "filterType": "PRICE_FILTER", "minPrice": "1000", "maxPrice": "4520958", "tickSize": "0.1" }
+                ]
+            }
+        ]
+    }))
+    .unwrap();
+
+    let meta = BinanceAdapters::adapt_exchange_meta_data(&info, &ExchangeType::Futures);
+
+    let perpetual = meta
+        .instruments
+        .get(&InstrumentDTO::Futures {
+            base: "BTC".into(),
+            counter: "USDT".into(),
+            prompt: Some("PERP".into()),
+        })
+        .expect("BTC/USDT/PERP meta data");
+    assert_eq!(perpetual.price_scale, Some(1));
+    assert_eq!(perpetual.volume_scale, Some(3));
+    assert_eq!(perpetual.counter_minimum_amount, Some(dec("100")));
+    assert_eq!(perpetual.market_order_enabled, Some(true));
+
+    let quarterly = meta
+        .instruments
+        .get(&InstrumentDTO::Futures {
+            base: "BTC".into(),
+            counter: "USD".into(),
+            prompt: Some("240329".into()),
+        })
+        .expect("BTC/USD/240329 meta data");
+    assert_eq!(quarterly.contract_value, Some(dec("100")));
+    // 没有 LOT_SIZE 时回退到 quantityPrecision
+    assert_eq!(quarterly.volume_scale, Some(0));
+    assert_eq!(quarterly.amount_step_size, None);
+    assert_eq!(quarterly.market_order_enabled, Some(false));
+}
+
+#[test]
+fn test_options_instrument_meta_data() {
+    let info: BinanceExchangeInfo = serde_json::from_value(json!({
+        "timezone": "UTC",
+        "serverTime": 1700000000000i64,
+        "rateLimits": [],
+        "exchangeFilters": [],
+        "symbols": [
+            {
+                "symbol": "BTC-240628-60000-C", "status": "TRADING", "baseAsset": "BTC",
+                "quoteAsset": "USDT", "baseAssetPrecision": 8, "quotePrecision": 8,
+                "orderTypes": ["LIMIT"], "filters": []
+            }
+        ]
+    }))
+    .unwrap();
+
+    let meta = BinanceAdapters::adapt_exchange_meta_data(&info, &ExchangeType::Futures);
+    assert_eq!(
+        meta.instruments.keys().next(),
+        Some(&"BTC/USDT/240628/60000/C".parse::<InstrumentDTO>().unwrap())
+    );
+}
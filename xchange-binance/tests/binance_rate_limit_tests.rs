@@ -0,0 +1,179 @@
+use retrofit_rs::RetrofitError;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use xchange_binance::binance_resilience::{
+    BinanceRateLimitTracker, BinanceResilience, ORDERS_PER_10_SECONDS, ORDERS_PER_MINUTE,
+    REQUEST_WEIGHT_RATE_LIMITER,
+};
+use xchange_binance::client::binance_spot::BinanceAuthed;
+use xchange_binance::client::{BinanceClient, BinanceClientBuilder};
+use xchange_binance::dto::{BinanceError, BinanceException};
+use xchange_core::client::{RateLimiterError, ResilienceRegistries, RetryConfig, RetryDecision};
+use xchange_core::rescu::transport::{FnTransport, HttpRequest, HttpResponse};
+
+#[test]
+fn test_used_weight_headers_sync_limiters() {
+    let registries = Arc::new(BinanceResilience::new_futures().registries);
+    let tracker = BinanceRateLimitTracker::new(registries.clone());
+
+    tracker.record([
+        ("Content-Type", "application/json"),
+        ("X-MBX-USED-WEIGHT-1M", "2000"),
+        ("X-MBX-ORDER-COUNT-10S", "7"),
+        ("X-MBX-ORDER-COUNT-1M", "42"),
+    ]);
+
+    let usage = tracker.usage();
+    assert_eq!(usage.used_weight_1m(), Some(2000));
+    assert_eq!(usage.order_count.get("10s"), Some(&7));
+    assert!(usage.updated_at.is_some());
+
+    let weight = registries
+        .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        .unwrap();
+    assert_eq!(weight.used(), 2000);
+    assert_eq!(weight.available(), 400);
+    assert_eq!(
+        registries
+            .rate_limiter(ORDERS_PER_10_SECONDS)
+            .unwrap()
+            .used(),
+        7
+    );
+    assert_eq!(
+        registries.rate_limiter(ORDERS_PER_MINUTE).unwrap().used(),
+        42
+    );
+
+    assert_eq!(
+        tracker.last_headers().get("x-mbx-used-weight-1m"),
+        Some(&vec!["2000".to_string()])
+    );
+}
+
+#[test]
+fn test_responses_without_usage_headers_keep_usage() {
+    let tracker = BinanceRateLimitTracker::new(Arc::new(BinanceResilience::new_spot().registries));
+    tracker.record([("x-mbx-used-weight-1m", "10")]);
+    tracker.record([("Content-Type", "application/json")]);
+    assert_eq!(tracker.usage().used_weight_1m(), Some(10));
+}
+
+/// 不发请求，直接返回固定响应
+fn stub_client(tracker: Arc<BinanceRateLimitTracker>, status: u16) -> BinanceClient {
+    let stub = FnTransport::new(move |_request: HttpRequest| {
+        Ok(HttpResponse::new(status, "{}")
+            .header("x-mbx-used-weight-1m", "7")
+            .header("content-type", "application/json"))
+    });
+    BinanceClientBuilder::new("https://api.binance.com")
+        .rate_limit_tracker(tracker)
+        .transport(Arc::new(stub))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_interceptor_records_response_headers() {
+    let tracker = Arc::new(BinanceRateLimitTracker::new(Arc::new(
+        BinanceResilience::new_spot().registries,
+    )));
+
+    stub_client(tracker.clone(), 200).spot.ping().await.unwrap();
+
+    assert_eq!(tracker.usage().used_weight_1m(), Some(7));
+}
+
+#[tokio::test]
+async fn test_interceptor_records_error_response_headers() {
+    let tracker = Arc::new(BinanceRateLimitTracker::new(Arc::new(
+        BinanceResilience::new_spot().registries,
+    )));
+
+    let error = stub_client(tracker.clone(), 429)
+        .spot
+        .ping()
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RetrofitError::HttpError { status: 429, .. }
+    ));
+    assert_eq!(tracker.usage().used_weight_1m(), Some(7));
+}
+
+#[test]
+fn test_parse_interval() {
+    assert_eq!(
+        BinanceRateLimitTracker::parse_interval("1m"),
+        Some(Duration::from_secs(60))
+    );
+    assert_eq!(
+        BinanceRateLimitTracker::parse_interval("10S"),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(
+        BinanceRateLimitTracker::parse_interval("1d"),
+        Some(Duration::from_secs(86400))
+    );
+    assert_eq!(BinanceRateLimitTracker::parse_interval("m"), None);
+}
+
+#[test]
+fn test_exception_headers() {
+    let mut e = BinanceException::new(-1003, "Too many requests");
+    assert_eq!(e.used_weight_1m(), None);
+
+    e.set_headers(HashMap::from([(
+        "x-mbx-used-weight-1m".to_string(),
+        vec!["6001".to_string()],
+    )]));
+    assert_eq!(e.used_weight_1m(), Some(6001));
+}
+
+#[test]
+fn test_retry_after_pauses_request_weight_limiter() {
+    let registries = Arc::new(BinanceResilience::new_spot().registries);
+    let tracker = BinanceRateLimitTracker::new(registries.clone());
+
+    tracker.record([("Retry-After", "120"), ("x-mbx-used-weight-1m", "6000")]);
+
+    let limiter = registries
+        .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        .unwrap();
+    match limiter.try_acquire(1) {
+        Err(RateLimiterError::WouldBlock { wait }) => assert!(wait > Duration::from_secs(100)),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_binance_retry_predicates() {
+    let registries = BinanceResilience::new_spot().registries;
+    let retry = registries.retry(REQUEST_WEIGHT_RATE_LIMITER).unwrap();
+    let non_idempotent = registries
+        .retry(ResilienceRegistries::NON_IDEMPOTENT)
+        .unwrap();
+
+    let decide = |config: &RetryConfig, e: BinanceError| {
+        let boxed: Box<dyn std::error::Error + Send + Sync> = Box::new(e);
+        config.decide(boxed.as_ref())
+    };
+    let exception = |code: i32| BinanceError::Binance(BinanceException::new(code, "error"));
+
+    assert_eq!(decide(&retry, exception(-1001)), RetryDecision::Retry);
+    assert_eq!(decide(&retry, exception(-2010)), RetryDecision::Abort);
+    assert_eq!(decide(&retry, exception(-2015)), RetryDecision::Abort);
+
+    // 下单超时 / 断开时订单状态未知，不能重试
+    assert_eq!(
+        decide(&non_idempotent, exception(-1001)),
+        RetryDecision::Abort
+    );
+    assert_eq!(
+        decide(&non_idempotent, exception(-1015)),
+        RetryDecision::Retry
+    );
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use xchange_binance::binance_exchange::{BinanceExchange, EXCHANGE_TYPE_KEY};
+use xchange_binance::binance_resilience::{
+    BinanceResilience, ENDPOINT_EXCHANGE_INFO, MARKET_DATA_GROUP, REQUEST_WEIGHT_RATE_LIMITER,
+    TRADE_GROUP, USER_DATA_STREAM_GROUP,
+};
+use xchange_core::client::{
+    Bulkhead, CircuitBreaker, CircuitBreakerConfig, CircuitBreakerOpenError, CircuitState,
+    HttpStatusError, ResiliencePolicy, ResilientCall, TimeoutError, boxed,
+};
+use xchange_core::exchange::{Exchange, ExchangeType};
+use xchange_core::exchange_specification::{
+    ExchangeParam, ExchangeSpecification, ResilienceSpecification,
+};
+
+#[test]
+fn test_default_circuit_breakers_and_bulkheads() {
+    for registries in [
+        BinanceResilience::new_spot().registries,
+        BinanceResilience::new_futures().registries,
+    ] {
+        for group in [MARKET_DATA_GROUP, TRADE_GROUP, USER_DATA_STREAM_GROUP] {
+            let breaker = registries.circuit_breaker(group).unwrap();
+            assert_eq!(breaker.state(), CircuitState::Closed);
+            assert!(registries.bulkhead(group).unwrap().available() > 0);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_open_circuit_fails_fast() {
+    let breaker = Arc::new(CircuitBreaker::new(
+        "test",
+        CircuitBreakerConfig {
+            sliding_window_size: 2,
+            minimum_number_of_calls: 2,
+            wait_duration_in_open_state: Duration::from_secs(60),
+            ..Default::default()
+        },
+    ));
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let call = {
+        let calls = calls.clone();
+        ResilientCall::new(move || {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(boxed(HttpStatusError::new(503, "unavailable")))
+            }
+        })
+        .with_circuit_breaker(breaker.clone())
+    };
+
+    assert!(call.call().await.is_err());
+    assert!(call.call().await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    let err = call.call().await.unwrap_err();
+    assert!(err.is::<CircuitBreakerOpenError>(), "{}", err);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_bulkhead_caps_in_flight_calls() {
+    let bulkhead = Arc::new(Bulkhead::new("test", 1, Duration::ZERO));
+    let held = bulkhead.acquire().await.unwrap();
+
+    let call =
+        ResilientCall::new(|| async { Ok::<_, Box<dyn std::error::Error + Send + Sync>>(1) })
+            .with_bulkhead(bulkhead.clone());
+    assert!(call.call().await.is_err());
+
+    drop(held);
+    assert_eq!(call.call().await.unwrap(), 1);
+}
+
+fn offline_specification(exchange_type: ExchangeType) -> ExchangeSpecification {
+    let mut spec = BinanceExchange::default_exchange_specification();
+    spec.api_key = None;
+    spec.secret_key = None;
+    spec.should_load_remote_meta_data = false;
+    spec.exchange_specific_parameters.insert(
+        EXCHANGE_TYPE_KEY.into(),
+        ExchangeParam::ExchangeType(exchange_type),
+    );
+    spec
+}
+
+fn request_weight_capacity(exchange: &BinanceExchange) -> u64 {
+    exchange
+        .get_resilience_registries()
+        .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        .unwrap()
+        .capacity()
+}
+
+#[tokio::test]
+async fn test_registries_follow_exchange_type() {
+    let exchange = BinanceExchange::with_specification(offline_specification(ExchangeType::Spot))
+        .await
+        .unwrap();
+    assert_eq!(request_weight_capacity(&exchange), 6000);
+
+    exchange
+        .apply_specification(offline_specification(ExchangeType::Futures))
+        .unwrap();
+    assert_eq!(request_weight_capacity(&exchange), 2400);
+    assert!(Arc::ptr_eq(
+        &exchange.resilience_registries().await.unwrap(),
+        &exchange.get_resilience_registries()
+    ));
+}
+
+#[tokio::test]
+async fn test_specification_disables_resilience() {
+    let mut spec = offline_specification(ExchangeType::Spot);
+    spec.resilience = ResilienceSpecification::new();
+    let exchange = BinanceExchange::with_specification(spec).await.unwrap();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let call = {
+        let calls = calls.clone();
+        ResilientCall::new(move || {
+            let calls = calls.clone();
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(boxed(HttpStatusError::new(503, "unavailable")))
+            }
+        })
+    };
+    let policy = ResiliencePolicy::new()
+        .retry(REQUEST_WEIGHT_RATE_LIMITER)
+        .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        .endpoint(ENDPOINT_EXCHANGE_INFO)
+        .group(MARKET_DATA_GROUP);
+
+    // 关闭 retry / 限流：只尝试一次，不占用权重
+    assert!(exchange.resilient(call, &policy).call().await.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let limiter = exchange
+        .get_resilience_registries()
+        .rate_limiter(REQUEST_WEIGHT_RATE_LIMITER)
+        .unwrap();
+    assert_eq!(limiter.used(), 0);
+}
+
+#[tokio::test]
+async fn test_attempt_timeout_from_specification() {
+    let mut spec = offline_specification(ExchangeType::Spot);
+    spec.http_conn_timeout = 10;
+    spec.http_read_timeout = 10;
+    spec.resilience = ResilienceSpecification::new();
+    let exchange = BinanceExchange::with_specification(spec).await.unwrap();
+
+    let call = ResilientCall::new(|| async {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        Ok(())
+    });
+    let error = exchange
+        .resilient(call, &ResiliencePolicy::new())
+        .call()
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<TimeoutError>(),
+        Some(TimeoutError::Attempt { timeout }) if *timeout == Duration::from_millis(20)
+    ));
+}
@@ -1,5 +1,6 @@
 // ----------------- 辅助函数 -----------------
 
+use std::path::Path;
 use std::sync::Arc;
 use xchange_binance::binance_exchange::{BinanceExchange, EXCHANGE_TYPE_KEY};
 use xchange_binance::dto::BinanceError;
@@ -10,20 +11,37 @@ use xchange_binance::service::market_data_service::BinanceMarketDataService;
 use xchange_core::currency::currency_pair::CurrencyPair;
 use xchange_core::dto::meta::ExchangeHealth;
 use xchange_core::exchange::{Exchange, ExchangeType};
-use xchange_core::exchange_specification::ExchangeParam;
+use xchange_core::exchange_specification::{ExchangeParam, ExchangeSpecification};
+use xchange_core::rescu::replay::fixture_transport;
+use xchange_core::rescu::transport::HttpTransport;
 use xchange_core::service::marketdata::market_data_service::MarketDataService;
 use xchange_core::utils::service_arc;
 
-/// 异步创建 BinanceExchange
-async fn default_exchange() -> Result<Arc<BinanceExchange>, BinanceError> {
-    BinanceExchange::default().await
+/// 默认回放 tests/fixtures 下录制的响应，`XCHANGE_HTTP_MODE=record` 重新录制，`live` 直连
+fn fixture(name: &str) -> Option<Arc<dyn HttpTransport>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/binance_service")
+        .join(name);
+    fixture_transport(path).expect("load HTTP fixture")
 }
 
-async fn new_exchange_futures() -> Result<Arc<BinanceExchange>, BinanceError> {
-    let mut exchange = BinanceExchange::new().await?;
+/// 不使用本地凭证，远程 meta data 从 fixture 中录制的 exchangeInfo 加载
+fn public_specification() -> ExchangeSpecification {
     let mut spec = BinanceExchange::default_exchange_specification();
     spec.api_key = None;
     spec.secret_key = None;
+    spec
+}
+
+/// 异步创建 BinanceExchange
+async fn default_exchange(fixture_name: &str) -> Result<Arc<BinanceExchange>, BinanceError> {
+    BinanceExchange::with_transport(public_specification(), fixture(fixture_name)).await
+}
+
+async fn new_exchange_futures(fixture_name: &str) -> Result<Arc<BinanceExchange>, BinanceError> {
+    let exchange =
+        BinanceExchange::with_transport(public_specification(), fixture(fixture_name)).await?;
+    let mut spec = public_specification();
     spec.use_sandbox = true;
     spec.exchange_specific_parameters.insert(
         EXCHANGE_TYPE_KEY.into(),
@@ -34,6 +52,8 @@ async fn new_exchange_futures() -> Result<Arc<BinanceExchange>, BinanceError> {
         ExchangeParam::Boolean(true),
     );
     let _ = exchange.apply_specification(spec);
+    // apply_specification 在后台加载远程 meta data，这里等待加载完成
+    exchange.load_remote_meta_data().await;
 
     Ok(exchange)
 }
@@ -41,7 +61,9 @@ async fn new_exchange_futures() -> Result<Arc<BinanceExchange>, BinanceError> {
 // ----------------- 测试 -----------------
 #[tokio::test]
 async fn test_market_data_service_arc() {
-    let exchange = default_exchange().await.expect("default() should succeed");
+    let exchange = default_exchange("market_data_service_arc.json")
+        .await
+        .expect("default() should succeed");
 
     let market_service: Arc<dyn MarketDataService + Send + Sync> =
         exchange.market_data_service().unwrap();
@@ -63,7 +85,9 @@ async fn test_market_data_service_arc() {
 
 #[tokio::test]
 async fn test_binance_time() {
-    let exchange = default_exchange().await.expect("default() should succeed");
+    let exchange = default_exchange("binance_time.json")
+        .await
+        .expect("default() should succeed");
     let service: Arc<dyn MarketDataService + Send + Sync> = exchange.market_data_service().unwrap();
 
     let market: Arc<BinanceMarketDataService> = service_arc(&service);
@@ -77,7 +101,9 @@ async fn test_binance_time() {
 
 #[tokio::test]
 async fn test_system_status() {
-    let exchange = default_exchange().await.expect("default() should succeed");
+    let exchange = default_exchange("system_status.json")
+        .await
+        .expect("default() should succeed");
     let service: Arc<dyn MarketDataService + Send + Sync> = exchange.market_data_service().unwrap();
 
     let market: Arc<BinanceMarketDataService> = service_arc(&service);
@@ -97,7 +123,9 @@ async fn test_system_status() {
 
 #[tokio::test]
 async fn test_exchange_info() {
-    let exchange = default_exchange().await.expect("default() should succeed");
+    let exchange = default_exchange("exchange_info.json")
+        .await
+        .expect("default() should succeed");
     let service: Arc<dyn MarketDataService + Send + Sync> = exchange.market_data_service().unwrap();
 
     let market: Arc<BinanceMarketDataService> = service_arc(&service);
@@ -116,7 +144,7 @@ async fn test_exchange_info() {
 
 #[tokio::test]
 async fn test_futures_exchange_info() {
-    let exchange = new_exchange_futures()
+    let exchange = new_exchange_futures("futures_exchange_info.json")
         .await
         .expect("default() should succeed");
     let service: Arc<dyn MarketDataService + Send + Sync> = exchange.market_data_service().unwrap();
@@ -138,7 +166,7 @@ async fn test_futures_exchange_info() {
 #[tokio::test]
 async fn test_klines_default_limit() {
     // 1. 初始化 exchange
-    let exchange = default_exchange()
+    let exchange = default_exchange("klines_default_limit.json")
         .await
         .expect("default_exchange() should succeed");
 
@@ -241,7 +269,7 @@ async fn test_klines_default_limit() {
 #[tokio::test]
 async fn test_klines_default_futures_limit() {
     // 1. 初始化 exchange
-    let exchange = new_exchange_futures()
+    let exchange = new_exchange_futures("klines_default_futures_limit.json")
         .await
         .expect("default_exchange() should succeed");
 
@@ -346,7 +374,7 @@ async fn test_klines_default_futures_limit() {
 #[tokio::test]
 async fn test_klines_futures_limit() {
     // 1. 初始化 exchange
-    let exchange = new_exchange_futures()
+    let exchange = new_exchange_futures("klines_futures_limit.json")
         .await
         .expect("default_exchange() should succeed");
 
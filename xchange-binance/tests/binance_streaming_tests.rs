@@ -0,0 +1,206 @@
+use futures::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::accept_async;
+use tokio_tungstenite::tungstenite::Message;
+use xchange_binance::binance_exchange::BinanceExchange;
+use xchange_core::dto::order::OrderType;
+use xchange_core::exchange::Exchange;
+use xchange_core::instrument::InstrumentDTO;
+
+/// 本地 WebSocket 服务：收到 SUBSCRIBE 后按 stream 名称推送预置数据
+async fn spawn_stream_server(payloads: HashMap<String, Value>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((tcp, _)) = listener.accept().await {
+            let payloads = payloads.clone();
+            tokio::spawn(async move {
+                let mut ws = accept_async(tcp).await.unwrap();
+                while let Some(Ok(message)) = ws.next().await {
+                    let Message::Text(text) = message else {
+                        continue;
+                    };
+                    let request: Value = serde_json::from_str(text.as_str()).unwrap();
+                    if request["method"] != "SUBSCRIBE" {
+                        continue;
+                    }
+
+                    let response = json!({ "result": null, "id": request["id"] });
+                    ws.send(Message::Text(response.to_string().into()))
+                        .await
+                        .unwrap();
+
+                    for stream in request["params"].as_array().unwrap() {
+                        let stream = stream.as_str().unwrap();
+                        if let Some(data) = payloads.get(stream) {
+                            let event = json!({ "stream": stream, "data": data });
+                            ws.send(Message::Text(event.to_string().into()))
+                                .await
+                                .unwrap();
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    format!("ws://{}", addr)
+}
+
+async fn streaming_exchange(stream_url: String) -> Arc<BinanceExchange> {
+    let mut spec = BinanceExchange::default_exchange_specification();
+    spec.api_key = None;
+    spec.secret_key = None;
+    spec.override_websocket_api_uri = Some(stream_url);
+    BinanceExchange::with_specification(spec)
+        .await
+        .expect("with_specification() should succeed")
+}
+
+fn btc_usdt() -> InstrumentDTO {
+    InstrumentDTO::Spot {
+        base: "BTC".into(),
+        counter: "USDT".into(),
+    }
+}
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[tokio::test]
+async fn test_ticker_stream() {
+    let payloads = HashMap::from([(
+        "btcusdt@ticker".to_string(),
+        json!({
+            "e": "24hrTicker", "E": 1700000000000i64, "s": "BTCUSDT",
+            "P": "1.50", "w": "30100.5", "c": "30200.1", "o": "29750.0",
+            "h": "30500.0", "l": "29500.0", "v": "1234.5", "q": "37000000.0",
+            "b": "30200.0", "B": "1.2", "a": "30200.2", "A": "0.8"
+        }),
+    )]);
+    let exchange = streaming_exchange(spawn_stream_server(payloads).await).await;
+    let service = exchange.streaming_market_data_service().unwrap();
+
+    let mut stream = service.ticker_stream(&btc_usdt()).await.unwrap();
+    let ticker = tokio::time::timeout(TIMEOUT, stream.next())
+        .await
+        .expect("ticker timeout")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(ticker.last, dec("30200.1"));
+    assert_eq!(ticker.open, dec("29750.0"));
+    assert_eq!(ticker.bid, dec("30200.0"));
+    assert_eq!(ticker.ask_size, dec("0.8"));
+    assert_eq!(ticker.volume, Some(dec("1234.5")));
+}
+
+#[tokio::test]
+async fn test_trade_stream() {
+    let payloads = HashMap::from([(
+        "btcusdt@trade".to_string(),
+        json!({
+            "e": "trade", "E": 1700000000001i64, "s": "BTCUSDT", "t": 12345,
+            "p": "30000.5", "q": "0.01", "T": 1700000000000i64, "m": true, "M": true
+        }),
+    )]);
+    let exchange = streaming_exchange(spawn_stream_server(payloads).await).await;
+    let service = exchange.streaming_market_data_service().unwrap();
+
+    let mut stream = service.trade_stream(&btc_usdt()).await.unwrap();
+    let trade = tokio::time::timeout(TIMEOUT, stream.next())
+        .await
+        .expect("trade timeout")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(trade.id, "12345");
+    assert_eq!(trade.price, dec("30000.5"));
+    assert_eq!(trade.original_amount, dec("0.01"));
+    // 买方为 maker → 主动卖出
+    assert_eq!(trade.order_type, OrderType::Ask);
+}
+
+#[tokio::test]
+async fn test_order_book_streams() {
+    let payloads = HashMap::from([
+        (
+            "btcusdt@depth20@100ms".to_string(),
+            json!({
+                "lastUpdateId": 160,
+                "bids": [["30000.0", "1.5"], ["29999.0", "2.0"]],
+                "asks": [["30001.0", "0.5"]]
+            }),
+        ),
+        (
+            "btcusdt@depth@100ms".to_string(),
+            json!({
+                "e": "depthUpdate", "E": 1700000000000i64, "s": "BTCUSDT",
+                "U": 157, "u": 160,
+                "b": [["30000.0", "0"]],
+                "a": [["30001.0", "0.7"], ["30002.0", "1.1"]]
+            }),
+        ),
+    ]);
+    let exchange = streaming_exchange(spawn_stream_server(payloads).await).await;
+    let service = exchange.streaming_market_data_service().unwrap();
+
+    let mut books = service.order_book_stream(&btc_usdt()).await.unwrap();
+    let book = tokio::time::timeout(TIMEOUT, books.next())
+        .await
+        .expect("order book timeout")
+        .unwrap()
+        .unwrap();
+    assert_eq!(book.bids.len(), 2);
+    assert_eq!(book.asks.len(), 1);
+
+    let mut updates = service.order_book_update_stream(&btc_usdt()).await.unwrap();
+    let updates = tokio::time::timeout(TIMEOUT, updates.next())
+        .await
+        .expect("order book update timeout")
+        .unwrap()
+        .unwrap();
+    assert_eq!(updates.len(), 3);
+    assert_eq!(updates[0].limit_order.order_base.type_, OrderType::Bid);
+    assert_eq!(updates[0].total_volume, Decimal::ZERO);
+}
+
+#[tokio::test]
+async fn test_candle_stick_stream() {
+    let payloads = HashMap::from([(
+        "btcusdt@kline_1m".to_string(),
+        json!({
+            "e": "kline", "E": 1700000000500i64, "s": "BTCUSDT",
+            "k": {
+                "t": 1699999980000i64, "T": 1700000039999i64, "s": "BTCUSDT", "i": "1m",
+                "o": "30000.0", "c": "30010.0", "h": "30020.0", "l": "29990.0",
+                "v": "12.5", "q": "375000.0", "x": false
+            }
+        }),
+    )]);
+    let exchange = streaming_exchange(spawn_stream_server(payloads).await).await;
+    let service = exchange.streaming_market_data_service().unwrap();
+
+    let mut stream = service.candle_stick_stream(&btc_usdt(), 60).await.unwrap();
+    let candle = tokio::time::timeout(TIMEOUT, stream.next())
+        .await
+        .expect("kline timeout")
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(candle.open, dec("30000.0"));
+    assert_eq!(candle.close, dec("30010.0"));
+    assert_eq!(candle.volume, dec("12.5"));
+
+    // 不支持的周期直接报错
+    assert!(service.candle_stick_stream(&btc_usdt(), 7).await.is_err());
+}
@@ -0,0 +1,203 @@
+use futures::future::FutureExt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime};
+use xchange_binance::binance_exchange::{
+    BinanceExchange, DEFAULT_TIME_SYNC_INTERVAL, TIME_SYNC_INTERVAL_KEY,
+};
+use xchange_binance::binance_time_provider::{BinanceTimeProvider, ClockOffset, TIME_SYNC_SAMPLES};
+use xchange_binance::dto::meta::binance_system::BinanceTime;
+use xchange_binance::dto::{BinanceError, BinanceException};
+use xchange_core::ValueFactory;
+use xchange_core::client::ResilienceRegistries;
+use xchange_core::exchange_specification::{
+    ExchangeParam, ExchangeSpecification, ResilienceSpecification,
+};
+
+const SKEW_MILLIS: i64 = 5_000;
+
+fn local_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as i64
+}
+
+fn provider() -> Arc<BinanceTimeProvider> {
+    Arc::new(BinanceTimeProvider::new(
+        ResilienceSpecification::default(),
+        Arc::new(ResilienceRegistries::new()),
+    ))
+}
+
+/// 服务器时钟比本地快 SKEW_MILLIS
+fn install_skewed_server(provider: &BinanceTimeProvider, calls: Arc<AtomicUsize>) {
+    provider.set_server_time_fetcher(Arc::new(move || {
+        calls.fetch_add(1, Ordering::SeqCst);
+        async move {
+            Ok(BinanceTime {
+                server_time: local_millis() + SKEW_MILLIS,
+            })
+        }
+        .boxed()
+    }));
+}
+
+fn timestamp_error() -> BinanceError {
+    BinanceException::new(
+        BinanceException::TIMESTAMP_OUTSIDE_RECV_WINDOW,
+        "Timestamp for this request is outside of the recvWindow.",
+    )
+    .into()
+}
+
+#[test]
+fn test_clock_offset_sample() {
+    let offset = ClockOffset::sample(1_000, 2_100, 1_200);
+    assert_eq!(offset.rtt_millis, 200);
+    assert_eq!(offset.offset_millis, 1_000);
+    assert!(!offset.is_expired());
+}
+
+#[tokio::test]
+async fn test_sync_applies_offset_to_timestamps() {
+    let provider = provider();
+    let calls = Arc::new(AtomicUsize::new(0));
+    install_skewed_server(&provider, calls.clone());
+
+    let before = provider.create() as i64 - local_millis();
+    assert!(before.abs() < 1_000);
+
+    let offset = provider.sync().await.unwrap();
+    assert_eq!(calls.load(Ordering::SeqCst), TIME_SYNC_SAMPLES);
+    assert!((offset.offset_millis - SKEW_MILLIS).abs() < 1_000);
+
+    let after = provider.create() as i64 - local_millis();
+    assert!((after - SKEW_MILLIS).abs() < 1_000);
+
+    provider.clear_delta_server_time();
+    assert!(provider.offset().is_none());
+}
+
+#[tokio::test]
+async fn test_sync_without_fetcher_fails() {
+    assert!(matches!(
+        provider().sync().await,
+        Err(BinanceError::ClientNotInitialized(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_timestamp_error_resyncs_and_retries_once() {
+    let provider = provider();
+    let syncs = Arc::new(AtomicUsize::new(0));
+    install_skewed_server(&provider, syncs.clone());
+
+    // 第一次 -1021，重新同步后成功
+    let calls = AtomicUsize::new(0);
+    let result = provider
+        .with_resync(|| async {
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Err(timestamp_error()),
+                _ => Ok("filled"),
+            }
+        })
+        .await;
+    assert_eq!(result.unwrap(), "filled");
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(syncs.load(Ordering::SeqCst), TIME_SYNC_SAMPLES);
+    assert!(provider.offset().is_some());
+
+    // 重试后仍然 -1021 时返回错误，不再重试
+    let calls = AtomicUsize::new(0);
+    let result: Result<(), _> = provider
+        .with_resync(|| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(timestamp_error())
+        })
+        .await;
+    assert!(BinanceTimeProvider::is_timestamp_error(
+        &result.unwrap_err()
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // 其他错误不触发同步
+    let calls = AtomicUsize::new(0);
+    let result: Result<(), _> = provider
+        .with_resync(|| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(BinanceError::from(BinanceException::new(-2010, "rejected")))
+        })
+        .await;
+    assert!(!BinanceTimeProvider::is_timestamp_error(
+        &result.unwrap_err()
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(syncs.load(Ordering::SeqCst), 2 * TIME_SYNC_SAMPLES);
+}
+
+#[tokio::test]
+async fn test_concurrent_timestamp_errors_sync_once() {
+    let provider = provider();
+    let syncs = Arc::new(AtomicUsize::new(0));
+    install_skewed_server(&provider, syncs.clone());
+
+    // 多个请求同时返回 -1021，只有第一个重新同步，其余复用新的偏移后重试
+    let barrier = Arc::new(tokio::sync::Barrier::new(5));
+    let requests = (0..5).map(|_| {
+        let provider = provider.clone();
+        let barrier = barrier.clone();
+        tokio::spawn(async move {
+            let calls = AtomicUsize::new(0);
+            provider
+                .with_resync(|| async {
+                    match calls.fetch_add(1, Ordering::SeqCst) {
+                        0 => {
+                            barrier.wait().await;
+                            Err(timestamp_error())
+                        }
+                        _ => Ok("filled"),
+                    }
+                })
+                .await
+        })
+    });
+    for result in futures::future::join_all(requests).await {
+        assert_eq!(result.unwrap().unwrap(), "filled");
+    }
+    assert_eq!(syncs.load(Ordering::SeqCst), TIME_SYNC_SAMPLES);
+}
+
+#[tokio::test]
+async fn test_background_refresh() {
+    let provider = provider();
+    let syncs = Arc::new(AtomicUsize::new(0));
+    install_skewed_server(&provider, syncs.clone());
+
+    provider.start_refresh(Duration::from_millis(20));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    provider.stop_refresh();
+
+    assert!(syncs.load(Ordering::SeqCst) >= 2 * TIME_SYNC_SAMPLES);
+    assert!((provider.offset().unwrap().offset_millis - SKEW_MILLIS).abs() < 1_000);
+}
+
+#[test]
+fn test_time_sync_interval() {
+    let mut spec = ExchangeSpecification::builder().build();
+    assert_eq!(
+        BinanceExchange::time_sync_interval(&spec),
+        Some(DEFAULT_TIME_SYNC_INTERVAL)
+    );
+
+    spec.exchange_specific_parameters
+        .insert(TIME_SYNC_INTERVAL_KEY.into(), ExchangeParam::Number(30_000));
+    assert_eq!(
+        BinanceExchange::time_sync_interval(&spec),
+        Some(Duration::from_secs(30))
+    );
+
+    spec.exchange_specific_parameters
+        .insert(TIME_SYNC_INTERVAL_KEY.into(), ExchangeParam::Number(0));
+    assert_eq!(BinanceExchange::time_sync_interval(&spec), None);
+}
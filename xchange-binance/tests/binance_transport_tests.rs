@@ -0,0 +1,101 @@
+use parking_lot::Mutex;
+use retrofit_rs::Query;
+use std::sync::Arc;
+use xchange_binance::binance_exchange::{BinanceExchange, EXCHANGE_TYPE_KEY};
+use xchange_binance::binance_resilience::BinanceRateLimitTracker;
+use xchange_binance::client::BinanceClientBuilder;
+use xchange_binance::client::binance_spot::BinanceAuthed;
+use xchange_binance::client::signature_interceptor::BinanceRequestSigner;
+use xchange_binance::service::BinanceHmacDigest;
+use xchange_core::ValueFactory;
+use xchange_core::client::ResilienceRegistries;
+use xchange_core::exchange::ExchangeType;
+use xchange_core::exchange_specification::ExchangeParam;
+use xchange_core::rescu::transport::{
+    FnTransport, HttpRequest, HttpResponse, HttpTransport, LoggingMiddleware, MiddlewareTransport,
+};
+
+const SECRET: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+
+struct FixedTime;
+
+impl ValueFactory<u64> for FixedTime {
+    fn create(&self) -> u64 {
+        1499827319559
+    }
+}
+
+/// 记录收到的请求，按路径返回固定响应
+fn fake_binance(requests: Arc<Mutex<Vec<HttpRequest>>>) -> Arc<dyn HttpTransport> {
+    let fake = FnTransport::new(move |request: HttpRequest| {
+        let response = match request.url.path() {
+            "/api/v3/time" => HttpResponse::new(200, r#"{"serverTime":1499827319559}"#),
+            "/api/v3/order" => HttpResponse::new(200, r#"{"orderId":28,"status":"FILLED"}"#),
+            _ => HttpResponse::new(404, ""),
+        };
+        requests.lock().push(request);
+        Ok(response.header("X-MBX-USED-WEIGHT-1M", "7"))
+    });
+    Arc::new(MiddlewareTransport::new(Arc::new(fake)).with(LoggingMiddleware))
+}
+
+#[tokio::test]
+async fn test_client_uses_injected_transport() {
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let tracker = Arc::new(BinanceRateLimitTracker::new(Arc::new(
+        ResilienceRegistries::new(),
+    )));
+    let signer = BinanceRequestSigner::new(
+        Arc::new(BinanceHmacDigest::new(SECRET).unwrap()),
+        Arc::new(FixedTime),
+    );
+
+    let client = BinanceClientBuilder::new("https://api.binance.com")
+        .api_key("test-key")
+        .rate_limit_tracker(tracker.clone())
+        .signer(Arc::new(signer))
+        .transport(fake_binance(requests.clone()))
+        .build()
+        .unwrap();
+
+    let time = client.spot.time().await.unwrap();
+    assert_eq!(time.server_time, 1499827319559);
+
+    let order = client
+        .spot
+        .order_status(Query("BTCUSDT"), Query(28), Query(""), Query(0), Query(0))
+        .await
+        .unwrap();
+    assert_eq!(order["status"], "FILLED");
+
+    // transport 收到的是签名后的请求，响应仍经过限流头中间件
+    let requests = requests.lock();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].header_value("X-MBX-APIKEY"), Some("test-key"));
+    let query = requests[1].url.query().unwrap();
+    assert!(query.contains("timestamp=1499827319559"));
+    assert!(query.contains("signature="));
+    assert_eq!(tracker.usage().used_weight_1m(), Some(7));
+}
+
+#[tokio::test]
+async fn test_exchange_with_transport() {
+    let mut spec = BinanceExchange::default_exchange_specification();
+    spec.api_key = None;
+    spec.secret_key = None;
+    spec.should_load_remote_meta_data = false;
+    spec.exchange_specific_parameters.insert(
+        EXCHANGE_TYPE_KEY.into(),
+        ExchangeParam::ExchangeType(ExchangeType::Spot),
+    );
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let exchange = BinanceExchange::with_transport(spec, Some(fake_binance(requests.clone())))
+        .await
+        .unwrap();
+    assert!(exchange.http_transport().is_some());
+
+    exchange.set_http_transport(None).unwrap();
+    assert!(exchange.http_transport().is_none());
+}
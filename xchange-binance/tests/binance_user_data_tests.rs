@@ -0,0 +1,185 @@
+use rust_decimal::Decimal;
+use serde_json::json;
+use xchange_binance::binance::BinanceAdapters;
+use xchange_binance::dto::trade::binance_user_data_event::BinanceOrderEvent;
+use xchange_core::dto::meta::exchange_metadata::ExchangeMetaData;
+use xchange_core::dto::meta::instrument_metadata::InstrumentMetaData;
+use xchange_core::dto::order::{Order, OrderStatus, OrderType};
+use xchange_core::instrument::InstrumentDTO;
+
+fn dec(s: &str) -> Decimal {
+    s.parse().unwrap()
+}
+
+fn btc_usdt() -> InstrumentDTO {
+    InstrumentDTO::Spot {
+        base: "BTC".into(),
+        counter: "USDT".into(),
+    }
+}
+
+#[test]
+fn test_execution_report_partial_fill() {
+    let data = json!({
+        "e": "executionReport", "E": 1700000000100i64, "s": "BTCUSDT", "c": "my-order-1",
+        "S": "BUY", "o": "LIMIT", "f": "GTC", "q": "2.00000000", "p": "30000.00",
+        "P": "0.00", "F": "0.00", "g": -1, "C": "", "x": "TRADE", "X": "PARTIALLY_FILLED",
+        "r": "NONE", "i": 4293153, "l": "0.50000000", "z": "1.50000000",
+        "L": "30000.00", "n": "0.00050000", "N": "BTC", "T": 1700000000099i64,
+        "t": 987, "I": 8641984, "w": true, "m": false, "M": true, "O": 1700000000000i64,
+        "Z": "44925.00", "Y": "15000.00", "Q": "0.00"
+    });
+
+    let event = BinanceOrderEvent::from_value(data).unwrap().unwrap();
+    assert_eq!(event.symbol(), "BTCUSDT");
+
+    let Order::LimitOrder(order) = event.to_order(btc_usdt()) else {
+        panic!("LIMIT order expected");
+    };
+    assert_eq!(order.order_base.id, "4293153");
+    assert_eq!(order.order_base.type_, OrderType::Bid);
+    assert_eq!(order.order_base.status, Some(OrderStatus::PartiallyFilled));
+    assert_eq!(order.order_base.cumulative_amount, Some(dec("1.5")));
+    // 44925 / 1.5
+    assert_eq!(order.order_base.average_price, Some(dec("29950")));
+    assert_eq!(
+        order.order_base.user_reference.as_deref(),
+        Some("my-order-1")
+    );
+
+    let trade = event
+        .to_user_trade(btc_usdt())
+        .expect("TRADE event carries a fill");
+    assert_eq!(trade.order_id, "4293153");
+    assert_eq!(trade.trade.id, "987");
+    assert_eq!(trade.trade.original_amount, dec("0.5"));
+    assert_eq!(trade.fee_amount, dec("0.0005"));
+    assert_eq!(trade.fee_currency.code, "BTC");
+}
+
+#[test]
+fn test_execution_report_cancel_has_no_fill() {
+    let data = json!({
+        "e": "executionReport", "E": 1700000000100i64, "s": "BTCUSDT", "c": "",
+        "S": "SELL", "o": "MARKET", "q": "1.0", "p": "0", "x": "CANCELED", "X": "CANCELED",
+        "i": 1, "l": "0", "z": "0", "L": "0", "n": "0", "N": null, "T": 1700000000099i64,
+        "t": -1, "m": false, "Z": "0"
+    });
+
+    let event = BinanceOrderEvent::from_value(data).unwrap().unwrap();
+    let Order::MarketOrder(order) = event.to_order(btc_usdt()) else {
+        panic!("MARKET order expected");
+    };
+    assert_eq!(order.order_base.status, Some(OrderStatus::CANCELED));
+    assert_eq!(order.order_base.average_price, None);
+    assert!(event.to_user_trade(btc_usdt()).is_none());
+}
+
+#[test]
+fn test_execution_report_cancel_uses_orig_client_order_id() {
+    let data = json!({
+        "e": "executionReport", "E": 1700000000100i64, "s": "BTCUSDT", "c": "cancel-req-7",
+        "C": "my-order-1", "S": "BUY", "o": "LIMIT", "q": "2.0", "p": "30000", "x": "CANCELED",
+        "X": "CANCELED", "i": 4293153, "l": "0", "z": "0", "L": "0", "n": "0", "N": null,
+        "T": 1700000000099i64, "t": -1, "m": false, "Z": "0"
+    });
+
+    let event = BinanceOrderEvent::from_value(data).unwrap().unwrap();
+    let Order::LimitOrder(order) = event.to_order(btc_usdt()) else {
+        panic!("LIMIT order expected");
+    };
+    assert_eq!(
+        order.order_base.user_reference.as_deref(),
+        Some("my-order-1")
+    );
+}
+
+#[test]
+fn test_futures_order_trade_update() {
+    let data = json!({
+        "e": "ORDER_TRADE_UPDATE", "E": 1700000000100i64, "T": 1700000000098i64,
+        "o": {
+            "s": "BTCUSDT", "c": "close-long", "S": "SELL", "o": "LIMIT", "f": "GTC",
+            "q": "0.010", "p": "31000", "ap": "31000.5", "sp": "0", "x": "TRADE", "X": "FILLED",
+            "i": 8886774, "l": "0.010", "z": "0.010", "L": "31000.5", "N": "USDT", "n": "0.1240",
+            "T": 1700000000098i64, "t": 1234, "b": "0", "a": "0", "m": true, "R": true,
+            "wt": "CONTRACT_PRICE", "ot": "LIMIT", "ps": "BOTH", "cp": false, "rp": "12.3"
+        }
+    });
+
+    let event = BinanceOrderEvent::from_value(data).unwrap().unwrap();
+    let instrument = BinanceAdapters::adapt_symbol(event.symbol(), true).unwrap();
+
+    let order = event.to_order(instrument.clone());
+    let Order::LimitOrder(order) = order else {
+        panic!("LIMIT order expected");
+    };
+    // reduceOnly 卖单 → 平多
+    assert_eq!(order.order_base.type_, OrderType::ExitBid);
+    assert_eq!(order.order_base.status, Some(OrderStatus::FILLED));
+    assert_eq!(order.order_base.average_price, Some(dec("31000.5")));
+
+    let trade = event.to_user_trade(instrument).unwrap();
+    assert_eq!(trade.fee_currency.code, "USDT");
+    assert_eq!(trade.trade.maker_order_id, "8886774");
+}
+
+#[test]
+fn test_non_order_events_are_ignored() {
+    let data = json!({ "e": "outboundAccountPosition", "E": 1, "u": 1, "B": [] });
+    assert!(BinanceOrderEvent::from_value(data).is_none());
+}
+
+#[test]
+fn test_adapt_symbol() {
+    assert_eq!(
+        BinanceAdapters::adapt_symbol("ETHBTC", false),
+        Some(InstrumentDTO::Spot {
+            base: "ETH".into(),
+            counter: "BTC".into(),
+        })
+    );
+    assert_eq!(
+        BinanceAdapters::adapt_symbol("BTCUSD_PERP", true),
+        Some(InstrumentDTO::Futures {
+            base: "BTC".into(),
+            counter: "USD".into(),
+            prompt: Some("PERP".into()),
+        })
+    );
+    assert_eq!(BinanceAdapters::adapt_symbol("USDT", false), None);
+}
+
+#[test]
+fn test_adapt_symbol_with_meta_data() {
+    let eth_aeur = InstrumentDTO::Spot {
+        base: "ETH".into(),
+        counter: "AEUR".into(),
+    };
+    let btc_usdt_quarter = InstrumentDTO::Futures {
+        base: "BTC".into(),
+        counter: "USDT".into(),
+        prompt: Some("240628".into()),
+    };
+    let mut meta_data = ExchangeMetaData::default();
+    for instrument in [eth_aeur.clone(), btc_usdt_quarter.clone()] {
+        meta_data
+            .instruments
+            .insert(instrument, InstrumentMetaData::builder().build());
+    }
+
+    // 计价币后缀规则会拆成 ETHA/EUR，按 exchangeInfo 为 ETH/AEUR
+    assert_eq!(
+        BinanceAdapters::adapt_symbol_with_meta_data("ETHAEUR", false, &meta_data),
+        Some(eth_aeur)
+    );
+    assert_eq!(
+        BinanceAdapters::adapt_symbol_with_meta_data("BTCUSDT_240628", true, &meta_data),
+        Some(btc_usdt_quarter)
+    );
+    // meta data 中没有的 symbol 退回后缀规则
+    assert_eq!(
+        BinanceAdapters::adapt_symbol_with_meta_data("ETHBTC", false, &meta_data),
+        BinanceAdapters::adapt_symbol("ETHBTC", false)
+    );
+}
@@ -0,0 +1,102 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::timeout;
+
+/// 并发数已满且等待超时
+#[derive(Debug, Clone)]
+pub struct BulkheadFullError {
+    pub name: String,
+    pub max_concurrent_calls: usize,
+}
+
+impl fmt::Display for BulkheadFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "bulkhead '{}' is full ({} concurrent calls)",
+            self.name, self.max_concurrent_calls
+        )
+    }
+}
+
+impl Error for BulkheadFullError {}
+
+/// 限制同一组 endpoint 的并发请求数，交易所故障时避免请求无限堆积
+#[derive(Debug)]
+pub struct Bulkhead {
+    name: String,
+    max_concurrent_calls: usize,
+    max_wait: Duration,
+    semaphore: Arc<Semaphore>,
+}
+
+impl Bulkhead {
+    /// `max_wait` 为等待空位的最长时间，为 0 时立即失败
+    pub fn new(name: impl Into<String>, max_concurrent_calls: usize, max_wait: Duration) -> Self {
+        Self {
+            name: name.into(),
+            max_concurrent_calls,
+            max_wait,
+            semaphore: Arc::new(Semaphore::new(max_concurrent_calls)),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn max_concurrent_calls(&self) -> usize {
+        self.max_concurrent_calls
+    }
+
+    /// 当前空闲的并发名额
+    pub fn available(&self) -> usize {
+        self.semaphore.available_permits()
+    }
+
+    /// 获取一个并发名额，permit 释放时归还
+    pub async fn acquire(&self) -> Result<BulkheadPermit, BulkheadFullError> {
+        let semaphore = self.semaphore.clone();
+        let permit = if self.max_wait.is_zero() {
+            semaphore.try_acquire_owned().ok()
+        } else {
+            timeout(self.max_wait, semaphore.acquire_owned())
+                .await
+                .ok()
+                .and_then(Result::ok)
+        };
+
+        permit
+            .map(|permit| BulkheadPermit { _permit: permit })
+            .ok_or_else(|| BulkheadFullError {
+                name: self.name.clone(),
+                max_concurrent_calls: self.max_concurrent_calls,
+            })
+    }
+}
+
+pub struct BulkheadPermit {
+    _permit: OwnedSemaphorePermit,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    #[test]
+    fn test_rejects_when_full() {
+        let bulkhead = Bulkhead::new("test", 2, Duration::ZERO);
+        let first = bulkhead.acquire().now_or_never().unwrap();
+        let second = bulkhead.acquire().now_or_never().unwrap();
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(bulkhead.available(), 0);
+        assert!(bulkhead.acquire().now_or_never().unwrap().is_err());
+
+        drop(first);
+        assert!(bulkhead.acquire().now_or_never().unwrap().is_ok());
+    }
+}
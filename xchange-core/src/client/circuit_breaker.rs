@@ -0,0 +1,352 @@
+use crate::client::{RetryConfig, RetryDecision};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// 正常放行，统计失败率
+    Closed,
+    /// 熔断中，直接拒绝
+    Open,
+    /// 放行少量试探请求，全部成功则关闭，任一失败则重新打开
+    HalfOpen,
+}
+
+/// 哪些错误计入失败率
+pub type FailurePredicate = Arc<dyn Fn(&(dyn Error + Send + Sync + 'static)) -> bool + Send + Sync>;
+
+pub struct CircuitBreakerConfig {
+    /// 失败率阈值 (0, 1]
+    pub failure_rate_threshold: f64,
+    /// 按最近 N 次调用统计失败率
+    pub sliding_window_size: usize,
+    /// 窗口内至少有这么多次调用才计算失败率
+    pub minimum_number_of_calls: usize,
+    /// 打开后多久进入半开
+    pub wait_duration_in_open_state: Duration,
+    /// 半开状态允许的试探请求数
+    pub permitted_calls_in_half_open_state: usize,
+    pub failure_predicate: FailurePredicate,
+}
+
+impl Default for CircuitBreakerConfig {
+    /// 只有瞬时错误（超时、5xx、交易所不可用等）计为失败，订单被拒绝等业务错误不计
+    fn default() -> Self {
+        Self {
+            failure_rate_threshold: 0.5,
+            sliding_window_size: 20,
+            minimum_number_of_calls: 10,
+            wait_duration_in_open_state: Duration::from_secs(30),
+            permitted_calls_in_half_open_state: 3,
+            failure_predicate: Arc::new(|e| {
+                RetryConfig::transient_errors(e) != RetryDecision::Abort
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for CircuitBreakerConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CircuitBreakerConfig")
+            .field("failure_rate_threshold", &self.failure_rate_threshold)
+            .field("sliding_window_size", &self.sliding_window_size)
+            .field("minimum_number_of_calls", &self.minimum_number_of_calls)
+            .field(
+                "wait_duration_in_open_state",
+                &self.wait_duration_in_open_state,
+            )
+            .field(
+                "permitted_calls_in_half_open_state",
+                &self.permitted_calls_in_half_open_state,
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+/// 熔断打开时的快速失败
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerOpenError {
+    pub name: String,
+    /// 距离进入半开的剩余时间
+    pub retry_in: Duration,
+}
+
+impl fmt::Display for CircuitBreakerOpenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "circuit breaker '{}' is open, retry in {:?}",
+            self.name, self.retry_in
+        )
+    }
+}
+
+impl Error for CircuitBreakerOpenError {}
+
+/// 基于失败率的熔断器
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: String,
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug)]
+enum BreakerState {
+    /// 最近调用结果，true 为失败
+    Closed {
+        outcomes: VecDeque<bool>,
+    },
+    Open {
+        until: Instant,
+    },
+    HalfOpen {
+        in_flight: usize,
+        succeeded: usize,
+    },
+}
+
+impl CircuitBreaker {
+    pub fn new(name: impl Into<String>, config: CircuitBreakerConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            state: Mutex::new(BreakerState::Closed {
+                outcomes: VecDeque::new(),
+            }),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn state(&self) -> CircuitState {
+        let mut state = self.state.lock();
+        self.advance(&mut state);
+        match *state {
+            BreakerState::Closed { .. } => CircuitState::Closed,
+            BreakerState::Open { .. } => CircuitState::Open,
+            BreakerState::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// 调用前检查，熔断打开或半开试探名额已满时拒绝
+    pub fn try_acquire(&self) -> Result<(), CircuitBreakerOpenError> {
+        let mut state = self.state.lock();
+        self.advance(&mut state);
+        match &mut *state {
+            BreakerState::Closed { .. } => Ok(()),
+            BreakerState::Open { until } => Err(self.open_error(*until)),
+            BreakerState::HalfOpen {
+                in_flight,
+                succeeded,
+            } => {
+                if *in_flight + *succeeded >= self.config.permitted_calls_in_half_open_state {
+                    return Err(CircuitBreakerOpenError {
+                        name: self.name.clone(),
+                        retry_in: Duration::ZERO,
+                    });
+                }
+                *in_flight += 1;
+                Ok(())
+            }
+        }
+    }
+
+    /// 同 `try_acquire`，返回的许可在 drop 时若未记录结果则归还半开试探名额，
+    /// 避免调用被取消或在本地失败后熔断器卡在半开
+    pub fn acquire(&self) -> Result<CircuitBreakerPermit<'_>, CircuitBreakerOpenError> {
+        self.try_acquire()?;
+        Ok(CircuitBreakerPermit {
+            breaker: self,
+            half_open: matches!(*self.state.lock(), BreakerState::HalfOpen { .. }),
+            recorded: false,
+        })
+    }
+
+    pub fn on_success(&self) {
+        self.record(false);
+    }
+
+    /// 按 `failure_predicate` 判断是否计为失败
+    pub fn on_error(&self, error: &(dyn Error + Send + Sync + 'static)) {
+        self.record((self.config.failure_predicate)(error));
+    }
+
+    fn record(&self, failed: bool) {
+        let mut state = self.state.lock();
+        match &mut *state {
+            BreakerState::Closed { outcomes } => {
+                outcomes.push_back(failed);
+                while outcomes.len() > self.config.sliding_window_size {
+                    outcomes.pop_front();
+                }
+                let failures = outcomes.iter().filter(|failed| **failed).count();
+                if outcomes.len() >= self.config.minimum_number_of_calls.max(1)
+                    && failures as f64 / outcomes.len() as f64 >= self.config.failure_rate_threshold
+                {
+                    log::warn!(
+                        "circuit breaker '{}' opened, failure rate {}/{}",
+                        self.name,
+                        failures,
+                        outcomes.len()
+                    );
+                    *state = self.open();
+                }
+            }
+            BreakerState::HalfOpen {
+                in_flight,
+                succeeded,
+            } => {
+                *in_flight = in_flight.saturating_sub(1);
+                if failed {
+                    *state = self.open();
+                } else {
+                    *succeeded += 1;
+                    if *succeeded >= self.config.permitted_calls_in_half_open_state {
+                        *state = BreakerState::Closed {
+                            outcomes: VecDeque::new(),
+                        };
+                    }
+                }
+            }
+            // 打开前发出的请求返回，不影响状态
+            BreakerState::Open { .. } => {}
+        }
+    }
+
+    /// 不计入统计，只归还半开试探名额
+    fn release(&self) {
+        if let BreakerState::HalfOpen { in_flight, .. } = &mut *self.state.lock() {
+            *in_flight = in_flight.saturating_sub(1);
+        }
+    }
+
+    fn advance(&self, state: &mut BreakerState) {
+        if let BreakerState::Open { until } = state
+            && Instant::now() >= *until
+        {
+            *state = BreakerState::HalfOpen {
+                in_flight: 0,
+                succeeded: 0,
+            };
+        }
+    }
+
+    fn open(&self) -> BreakerState {
+        BreakerState::Open {
+            until: Instant::now() + self.config.wait_duration_in_open_state,
+        }
+    }
+
+    fn open_error(&self, until: Instant) -> CircuitBreakerOpenError {
+        CircuitBreakerOpenError {
+            name: self.name.clone(),
+            retry_in: until.saturating_duration_since(Instant::now()),
+        }
+    }
+}
+
+/// `CircuitBreaker::acquire` 返回的调用许可，只记录远程调用本身的结果
+#[derive(Debug)]
+pub struct CircuitBreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    half_open: bool,
+    recorded: bool,
+}
+
+impl CircuitBreakerPermit<'_> {
+    pub fn on_success(mut self) {
+        self.recorded = true;
+        self.breaker.on_success();
+    }
+
+    pub fn on_error(mut self, error: &(dyn Error + Send + Sync + 'static)) {
+        self.recorded = true;
+        self.breaker.on_error(error);
+    }
+}
+
+impl Drop for CircuitBreakerPermit<'_> {
+    fn drop(&mut self) {
+        if !self.recorded && self.half_open {
+            self.breaker.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::HttpStatusError;
+
+    fn breaker(wait: Duration) -> CircuitBreaker {
+        CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                sliding_window_size: 4,
+                minimum_number_of_calls: 4,
+                wait_duration_in_open_state: wait,
+                permitted_calls_in_half_open_state: 1,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_opens_on_failure_rate() {
+        let breaker = breaker(Duration::from_secs(60));
+        let unavailable = HttpStatusError::new(503, "unavailable");
+        let rejected = HttpStatusError::new(400, "rejected");
+
+        breaker.on_success();
+        breaker.on_error(&rejected);
+        breaker.on_error(&unavailable);
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        // 4 次中 2 次瞬时失败，达到 50%
+        breaker.on_error(&unavailable);
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.try_acquire().is_err());
+    }
+
+    #[test]
+    fn test_half_open_recovers() {
+        let breaker = breaker(Duration::from_millis(10));
+        for _ in 0..4 {
+            breaker.on_error(&HttpStatusError::new(502, "bad gateway"));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(15));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire().is_ok());
+        // 试探名额已用完
+        assert!(breaker.try_acquire().is_err());
+        breaker.on_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_dropped_permit_releases_half_open_slot() {
+        let breaker = breaker(Duration::from_millis(10));
+        for _ in 0..4 {
+            breaker.on_error(&HttpStatusError::new(502, "bad gateway"));
+        }
+        std::thread::sleep(Duration::from_millis(15));
+
+        // 未记录结果的许可被丢弃，试探名额归还，状态不变
+        let permit = breaker.acquire().unwrap();
+        assert!(breaker.acquire().is_err());
+        drop(permit);
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+
+        breaker.acquire().unwrap().on_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
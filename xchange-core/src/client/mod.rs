@@ -1,74 +1,26 @@
+use crate::exchange_specification::ResilienceSpecification;
 use futures::FutureExt;
 use futures::future::BoxFuture;
+use std::time::Instant;
 use std::{collections::HashMap, sync::Arc, time::Duration};
-use tokio::sync::Semaphore;
-use tokio::time::sleep;
-
-/// ========================
-/// Retry Config
-/// ========================
-#[derive(Debug)]
-pub struct RetryConfig {
-    pub max_attempts: usize,
-    pub initial_delay: Duration,
-    pub multiplier: f64,
-}
-
-impl RetryConfig {
-    pub fn delay_for_attempt(&self, attempt: usize) -> Duration {
-        let factor = self.multiplier.powi(attempt as i32);
-        self.initial_delay.mul_f64(factor)
-    }
-}
-
-/// ========================
-/// RateLimiter
-/// ========================
-#[derive(Debug)]
-pub struct RateLimiter {
-    capacity: usize,
-    semaphore: Arc<Semaphore>,
-    refill_period: Duration,
-}
-
-#[derive(Debug)]
-pub enum RateLimiterError {
-    AcquireFailed,
-}
-
-impl RateLimiter {
-    pub fn new(capacity: usize, refill_period: Duration) -> Self {
-        Self {
-            capacity,
-            semaphore: Arc::new(Semaphore::new(capacity)),
-            refill_period,
-        }
-    }
-
-    pub async fn acquire(&self) -> Result<RateLimiterPermit, RateLimiterError> {
-        let permit = self
-            .semaphore
-            .clone()
-            .acquire_owned()
-            .await
-            .map_err(|_| RateLimiterError::AcquireFailed)?;
-
-        let sem_clone = self.semaphore.clone();
-        let period = self.refill_period;
-
-        tokio::spawn(async move {
-            sleep(period).await;
-            sem_clone.add_permits(1);
-        });
-
-        Ok(RateLimiterPermit { _permit: permit })
-    }
-}
-
-/// 用于保持 semaphore permit 生命周期
-pub struct RateLimiterPermit {
-    _permit: tokio::sync::OwnedSemaphorePermit,
-}
+use tokio::time::{sleep, timeout_at};
+
+mod bulkhead;
+mod circuit_breaker;
+mod rate_limiter;
+mod retry;
+mod timeout;
+
+pub use bulkhead::{Bulkhead, BulkheadFullError, BulkheadPermit};
+pub use circuit_breaker::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitBreakerOpenError, CircuitBreakerPermit,
+    CircuitState, FailurePredicate,
+};
+pub use rate_limiter::{RateLimitMode, RateLimiter, RateLimiterError, RateLimiterPermit};
+pub use retry::{
+    HttpStatusError, RetryConfig, RetryDecision, RetryEvent, RetryHook, RetryPredicate,
+};
+pub use timeout::TimeoutError;
 
 /// ========================
 /// Resilience Registries
@@ -77,6 +29,10 @@ pub struct RateLimiterPermit {
 pub struct ResilienceRegistries {
     pub retry_configs: HashMap<String, Arc<RetryConfig>>,
     pub rate_limiters: HashMap<String, Arc<RateLimiter>>,
+    /// 各 endpoint 的请求权重，未声明的按 1 计
+    pub endpoint_weights: HashMap<String, u32>,
+    pub circuit_breakers: HashMap<String, Arc<CircuitBreaker>>,
+    pub bulkheads: HashMap<String, Arc<Bulkhead>>,
 }
 
 impl ResilienceRegistries {
@@ -87,19 +43,15 @@ impl ResilienceRegistries {
         let mut retry_configs = HashMap::new();
         retry_configs.insert(
             Self::DEFAULT_RETRY.into(),
-            Arc::new(RetryConfig {
-                max_attempts: 3,
-                initial_delay: Duration::from_millis(50),
-                multiplier: 2.0,
-            }),
+            Arc::new(RetryConfig::new(3, Duration::from_millis(50), 2.0)),
         );
+        // 下单等非幂等请求：只重试确定未到达交易所的失败
         retry_configs.insert(
             Self::NON_IDEMPOTENT.into(),
-            Arc::new(RetryConfig {
-                max_attempts: 1,
-                initial_delay: Duration::from_millis(50),
-                multiplier: 1.0,
-            }),
+            Arc::new(
+                RetryConfig::new(3, Duration::from_millis(50), 2.0)
+                    .with_predicate(RetryConfig::not_sent_errors),
+            ),
         );
 
         let mut rate_limiters = HashMap::new();
@@ -111,6 +63,9 @@ impl ResilienceRegistries {
         Self {
             retry_configs,
             rate_limiters,
+            endpoint_weights: HashMap::new(),
+            circuit_breakers: HashMap::new(),
+            bulkheads: HashMap::new(),
         }
     }
 
@@ -121,6 +76,139 @@ impl ResilienceRegistries {
     pub fn rate_limiter(&self, name: &str) -> Option<Arc<RateLimiter>> {
         self.rate_limiters.get(name).cloned()
     }
+
+    pub fn circuit_breaker(&self, name: &str) -> Option<Arc<CircuitBreaker>> {
+        self.circuit_breakers.get(name).cloned()
+    }
+
+    pub fn bulkhead(&self, name: &str) -> Option<Arc<Bulkhead>> {
+        self.bulkheads.get(name).cloned()
+    }
+
+    pub fn weight(&self, endpoint: &str) -> u32 {
+        self.endpoint_weights.get(endpoint).copied().unwrap_or(1)
+    }
+
+    pub fn set_weight(&mut self, endpoint: impl Into<String>, weight: u32) {
+        self.endpoint_weights.insert(endpoint.into(), weight);
+    }
+
+    /// 按 spec 的开关把 policy 中声明的组件装配到调用上，关闭的功能即使注册了也不会生效。
+    /// 找不到 `policy.retry` 时退回 `DEFAULT_RETRY`
+    pub fn decorate<T>(
+        &self,
+        mut call: ResilientCall<T>,
+        spec: &ResilienceSpecification,
+        policy: &ResiliencePolicy<'_>,
+    ) -> ResilientCall<T>
+    where
+        T: Send + 'static,
+    {
+        if spec.retry_enabled
+            && let Some(retry) = policy
+                .retry
+                .and_then(|name| self.retry(name).or_else(|| self.retry(Self::DEFAULT_RETRY)))
+        {
+            call = call.with_retry(retry);
+        }
+        if spec.rate_limiter_enabled
+            && let Some(limiter) = policy.rate_limiter.and_then(|name| self.rate_limiter(name))
+        {
+            let weight = policy
+                .weight
+                .or_else(|| policy.endpoint.map(|endpoint| self.weight(endpoint)))
+                .unwrap_or(1);
+            call = call.with_rate_limiter(limiter).with_weight(weight);
+        }
+        if spec.circuit_breaker_enabled
+            && let Some(breaker) = policy.group.and_then(|group| self.circuit_breaker(group))
+        {
+            call = call.with_circuit_breaker(breaker);
+        }
+        if spec.bulkhead_enabled
+            && let Some(bulkhead) = policy.group.and_then(|group| self.bulkhead(group))
+        {
+            call = call.with_bulkhead(bulkhead);
+        }
+        // 超时不受开关控制，policy 未指定时使用 spec 的默认值
+        if let Some(timeout) = policy.attempt_timeout {
+            call = call.with_attempt_timeout(timeout);
+        }
+        if let Some(timeout) = policy.timeout.or(spec.call_timeout) {
+            call = call.with_timeout(timeout);
+        }
+        if let Some(deadline) = policy.deadline {
+            call = call.with_deadline(deadline);
+        }
+        call
+    }
+}
+
+/// 一次调用使用的注册表条目，由 `ResilienceRegistries::decorate` 按 spec 开关装配
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResiliencePolicy<'a> {
+    /// RetryConfig 名称
+    pub retry: Option<&'a str>,
+    /// RateLimiter 名称
+    pub rate_limiter: Option<&'a str>,
+    /// 按 endpoint 查找请求权重
+    pub endpoint: Option<&'a str>,
+    /// 直接指定权重，优先于 endpoint
+    pub weight: Option<u32>,
+    /// 熔断器和并发隔离所在分组
+    pub group: Option<&'a str>,
+    /// 单次尝试的超时
+    pub attempt_timeout: Option<Duration>,
+    /// 整体时间预算，优先于 spec 的 `call_timeout`
+    pub timeout: Option<Duration>,
+    /// 绝对截止时间
+    pub deadline: Option<Instant>,
+}
+
+impl<'a> ResiliencePolicy<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn retry(mut self, name: &'a str) -> Self {
+        self.retry = Some(name);
+        self
+    }
+
+    pub fn rate_limiter(mut self, name: &'a str) -> Self {
+        self.rate_limiter = Some(name);
+        self
+    }
+
+    pub fn endpoint(mut self, endpoint: &'a str) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    pub fn weight(mut self, weight: u32) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn group(mut self, group: &'a str) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    pub fn attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
 }
 
 /// ========================
@@ -134,6 +222,12 @@ pub struct ResilientCall<T> {
     >,
     retry_cfg: Option<Arc<RetryConfig>>,
     rate_limiter: Option<Arc<RateLimiter>>,
+    weight: u32,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    bulkhead: Option<Arc<Bulkhead>>,
+    attempt_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    deadline: Option<Instant>,
 }
 
 impl<T> ResilientCall<T>
@@ -149,6 +243,12 @@ where
             func: Box::new(move || func().boxed()),
             retry_cfg: None,
             rate_limiter: None,
+            weight: 1,
+            circuit_breaker: None,
+            bulkhead: None,
+            attempt_timeout: None,
+            timeout: None,
+            deadline: None,
         }
     }
 
@@ -162,35 +262,181 @@ where
         self
     }
 
+    pub fn with_circuit_breaker(mut self, circuit_breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    pub fn with_bulkhead(mut self, bulkhead: Arc<Bulkhead>) -> Self {
+        self.bulkhead = Some(bulkhead);
+        self
+    }
+
+    /// 每次尝试向限流器申请的权重，默认 1
+    pub fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// 单次尝试的超时，超时返回 `TimeoutError::Attempt`，按瞬时错误重试
+    pub fn with_attempt_timeout(mut self, timeout: Duration) -> Self {
+        self.attempt_timeout = Some(timeout);
+        self
+    }
+
+    /// 整体时间预算，从 `call()` 开始计时，覆盖重试退避和限流 / 并发隔离等待
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 绝对截止时间，与 `with_timeout` 同时设置时取较早者
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// 无法在截止时间前完成时返回 `TimeoutError::DeadlineExceeded`，不会再发出请求
     pub async fn call(&self) -> Result<T, Box<dyn std::error::Error + Send + Sync>> {
+        let deadline = earliest(self.deadline, self.timeout.map(|t| Instant::now() + t));
         let mut attempt = 0;
+        let mut last_error = None;
 
         loop {
-            if let Some(ref limiter) = self.rate_limiter {
-                let _permit = limiter.acquire().await;
+            // 每次尝试（包括退避之后的重试）前检查剩余时间
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(boxed(TimeoutError::DeadlineExceeded { last_error }));
             }
 
-            let fut = (self.func)();
-            let result = fut.await;
+            let error = match self.attempt(deadline).await {
+                Ok(val) => return Ok(val),
+                Err(Attempt::Failed(e)) => e,
+                // 截止时间在等待或调用中到期，不再重试
+                Err(Attempt::DeadlineExceeded) => {
+                    return Err(boxed(TimeoutError::DeadlineExceeded { last_error }));
+                }
+            };
+
+            // 没有 retry 配置直接返回
+            let Some(cfg) = &self.retry_cfg else {
+                return Err(error);
+            };
 
-            if let Ok(val) = result {
-                return Ok(val);
+            attempt += 1;
+            if attempt >= cfg.max_attempts {
+                return Err(error); // 超过最大尝试次数
+            }
+            let decision = cfg.decide(error.as_ref());
+            if decision == RetryDecision::Abort {
+                return Err(error);
             }
 
-            attempt += 1;
-            if let Some(cfg) = &self.retry_cfg {
-                if attempt >= cfg.max_attempts {
-                    return result; // 超过最大尝试次数
-                }
-                let delay = cfg.delay_for_attempt(attempt);
-                sleep(delay).await;
-            } else {
-                return result; // 没有 retry 配置直接返回
+            let delay = cfg.backoff(attempt, decision);
+            if deadline.is_some_and(|deadline| Instant::now() + delay >= deadline) {
+                return Err(boxed(TimeoutError::DeadlineExceeded {
+                    last_error: Some(error),
+                }));
             }
+            if let Some(hook) = &cfg.on_retry {
+                hook(&RetryEvent {
+                    attempt,
+                    delay,
+                    error: error.as_ref(),
+                });
+            }
+            sleep(delay).await;
+            last_error = Some(error);
         }
     }
 }
 
+/// 单次尝试的失败：截止时间到期与调用返回的错误分开，前者不计入熔断也不重试
+enum Attempt {
+    Failed(Box<dyn std::error::Error + Send + Sync>),
+    DeadlineExceeded,
+}
+
+impl<T> ResilientCall<T>
+where
+    T: Send + 'static,
+{
+    /// 单次尝试：熔断检查 → 并发隔离 → 限流 → 调用，各阶段的等待都不超过截止时间。
+    ///
+    /// 熔断器只记录远程调用的结果（包括单次尝试超时），并发隔离 / 限流等待和截止时间到期只归还许可
+    async fn attempt(&self, deadline: Option<Instant>) -> Result<T, Attempt> {
+        let permit = match &self.circuit_breaker {
+            Some(breaker) => Some(breaker.acquire().map_err(|e| Attempt::Failed(boxed(e)))?),
+            None => None,
+        };
+
+        let _bulkhead_permit = match &self.bulkhead {
+            Some(bulkhead) => Some(match deadline {
+                Some(deadline) => timeout_at(deadline.into(), bulkhead.acquire())
+                    .await
+                    .map_err(|_| Attempt::DeadlineExceeded)?
+                    .map_err(|e| Attempt::Failed(boxed(e)))?,
+                None => bulkhead
+                    .acquire()
+                    .await
+                    .map_err(|e| Attempt::Failed(boxed(e)))?,
+            }),
+            None => None,
+        };
+        if let Some(limiter) = &self.rate_limiter {
+            match deadline {
+                Some(deadline) => limiter
+                    .acquire_before(self.weight, deadline)
+                    .await
+                    .map_err(|e| match e {
+                        RateLimiterError::WouldBlock { .. } => Attempt::DeadlineExceeded,
+                        e => Attempt::Failed(boxed(e)),
+                    })?,
+                None => limiter
+                    .acquire(self.weight)
+                    .await
+                    .map_err(|e| Attempt::Failed(boxed(e)))?,
+            };
+        }
+
+        let result = self.invoke(deadline).await?;
+        if let Some(permit) = permit {
+            match &result {
+                Ok(_) => permit.on_success(),
+                Err(e) => permit.on_error(e.as_ref()),
+            }
+        }
+        result.map_err(Attempt::Failed)
+    }
+
+    /// 调用 func，受单次超时和截止时间限制；截止时间先到时返回 `Attempt::DeadlineExceeded`
+    async fn invoke(
+        &self,
+        deadline: Option<Instant>,
+    ) -> Result<Result<T, Box<dyn std::error::Error + Send + Sync>>, Attempt> {
+        let attempt_deadline = self.attempt_timeout.map(|t| Instant::now() + t);
+        let Some(limit) = earliest(attempt_deadline, deadline) else {
+            return Ok((self.func)().await);
+        };
+
+        match timeout_at(limit.into(), (self.func)()).await {
+            Ok(result) => Ok(result),
+            Err(_) => match self.attempt_timeout {
+                Some(timeout) if deadline != Some(limit) => {
+                    Ok(Err(boxed(TimeoutError::Attempt { timeout })))
+                }
+                _ => Err(Attempt::DeadlineExceeded),
+            },
+        }
+    }
+}
+
+fn earliest(a: Option<Instant>, b: Option<Instant>) -> Option<Instant> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    }
+}
+
 /// 将任意错误装箱为 Box<dyn Error + Send + Sync>
 pub fn boxed<E>(e: E) -> Box<dyn std::error::Error + Send + Sync>
 where
@@ -218,18 +464,211 @@ mod tests {
     #[tokio::test]
     async fn test_resilient_call() {
         let registries = Arc::new(ResilienceRegistries::new());
-        let client = DummyClient;
+        let client = Arc::new(DummyClient);
+
+        let limiter = registries.rate_limiter("global").unwrap();
+        let retry_cfg = registries.retry("global").unwrap();
+
+        let result = ResilientCall::new(move || {
+            let client = client.clone();
+            async move { client.ping().await }
+        })
+        .with_rate_limiter(limiter)
+        .with_retry(retry_cfg)
+        .call()
+        .await
+        .unwrap();
+
+        assert_eq!(result, "pong");
+    }
 
-        let limiter = registries.get_rate_limiter("global").unwrap();
-        let retry_cfg = registries.get_retry("global").unwrap();
+    #[tokio::test]
+    async fn test_decorate_respects_specification() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let registries = ResilienceRegistries::new();
+        let calls = Arc::new(AtomicU32::new(0));
+        let policy = ResiliencePolicy::new()
+            .retry(ResilienceRegistries::DEFAULT_RETRY)
+            .rate_limiter("global")
+            .weight(10);
+        let failing = |calls: Arc<AtomicU32>| {
+            ResilientCall::new(move || {
+                let calls = calls.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err::<(), _>(boxed(HttpStatusError::new(503, "unavailable")))
+                }
+            })
+        };
+
+        // 全部关闭：只调用一次，不占用限流额度
+        let call = registries.decorate(
+            failing(calls.clone()),
+            &ResilienceSpecification::new(),
+            &policy,
+        );
+        assert!(call.call().await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(registries.rate_limiter("global").unwrap().used(), 0);
+
+        // 全部开启：按 DEFAULT_RETRY 重试，每次尝试扣除权重
+        calls.store(0, Ordering::SeqCst);
+        let call = registries.decorate(
+            failing(calls.clone()),
+            &ResilienceSpecification::enabled(),
+            &policy,
+        );
+        assert!(call.call().await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(registries.rate_limiter("global").unwrap().used(), 30);
+    }
 
-        let result = ResilientCall::new(|| client.ping())
+    #[tokio::test]
+    async fn test_deadline_fails_fast_in_limiter() {
+        let limiter = Arc::new(RateLimiter::fixed_window(1, Duration::from_secs(3600)));
+        limiter.try_acquire(1).unwrap();
+
+        let started = Instant::now();
+        let error = ResilientCall::new(|| async { Ok(()) })
             .with_rate_limiter(limiter)
-            .with_retry(retry_cfg)
+            .with_timeout(Duration::from_secs(1))
             .call()
             .await
-            .unwrap();
+            .unwrap_err();
+        let error = error.downcast_ref::<TimeoutError>().unwrap();
+        assert!(error.is_deadline_exceeded());
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
 
-        assert_eq!(result, "pong");
+    #[tokio::test]
+    async fn test_attempt_timeout_retries_within_deadline() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let calls = Arc::new(AtomicU32::new(0));
+        let call = {
+            let calls = calls.clone();
+            ResilientCall::new(move || {
+                let calls = calls.clone();
+                async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    sleep(Duration::from_secs(1)).await;
+                    Ok(())
+                }
+            })
+        };
+
+        let error = call
+            .with_retry(Arc::new(RetryConfig::new(5, Duration::from_millis(1), 1.0)))
+            .with_attempt_timeout(Duration::from_millis(20))
+            .with_timeout(Duration::from_millis(50))
+            .call()
+            .await
+            .unwrap_err();
+
+        // 每次尝试超时后重试，直到截止时间
+        let error = error.downcast_ref::<TimeoutError>().unwrap();
+        assert!(error.is_deadline_exceeded());
+        let calls = calls.load(Ordering::SeqCst);
+        assert!((2..5).contains(&calls), "calls = {}", calls);
+    }
+
+    fn half_open_breaker() -> Arc<CircuitBreaker> {
+        let breaker = Arc::new(CircuitBreaker::new(
+            "test",
+            CircuitBreakerConfig {
+                sliding_window_size: 1,
+                minimum_number_of_calls: 1,
+                wait_duration_in_open_state: Duration::from_millis(10),
+                permitted_calls_in_half_open_state: 1,
+                ..Default::default()
+            },
+        ));
+        breaker.on_error(&HttpStatusError::new(503, "unavailable"));
+        std::thread::sleep(Duration::from_millis(15));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        breaker
+    }
+
+    #[tokio::test]
+    async fn test_local_deadline_not_recorded_by_breaker() {
+        let breaker = half_open_breaker();
+        let limiter = Arc::new(RateLimiter::fixed_window(1, Duration::from_secs(3600)));
+        limiter.try_acquire(1).unwrap();
+
+        // 限流等待超出截止时间，请求未发出，不影响熔断状态且归还试探名额
+        let error = ResilientCall::new(|| async { Ok(()) })
+            .with_circuit_breaker(breaker.clone())
+            .with_rate_limiter(limiter)
+            .with_timeout(Duration::from_millis(50))
+            .call()
+            .await
+            .unwrap_err();
+        assert!(error.downcast_ref::<TimeoutError>().is_some());
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_dropped_call_releases_half_open_permit() {
+        let breaker = half_open_breaker();
+        let call = ResilientCall::new(|| async {
+            sleep(Duration::from_secs(3600)).await;
+            Ok(())
+        })
+        .with_circuit_breaker(breaker.clone());
+
+        assert!(
+            tokio::time::timeout(Duration::from_millis(20), call.call())
+                .await
+                .is_err()
+        );
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deadline_expiry_not_recorded_by_breaker() {
+        let breaker = half_open_breaker();
+        let call = ResilientCall::new(|| async {
+            sleep(Duration::from_secs(3600)).await;
+            Ok(())
+        })
+        .with_circuit_breaker(breaker.clone())
+        .with_attempt_timeout(Duration::from_secs(1))
+        .with_timeout(Duration::from_millis(20));
+
+        let error = call.call().await.unwrap_err();
+        assert!(
+            error
+                .downcast_ref::<TimeoutError>()
+                .is_some_and(TimeoutError::is_deadline_exceeded)
+        );
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.try_acquire().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_deadline_exceeded_keeps_last_error() {
+        let call = ResilientCall::new(|| async {
+            Err::<(), _>(boxed(HttpStatusError::new(503, "unavailable")))
+        })
+        .with_retry(Arc::new(RetryConfig::new(
+            5,
+            Duration::from_millis(100),
+            1.0,
+        )))
+        .with_timeout(Duration::from_millis(50));
+
+        // 退避超出剩余时间，不再睡眠重试，返回最后一次失败
+        let started = Instant::now();
+        let error = call.call().await.unwrap_err();
+        assert!(started.elapsed() < Duration::from_millis(50));
+        match error.downcast_ref::<TimeoutError>() {
+            Some(TimeoutError::DeadlineExceeded {
+                last_error: Some(e),
+            }) => assert!(e.is::<HttpStatusError>()),
+            other => panic!("unexpected error: {:?}", other),
+        }
     }
 }
@@ -0,0 +1,466 @@
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::time::{sleep, timeout_at};
+
+/// 限流窗口语义
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// 令牌桶：按 capacity / period 的速率连续补充
+    TokenBucket,
+    /// 固定窗口：窗口按墙上时钟对齐（如整分钟），窗口结束时清零，与 Binance 的计数方式一致
+    FixedWindow,
+    /// 滑动窗口：任意 period 区间内的权重之和不超过 capacity
+    SlidingWindow,
+}
+
+#[derive(Debug)]
+pub enum RateLimiterError {
+    AcquireFailed,
+    /// 单次请求的权重超过了限流器容量，永远无法获取
+    WeightExceedsCapacity {
+        weight: u32,
+        capacity: u64,
+    },
+    /// `try_acquire` 当前无法获取，`wait` 为预计等待时间
+    WouldBlock {
+        wait: Duration,
+    },
+}
+
+impl fmt::Display for RateLimiterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimiterError::AcquireFailed => write!(f, "rate limiter acquire failed"),
+            RateLimiterError::WeightExceedsCapacity { weight, capacity } => write!(
+                f,
+                "request weight {} exceeds rate limiter capacity {}",
+                weight, capacity
+            ),
+            RateLimiterError::WouldBlock { wait } => {
+                write!(f, "rate limit reached, retry in {:?}", wait)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RateLimiterError {}
+
+/// 带权重的限流器。
+///
+/// 所有状态在一把锁内按需计算，不会为每个 permit 启动任务；
+/// 等待者通过公平的 tokio Mutex 排队，按先来后到获取。
+#[derive(Debug)]
+pub struct RateLimiter {
+    capacity: u64,
+    period: Duration,
+    mode: RateLimitMode,
+    state: Mutex<LimiterState>,
+    queue: tokio::sync::Mutex<()>,
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    /// TokenBucket：剩余令牌
+    tokens: f64,
+    last_refill: Instant,
+    /// FixedWindow：当前窗口起点和已用权重
+    window_start: Instant,
+    used: u64,
+    /// SlidingWindow：窗口内的 (时间, 权重)
+    history: VecDeque<(Instant, u32)>,
+    /// 服务端要求暂停（Retry-After）
+    paused_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// 滑动窗口限流：任意 `refill_period` 内最多 `capacity` 权重
+    pub fn new(capacity: usize, refill_period: Duration) -> Self {
+        Self::sliding_window(capacity as u64, refill_period)
+    }
+
+    pub fn sliding_window(capacity: u64, period: Duration) -> Self {
+        Self::with_mode(RateLimitMode::SlidingWindow, capacity, period)
+    }
+
+    pub fn fixed_window(capacity: u64, period: Duration) -> Self {
+        Self::with_mode(RateLimitMode::FixedWindow, capacity, period)
+    }
+
+    pub fn token_bucket(capacity: u64, period: Duration) -> Self {
+        Self::with_mode(RateLimitMode::TokenBucket, capacity, period)
+    }
+
+    pub fn with_mode(mode: RateLimitMode, capacity: u64, period: Duration) -> Self {
+        let now = Instant::now();
+        let period = period.max(Duration::from_millis(1));
+        Self {
+            capacity,
+            period,
+            mode,
+            state: Mutex::new(LimiterState {
+                tokens: capacity as f64,
+                last_refill: now,
+                window_start: aligned_window_start(now, period),
+                used: 0,
+                history: VecDeque::new(),
+                paused_until: None,
+            }),
+            queue: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// 当前可用权重
+    pub fn available(&self) -> u64 {
+        let mut state = self.state.lock();
+        self.advance(&mut state, Instant::now());
+        self.capacity.saturating_sub(self.used_in(&state))
+    }
+
+    /// 当前已用权重
+    pub fn used(&self) -> u64 {
+        let mut state = self.state.lock();
+        self.advance(&mut state, Instant::now());
+        self.used_in(&state)
+    }
+
+    /// 用服务端返回的已用权重校正本地状态。
+    ///
+    /// 多个进程共用同一 IP 时，服务端计数才是准确的：固定窗口直接采用服务端的值，
+    /// 令牌桶 / 滑动窗口只在服务端用量更高时补齐差额。
+    pub fn sync_used(&self, used: u64) {
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        self.advance(&mut state, now);
+
+        match self.mode {
+            RateLimitMode::TokenBucket => {
+                let remaining = self.capacity.saturating_sub(used) as f64;
+                state.tokens = state.tokens.min(remaining);
+            }
+            RateLimitMode::FixedWindow => state.used = used,
+            RateLimitMode::SlidingWindow => {
+                let local = self.used_in(&state);
+                if used > local {
+                    let missing = (used - local).min(u32::MAX as u64) as u32;
+                    state.history.push_back((now, missing));
+                }
+            }
+        }
+    }
+
+    /// 在 `duration` 内拒绝所有获取，例如服务端返回 Retry-After 时
+    pub fn pause_for(&self, duration: Duration) {
+        let until = Instant::now() + duration;
+        let mut state = self.state.lock();
+        state.paused_until = Some(state.paused_until.map_or(until, |p| p.max(until)));
+    }
+
+    /// 获取 `weight` 权重，额度不足时等待
+    pub async fn acquire(&self, weight: u32) -> Result<RateLimiterPermit, RateLimiterError> {
+        self.check_weight(weight)?;
+        let _turn = self.queue.lock().await;
+        loop {
+            match self.try_acquire(weight) {
+                Err(RateLimiterError::WouldBlock { wait }) => sleep(wait).await,
+                other => return other,
+            }
+        }
+    }
+
+    /// 在 `deadline` 前获取 `weight` 权重。
+    /// 预计等待会超过截止时间时立即返回 `WouldBlock`，不在队列中空等
+    pub async fn acquire_before(
+        &self,
+        weight: u32,
+        deadline: Instant,
+    ) -> Result<RateLimiterPermit, RateLimiterError> {
+        self.check_weight(weight)?;
+        let _turn = match timeout_at(deadline.into(), self.queue.lock()).await {
+            Ok(turn) => turn,
+            Err(_) => {
+                return Err(RateLimiterError::WouldBlock {
+                    wait: Duration::ZERO,
+                });
+            }
+        };
+        loop {
+            match self.try_acquire(weight) {
+                Err(RateLimiterError::WouldBlock { wait }) if Instant::now() + wait <= deadline => {
+                    sleep(wait).await
+                }
+                other => return other,
+            }
+        }
+    }
+
+    /// 立即尝试获取，额度不足时返回 `WouldBlock` 和预计等待时间
+    pub fn try_acquire(&self, weight: u32) -> Result<RateLimiterPermit, RateLimiterError> {
+        self.check_weight(weight)?;
+        let now = Instant::now();
+        let mut state = self.state.lock();
+        self.advance(&mut state, now);
+
+        match self.wait_time(&state, weight, now) {
+            Some(wait) => Err(RateLimiterError::WouldBlock { wait }),
+            None => {
+                match self.mode {
+                    RateLimitMode::TokenBucket => state.tokens -= weight as f64,
+                    RateLimitMode::FixedWindow => state.used += weight as u64,
+                    RateLimitMode::SlidingWindow => state.history.push_back((now, weight)),
+                }
+                Ok(RateLimiterPermit { weight })
+            }
+        }
+    }
+
+    fn check_weight(&self, weight: u32) -> Result<(), RateLimiterError> {
+        if weight as u64 > self.capacity {
+            return Err(RateLimiterError::WeightExceedsCapacity {
+                weight,
+                capacity: self.capacity,
+            });
+        }
+        Ok(())
+    }
+
+    /// 按当前时间补充令牌 / 切换窗口 / 淘汰过期记录
+    fn advance(&self, state: &mut LimiterState, now: Instant) {
+        match self.mode {
+            RateLimitMode::TokenBucket => {
+                let elapsed = now.saturating_duration_since(state.last_refill);
+                let refill =
+                    elapsed.as_secs_f64() * self.capacity as f64 / self.period.as_secs_f64();
+                state.tokens = (state.tokens + refill).min(self.capacity as f64);
+                state.last_refill = now;
+            }
+            RateLimitMode::FixedWindow => {
+                let elapsed = now.saturating_duration_since(state.window_start);
+                if elapsed >= self.period {
+                    // 在 u128 纳秒上计算当前窗口的起点，长时间空闲后窗口数不会溢出
+                    let offset = elapsed.as_nanos() % self.period.as_nanos();
+                    state.window_start = now - Duration::from_nanos(offset as u64);
+                    state.used = 0;
+                }
+            }
+            RateLimitMode::SlidingWindow => {
+                while let Some((at, _)) = state.history.front() {
+                    if now.saturating_duration_since(*at) >= self.period {
+                        state.history.pop_front();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn used_in(&self, state: &LimiterState) -> u64 {
+        match self.mode {
+            RateLimitMode::TokenBucket => {
+                (self.capacity as f64 - state.tokens).ceil().max(0.0) as u64
+            }
+            RateLimitMode::FixedWindow => state.used,
+            RateLimitMode::SlidingWindow => state.history.iter().map(|(_, w)| *w as u64).sum(),
+        }
+    }
+
+    /// 额度足够返回 None，否则返回预计等待时间
+    fn wait_time(&self, state: &LimiterState, weight: u32, now: Instant) -> Option<Duration> {
+        if let Some(until) = state.paused_until.filter(|until| *until > now) {
+            return Some(until - now);
+        }
+        let weight = weight as u64;
+        match self.mode {
+            RateLimitMode::TokenBucket => {
+                let missing = weight as f64 - state.tokens;
+                (missing > 0.0).then(|| {
+                    Duration::from_secs_f64(
+                        missing * self.period.as_secs_f64() / self.capacity as f64,
+                    )
+                })
+            }
+            RateLimitMode::FixedWindow => (state.used + weight > self.capacity)
+                .then(|| (state.window_start + self.period).saturating_duration_since(now)),
+            RateLimitMode::SlidingWindow => {
+                let used = self.used_in(state);
+                if used + weight <= self.capacity {
+                    return None;
+                }
+                // 找到最早的若干条记录，其过期后释放的权重足够本次请求
+                let mut freed = 0u64;
+                for (at, w) in &state.history {
+                    freed += *w as u64;
+                    if used - freed + weight <= self.capacity {
+                        return Some((*at + self.period).saturating_duration_since(now));
+                    }
+                }
+                Some(self.period)
+            }
+        }
+    }
+}
+
+/// 窗口起点对齐到墙上时钟，例如 1 分钟窗口从整分钟开始
+fn aligned_window_start(now: Instant, period: Duration) -> Instant {
+    let since_epoch = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let offset = since_epoch.as_nanos() % period.as_nanos();
+    now.checked_sub(Duration::from_nanos(offset as u64))
+        .unwrap_or(now)
+}
+
+/// 获取成功的凭证，额度由限流器按时间自行回收，无需持有
+#[derive(Debug)]
+pub struct RateLimiterPermit {
+    weight: u32,
+}
+
+impl RateLimiterPermit {
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_acquire() {
+        for limiter in [
+            RateLimiter::sliding_window(10, Duration::from_secs(60)),
+            RateLimiter::fixed_window(10, Duration::from_secs(3600)),
+            RateLimiter::token_bucket(10, Duration::from_secs(60)),
+        ] {
+            assert_eq!(limiter.try_acquire(5).unwrap().weight(), 5);
+            assert!(limiter.try_acquire(4).is_ok());
+            assert!(matches!(
+                limiter.try_acquire(2),
+                Err(RateLimiterError::WouldBlock { .. })
+            ));
+            assert!(limiter.try_acquire(1).is_ok());
+            assert_eq!(limiter.available(), 0);
+        }
+    }
+
+    #[test]
+    fn test_weight_exceeds_capacity() {
+        let limiter = RateLimiter::sliding_window(10, Duration::from_secs(1));
+        assert!(matches!(
+            limiter.try_acquire(11),
+            Err(RateLimiterError::WeightExceedsCapacity {
+                weight: 11,
+                capacity: 10
+            })
+        ));
+    }
+
+    #[test]
+    fn test_estimated_wait() {
+        let limiter = RateLimiter::token_bucket(10, Duration::from_secs(10));
+        limiter.try_acquire(10).unwrap();
+        // 每秒补充 1 个令牌，3 个约需 3 秒
+        match limiter.try_acquire(3) {
+            Err(RateLimiterError::WouldBlock { wait }) => {
+                assert!(wait > Duration::from_millis(2900) && wait <= Duration::from_secs(3))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let limiter = RateLimiter::sliding_window(10, Duration::from_secs(60));
+        limiter.try_acquire(10).unwrap();
+        match limiter.try_acquire(1) {
+            Err(RateLimiterError::WouldBlock { wait }) => {
+                assert!(wait > Duration::from_secs(59) && wait <= Duration::from_secs(60))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_sync_used() {
+        let limiter = RateLimiter::fixed_window(100, Duration::from_secs(3600));
+        limiter.try_acquire(10).unwrap();
+        limiter.sync_used(95);
+        assert_eq!(limiter.used(), 95);
+        assert!(limiter.try_acquire(6).is_err());
+        // 固定窗口以服务端为准，允许向下校正
+        limiter.sync_used(20);
+        assert_eq!(limiter.available(), 80);
+
+        let limiter = RateLimiter::sliding_window(100, Duration::from_secs(60));
+        limiter.try_acquire(10).unwrap();
+        limiter.sync_used(5);
+        assert_eq!(limiter.used(), 10);
+        limiter.sync_used(40);
+        assert_eq!(limiter.used(), 40);
+    }
+
+    #[test]
+    fn test_pause() {
+        let limiter = RateLimiter::sliding_window(10, Duration::from_secs(60));
+        limiter.pause_for(Duration::from_secs(30));
+        match limiter.try_acquire(1) {
+            Err(RateLimiterError::WouldBlock { wait }) => {
+                assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_windows_expire() {
+        let limiter = RateLimiter::sliding_window(2, Duration::from_millis(20));
+        limiter.try_acquire(2).unwrap();
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(limiter.try_acquire(2).is_ok());
+
+        let limiter = RateLimiter::fixed_window(2, Duration::from_millis(20));
+        limiter.try_acquire(2).unwrap();
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(limiter.try_acquire(2).is_ok());
+    }
+
+    #[test]
+    fn test_fixed_window_start_after_long_idle() {
+        // 空闲的窗口数超过 u32::MAX
+        let limiter = RateLimiter::fixed_window(2, Duration::from_millis(1));
+        let mut state = limiter.state.lock();
+        let now = state.window_start
+            + Duration::from_millis(u32::MAX as u64 + 2)
+            + Duration::from_nanos(1);
+
+        limiter.advance(&mut state, now);
+        assert_eq!(state.window_start, now - Duration::from_nanos(1));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_before_fails_fast() {
+        let limiter = RateLimiter::fixed_window(2, Duration::from_secs(3600));
+        limiter.try_acquire(2).unwrap();
+
+        let started = Instant::now();
+        let deadline = started + Duration::from_secs(1);
+        assert!(matches!(
+            limiter.acquire_before(1, deadline).await,
+            Err(RateLimiterError::WouldBlock { .. })
+        ));
+        // 额度要到窗口结束才恢复，不等待截止时间
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,387 @@
+use crate::client::{RateLimiterError, TimeoutError};
+use crate::error::ErrorKind;
+use crate::error::exchange_error::{
+    ExchangeApiError, ExchangeError, ExchangeUnavailableError, RateLimitExceededError,
+};
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 一次失败后的重试决策
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// 按退避时间重试
+    Retry,
+    /// 至少等待指定时间后重试（Retry-After、限流器的预计等待时间）
+    RetryAfter(Duration),
+    /// 不可重试，直接返回错误
+    Abort,
+}
+
+pub type RetryPredicate =
+    Arc<dyn Fn(&(dyn Error + Send + Sync + 'static)) -> RetryDecision + Send + Sync>;
+
+/// 每次重试前触发，可用于日志 / 监控
+pub type RetryHook = Arc<dyn Fn(&RetryEvent<'_>) + Send + Sync>;
+
+pub struct RetryEvent<'a> {
+    /// 已失败的次数，从 1 开始
+    pub attempt: usize,
+    pub delay: Duration,
+    pub error: &'a (dyn Error + Send + Sync + 'static),
+}
+
+/// HTTP 层返回的非 2xx 状态，429 / 418 时携带 Retry-After
+#[derive(Debug, Clone)]
+pub struct HttpStatusError {
+    pub status: u16,
+    pub retry_after: Option<Duration>,
+    pub message: String,
+}
+
+impl HttpStatusError {
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            retry_after: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn with_retry_after(mut self, retry_after: Option<Duration>) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    /// 解析 Retry-After 的秒数形式
+    pub fn parse_retry_after(value: &str) -> Option<Duration> {
+        value.trim().parse::<u64>().ok().map(Duration::from_secs)
+    }
+
+    /// 429 Too Many Requests / 418 IP 被封禁
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self.status, 429 | 418)
+    }
+}
+
+impl fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "HTTP {}: {}", self.status, self.message)
+    }
+}
+
+impl Error for HttpStatusError {}
+
+/// ========================
+/// Retry Config
+/// ========================
+pub struct RetryConfig {
+    pub max_attempts: usize,
+    pub initial_delay: Duration,
+    pub multiplier: f64,
+    /// 单次退避的上限
+    pub max_delay: Duration,
+    /// 抖动比例 [0, 1]，实际延迟在 delay * (1 ± jitter) 之间
+    pub jitter: f64,
+    pub predicate: RetryPredicate,
+    pub on_retry: Option<RetryHook>,
+}
+
+impl fmt::Debug for RetryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryConfig")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("multiplier", &self.multiplier)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RetryConfig {
+    /// 默认只重试瞬时错误，见 [`RetryConfig::transient_errors`]
+    pub fn new(max_attempts: usize, initial_delay: Duration, multiplier: f64) -> Self {
+        Self {
+            max_attempts,
+            initial_delay,
+            multiplier,
+            max_delay: Duration::from_secs(30),
+            jitter: 0.2,
+            predicate: Arc::new(Self::transient_errors),
+            on_retry: None,
+        }
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&(dyn Error + Send + Sync + 'static)) -> RetryDecision + Send + Sync + 'static,
+    {
+        self.predicate = Arc::new(predicate);
+        self
+    }
+
+    pub fn on_retry<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RetryEvent<'_>) + Send + Sync + 'static,
+    {
+        self.on_retry = Some(Arc::new(hook));
+        self
+    }
+
+    /// 不含抖动的指数退避，受 `max_delay` 限制
+    pub fn delay_for_attempt(&self, attempt: usize) -> Duration {
+        let factor = self.multiplier.powi(attempt as i32);
+        let delay = self.initial_delay.as_secs_f64() * factor;
+        if !delay.is_finite() || delay >= self.max_delay.as_secs_f64() {
+            return self.max_delay;
+        }
+        Duration::from_secs_f64(delay)
+    }
+
+    /// 第 `attempt` 次失败后的等待时间；`RetryAfter` 给出的时间是下限，不加抖动
+    pub fn backoff(&self, attempt: usize, decision: RetryDecision) -> Duration {
+        let delay = self.delay_for_attempt(attempt);
+        let delay = if self.jitter > 0.0 {
+            let factor = 1.0 + self.jitter * (2.0 * random_unit() - 1.0);
+            delay.mul_f64(factor).min(self.max_delay)
+        } else {
+            delay
+        };
+        match decision {
+            RetryDecision::RetryAfter(wait) => delay.max(wait),
+            _ => delay,
+        }
+    }
+
+    pub fn decide(&self, error: &(dyn Error + Send + Sync + 'static)) -> RetryDecision {
+        (self.predicate)(error)
+    }
+
+    /// 瞬时错误：超时、连接中断、5xx、`ExchangeUnavailableError`、
+    /// 限流（429 / 418 / `RateLimitExceededError` / 本地限流器）等待后重试。
+    /// `ExchangeError` 按 `ErrorKind::is_retryable` 判断
+    pub fn transient_errors(error: &(dyn Error + Send + Sync + 'static)) -> RetryDecision {
+        classify(error, |e| {
+            if let Some(e) = e.downcast_ref::<HttpStatusError>() {
+                return Some(match e.status {
+                    429 | 418 => e
+                        .retry_after
+                        .map(RetryDecision::RetryAfter)
+                        .unwrap_or(RetryDecision::Retry),
+                    408 | 500..=599 => RetryDecision::Retry,
+                    _ => RetryDecision::Abort,
+                });
+            }
+            if let Some(e) = e.downcast_ref::<io::Error>() {
+                return Some(match e.kind() {
+                    io::ErrorKind::TimedOut
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::UnexpectedEof
+                    | io::ErrorKind::Interrupted => RetryDecision::Retry,
+                    _ => RetryDecision::Abort,
+                });
+            }
+            if e.is::<tokio::time::error::Elapsed>() || e.is::<TimeoutError>() {
+                return Some(RetryDecision::Retry);
+            }
+            if let Some(e) = e.downcast_ref::<ExchangeError>()
+                && e.is_retryable()
+            {
+                return Some(retry_after(e.retry_after()));
+            }
+            if let Some(e) = e.downcast_ref::<ExchangeApiError>()
+                && e.is_retryable()
+            {
+                return Some(retry_after(e.retry_after));
+            }
+            if e.is::<ExchangeUnavailableError>() || e.is::<RateLimitExceededError>() {
+                return Some(RetryDecision::Retry);
+            }
+            None
+        })
+    }
+
+    /// 只重试确定没有到达交易所的失败，用于下单等非幂等请求：
+    /// 本地限流、429 / 418、连接被拒绝。超时和 5xx 时订单状态未知，不能重试。
+    pub fn not_sent_errors(error: &(dyn Error + Send + Sync + 'static)) -> RetryDecision {
+        classify(error, |e| {
+            if let Some(e) = e.downcast_ref::<HttpStatusError>() {
+                return Some(if e.is_rate_limited() {
+                    e.retry_after
+                        .map(RetryDecision::RetryAfter)
+                        .unwrap_or(RetryDecision::Retry)
+                } else {
+                    RetryDecision::Abort
+                });
+            }
+            if let Some(e) = e.downcast_ref::<io::Error>() {
+                return Some(match e.kind() {
+                    io::ErrorKind::ConnectionRefused => RetryDecision::Retry,
+                    _ => RetryDecision::Abort,
+                });
+            }
+            if let Some(e) = e.downcast_ref::<ExchangeError>()
+                && e.kind() == ErrorKind::RateLimit
+            {
+                return Some(retry_after(e.retry_after()));
+            }
+            if let Some(e) = e.downcast_ref::<ExchangeApiError>()
+                && e.kind == ErrorKind::RateLimit
+            {
+                return Some(retry_after(e.retry_after));
+            }
+            None
+        })
+    }
+}
+
+fn retry_after(wait: Option<Duration>) -> RetryDecision {
+    wait.map(RetryDecision::RetryAfter)
+        .unwrap_or(RetryDecision::Retry)
+}
+
+/// 沿 source 链查找第一个可判断的错误；本地限流器的错误总是优先处理，截止时间已到时不再重试
+fn classify<F>(error: &(dyn Error + Send + Sync + 'static), f: F) -> RetryDecision
+where
+    F: Fn(&(dyn Error + 'static)) -> Option<RetryDecision>,
+{
+    let mut current: Option<&(dyn Error + 'static)> = Some(error);
+    while let Some(e) = current {
+        if let Some(e) = e.downcast_ref::<RateLimiterError>() {
+            return match e {
+                RateLimiterError::WouldBlock { wait } => RetryDecision::RetryAfter(*wait),
+                _ => RetryDecision::Abort,
+            };
+        }
+        if e.downcast_ref::<TimeoutError>()
+            .is_some_and(TimeoutError::is_deadline_exceeded)
+        {
+            return RetryDecision::Abort;
+        }
+        if let Some(decision) = f(e) {
+            return decision;
+        }
+        current = e.source();
+    }
+    RetryDecision::Abort
+}
+
+/// [0, 1) 之间的随机数，避免为抖动引入 rand 依赖
+fn random_unit() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::exchange_error::OrderNotValidError;
+
+    fn decide(config: &RetryConfig, error: impl Error + Send + Sync + 'static) -> RetryDecision {
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(error);
+        config.decide(boxed.as_ref())
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        let config = RetryConfig::new(3, Duration::from_millis(10), 2.0);
+        assert_eq!(
+            decide(&config, HttpStatusError::new(503, "unavailable")),
+            RetryDecision::Retry
+        );
+        assert_eq!(
+            decide(
+                &config,
+                HttpStatusError::new(429, "too many requests")
+                    .with_retry_after(Some(Duration::from_secs(7)))
+            ),
+            RetryDecision::RetryAfter(Duration::from_secs(7))
+        );
+        assert_eq!(
+            decide(&config, HttpStatusError::new(400, "bad request")),
+            RetryDecision::Abort
+        );
+        assert_eq!(
+            decide(
+                &config,
+                ExchangeError::from(ExchangeUnavailableError::with_message("down"))
+            ),
+            RetryDecision::Retry
+        );
+        assert_eq!(
+            decide(
+                &config,
+                ExchangeError::from(OrderNotValidError::with_message("rejected"))
+            ),
+            RetryDecision::Abort
+        );
+        assert_eq!(
+            decide(&config, io::Error::from(io::ErrorKind::TimedOut)),
+            RetryDecision::Retry
+        );
+    }
+
+    #[test]
+    fn test_not_sent_errors() {
+        let config = RetryConfig::new(3, Duration::from_millis(10), 2.0)
+            .with_predicate(RetryConfig::not_sent_errors);
+        assert_eq!(
+            decide(&config, io::Error::from(io::ErrorKind::TimedOut)),
+            RetryDecision::Abort
+        );
+        assert_eq!(
+            decide(&config, HttpStatusError::new(502, "bad gateway")),
+            RetryDecision::Abort
+        );
+        assert_eq!(
+            decide(
+                &config,
+                RateLimiterError::WouldBlock {
+                    wait: Duration::from_millis(5)
+                }
+            ),
+            RetryDecision::RetryAfter(Duration::from_millis(5))
+        );
+    }
+
+    #[test]
+    fn test_backoff_jitter_and_max_delay() {
+        let config = RetryConfig::new(10, Duration::from_millis(100), 2.0)
+            .with_max_delay(Duration::from_secs(1))
+            .with_jitter(0.5);
+        for attempt in 1..10 {
+            let base = config.delay_for_attempt(attempt);
+            let delay = config.backoff(attempt, RetryDecision::Retry);
+            assert!(delay >= base.mul_f64(0.5) && delay <= Duration::from_secs(1));
+        }
+        assert_eq!(config.delay_for_attempt(9), Duration::from_secs(1));
+        assert!(
+            config.backoff(1, RetryDecision::RetryAfter(Duration::from_secs(5)))
+                >= Duration::from_secs(5)
+        );
+    }
+}
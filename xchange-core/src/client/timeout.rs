@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+/// 调用超时
+#[derive(Debug)]
+pub enum TimeoutError {
+    /// 单次尝试超时，请求可能已到达交易所
+    Attempt { timeout: Duration },
+    /// 整体截止时间已到或无法在截止前完成（重试退避、限流 / 并发隔离等待），
+    /// `last_error` 为截止前最后一次失败
+    DeadlineExceeded {
+        last_error: Option<Box<dyn Error + Send + Sync>>,
+    },
+}
+
+impl TimeoutError {
+    pub fn deadline_exceeded() -> Self {
+        TimeoutError::DeadlineExceeded { last_error: None }
+    }
+
+    pub fn is_deadline_exceeded(&self) -> bool {
+        matches!(self, TimeoutError::DeadlineExceeded { .. })
+    }
+}
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeoutError::Attempt { timeout } => write!(f, "attempt timed out after {:?}", timeout),
+            TimeoutError::DeadlineExceeded { last_error: None } => write!(f, "deadline exceeded"),
+            TimeoutError::DeadlineExceeded {
+                last_error: Some(e),
+            } => write!(f, "deadline exceeded, last error: {}", e),
+        }
+    }
+}
+
+impl Error for TimeoutError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TimeoutError::DeadlineExceeded {
+                last_error: Some(e),
+            } => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
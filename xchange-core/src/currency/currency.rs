@@ -178,6 +178,18 @@ impl std::fmt::Display for Currency {
     }
 }
 
+impl std::str::FromStr for Currency {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        let code = code.trim();
+        if code.is_empty() {
+            return Err("Empty currency code".to_string());
+        }
+        Ok(Currency::new(code))
+    }
+}
+
 impl Currency {
     ///  Public constructor. Links to an existing currency.
     pub fn new(code: &str) -> Self {
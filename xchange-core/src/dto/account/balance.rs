@@ -9,12 +9,12 @@ use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
 
 ///
-///  DTO representing a balance in a currency
+/// DTO representing a balance in a currency
 ///
-///   <p>This is simply defined by an amount of money in a given currency, contained in the cash
-///   object.
+/// <p>This is simply defined by an amount of money in a given currency, contained in the cash
+/// object.
 ///
-///   <p>This class is immutable.
+/// <p>This class is immutable.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Balance {
     pub currency: Currency,
@@ -32,11 +32,11 @@ pub struct Balance {
 }
 
 impl Balance {
-    ///  Constructs a balance, the {@link #available} will be the same as the <code>total</code>, and
-    ///   the {@link #frozen} is zero. The <code>borrowed</code> and <code>loaned</code> will be zero.
+    /// Constructs a balance, the {@link #available} will be the same as the <code>total</code>, and
+    /// the {@link #frozen} is zero. The <code>borrowed</code> and <code>loaned</code> will be zero.
     ///
-    ///   @param currency The underlying currency
-    ///   @param total The total
+    /// @param currency The underlying currency
+    /// @param total The total
     pub fn new(currency: Currency, total: Decimal) -> Self {
         Balance {
             currency,
@@ -52,13 +52,13 @@ impl Balance {
     }
 
     ///
-    ///     Constructs a balance, the {@link #frozen} will be assigned as <code>total</code> - <code>
-    ///     available</code>. The <code>borrowed</code> and <code>loaned</code> will be zero.
+    /// Constructs a balance, the {@link #frozen} will be assigned as <code>total</code> - <code>
+    /// available</code>. The <code>borrowed</code> and <code>loaned</code> will be zero.
     ///
-    ///     @param currency the underlying currency of this balance.
-    ///     @param total the total amount of the <code>currency</code> in this balance.
-    ///     @param available the amount of the <code>currency</code> in this balance that is available to
-    ///         trade.
+    /// @param currency the underlying currency of this balance.
+    /// @param total the total amount of the <code>currency</code> in this balance.
+    /// @param available the amount of the <code>currency</code> in this balance that is available to
+    /// trade.
     ///
     pub fn new_with_available(currency: Currency, total: Decimal, available: Decimal) -> Self {
         Balance {
@@ -74,15 +74,15 @@ impl Balance {
         }
     }
 
-    ///     Constructs a balance. The <code>borrowed</code> and <code>loaned</code> will be zero.
+    /// Constructs a balance. The <code>borrowed</code> and <code>loaned</code> will be zero.
     ///
-    ///     @param currency the underlying currency of this balance.
-    ///     @param total the total amount of the <code>currency</code> in this balance, including the
-    ///         <code>available</code> and <code>frozen</code>.
-    ///     @param available the amount of the <code>currency</code> in this balance that is available to
-    ///         trade.
-    ///     @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
-    ///         trading.
+    /// @param currency the underlying currency of this balance.
+    /// @param total the total amount of the <code>currency</code> in this balance, including the
+    /// <code>available</code> and <code>frozen</code>.
+    /// @param available the amount of the <code>currency</code> in this balance that is available to
+    /// trade.
+    /// @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
+    /// trading.
     pub fn new_with_frozen(
         currency: Currency,
         total: Decimal,
@@ -104,22 +104,22 @@ impl Balance {
 
     /// Constructs a balance.
     ///
-    ///     @param currency the underlying currency of this balance.
-    ///     @param total the total amount of the <code>currency</code> in this balance, equal to <code>
-    ///         available + frozen - borrowed + loaned</code>.
-    ///     @param available the amount of the <code>currency</code> in this balance that is available to
-    ///         trade, including the <code>borrowed</code>.
-    ///     @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
-    ///         trading.
-    ///     @param borrowed the borrowed amount of the available <code>currency</code> in this balance that
-    ///         must be repaid.
-    ///     @param loaned the loaned amount of the total <code>currency</code> in this balance that will be
-    ///         returned.
-    ///     @param withdrawing the amount of the <code>currency</code> in this balance that is scheduled
-    ///        for withdrawal.
-    ///     @param depositing the amount of the <code>currency</code> in this balance that is being
-    ///         deposited but not available yet.
-    ///     @param timestamp Time the balance was valid on the exchange server
+    /// @param currency the underlying currency of this balance.
+    /// @param total the total amount of the <code>currency</code> in this balance, equal to <code>
+    /// available + frozen - borrowed + loaned</code>.
+    /// @param available the amount of the <code>currency</code> in this balance that is available to
+    /// trade, including the <code>borrowed</code>.
+    /// @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
+    /// trading.
+    /// @param borrowed the borrowed amount of the available <code>currency</code> in this balance that
+    /// must be repaid.
+    /// @param loaned the loaned amount of the total <code>currency</code> in this balance that will be
+    /// returned.
+    /// @param withdrawing the amount of the <code>currency</code> in this balance that is scheduled
+    /// for withdrawal.
+    /// @param depositing the amount of the <code>currency</code> in this balance that is being
+    /// deposited but not available yet.
+    /// @param timestamp Time the balance was valid on the exchange server
     pub fn new_full(
         currency: Currency,
         total: Decimal,
@@ -154,23 +154,23 @@ impl Balance {
         }
     }
 
-    ///     Constructs a balance.
+    /// Constructs a balance.
     ///
-    ///     @param currency the underlying currency of this balance.
-    ///     @param total the total amount of the <code>currency</code> in this balance, equal to <code>
-    ///         available + frozen - borrowed + loaned</code>.
-    ///     @param available the amount of the <code>currency</code> in this balance that is available to
-    ///         trade, including the <code>borrowed</code>.
-    ///     @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
-    ///         trading.
-    ///     @param borrowed the borrowed amount of the available <code>currency</code> in this balance that
-    ///         must be repaid.
-    ///     @param loaned the loaned amount of the total <code>currency</code> in this balance that will be
-    ///         returned.
-    ///     @param withdrawing the amount of the <code>currency</code> in this balance that is scheduled
-    ///         for withdrawal.
-    ///     @param depositing the amount of the <code>currency</code> in this balance that is being
-    ///         deposited but not available yet.
+    /// @param currency the underlying currency of this balance.
+    /// @param total the total amount of the <code>currency</code> in this balance, equal to <code>
+    /// available + frozen - borrowed + loaned</code>.
+    /// @param available the amount of the <code>currency</code> in this balance that is available to
+    /// trade, including the <code>borrowed</code>.
+    /// @param frozen the frozen amount of the <code>currency</code> in this balance that is locked in
+    /// trading.
+    /// @param borrowed the borrowed amount of the available <code>currency</code> in this balance that
+    /// must be repaid.
+    /// @param loaned the loaned amount of the total <code>currency</code> in this balance that will be
+    /// returned.
+    /// @param withdrawing the amount of the <code>currency</code> in this balance that is scheduled
+    /// for withdrawal.
+    /// @param depositing the amount of the <code>currency</code> in this balance that is being
+    /// deposited but not available yet.
     pub fn new_no_timestamp(
         currency: Currency,
         total: Decimal,
@@ -204,10 +204,10 @@ impl Balance {
         }
     }
 
-    ///  Returns a zero balance.
+    /// Returns a zero balance.
     ///
-    ///  @param currency the balance currency.
-    ///  @return a zero balance.
+    /// @param currency the balance currency.
+    /// @return a zero balance.
     pub fn zero(currency: Currency) -> Self {
         Balance {
             currency,
@@ -226,9 +226,9 @@ impl Balance {
         &self.currency
     }
 
-    ///  Returns the total amount of the <code>currency</code> in this balance.
+    /// Returns the total amount of the <code>currency</code> in this balance.
     ///
-    ///  @return the total amount.
+    /// @return the total amount.
     pub fn total(&self) -> Decimal {
         let available = self.available.unwrap_or(Decimal::ZERO);
         let frozen = self.frozen.unwrap_or(Decimal::ZERO);
@@ -239,7 +239,7 @@ impl Balance {
 
     /// Returns the amount of the <code>currency</code> in this balance that is available to trade.
     ///
-    ///  @return the amount that is available to trade.
+    /// @return the amount that is available to trade.
     pub fn available(&self) -> Decimal {
         let frozen = self.frozen.unwrap_or(Decimal::ZERO);
         match self.available {
@@ -253,17 +253,17 @@ impl Balance {
     }
 
     /// Returns the amount of the <code>currency</code> in this balance that may be withdrawn. Equal to
-    ///    <code>available - borrowed</code>.
+    /// <code>available - borrowed</code>.
     ///
-    ///    @return the amount that is available to withdraw.
+    /// @return the amount that is available to withdraw.
     pub fn available_for_withdrawal(&self) -> Decimal {
         self.available() - self.borrowed()
     }
 
     /// Returns the frozen amount of the <code>currency</code> in this balance that is locked in
-    ///    trading.
+    /// trading.
     ///
-    ///    @return the amount that is locked in open orders.
+    /// @return the amount that is locked in open orders.
     pub fn frozen(&self) -> Decimal {
         match self.frozen {
             Some(f) => f,
@@ -272,38 +272,38 @@ impl Balance {
     }
 
     /// Returns the borrowed amount of the available <code>currency</code> in this balance that must be
-    ///    repaid.
+    /// repaid.
     ///
-    ///    @return the amount that must be repaid.
+    /// @return the amount that must be repaid.
     pub fn borrowed(&self) -> Decimal {
         self.borrowed
     }
 
     /// Returns the loaned amount of the total <code>currency</code> in this balance that will be
-    ///     returned.
+    /// returned.
     ///
-    ///     @return that amount that is loaned out.
+    /// @return that amount that is loaned out.
     pub fn loaned(&self) -> Decimal {
         self.loaned
     }
 
     /// Returns the amount of the <code>currency</code> in this balance that is locked in withdrawal
     ///
-    ///   @return the amount in withdrawal.
+    /// @return the amount in withdrawal.
     pub fn withdrawing(&self) -> Decimal {
         self.withdrawing
     }
 
     /// Returns the amount of the <code>currency</code> in this balance that is locked in deposit
     ///
-    ///    @return the amount in deposit.
+    /// @return the amount in deposit.
     pub fn depositing(&self) -> Decimal {
         self.depositing
     }
 
     /// Returns the time the balance was valid on the exchange server
     ///
-    ///    @return the timestamp.
+    /// @return the timestamp.
     pub fn timestamp(&self) -> Option<DateTime<Utc>> {
         self.timestamp
     }
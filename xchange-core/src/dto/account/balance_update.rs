@@ -0,0 +1,58 @@
+use crate::currency::currency::Currency;
+use crate::dto::account::balance::Balance;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+///  DTO representing a pushed change of a balance in a wallet
+///
+///   <p>Exchanges either push the latest balance, the change amount, or both. Fields the exchange
+///   did not send are `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BalanceUpdate {
+    /// Id of the wallet the balance belongs to, see `Wallet::id`
+    pub wallet_id: String,
+
+    pub currency: Currency,
+
+    /// The balance after the change
+    pub balance: Option<Balance>,
+
+    /// The amount the balance changed by (negative for a decrease)
+    pub delta: Option<Decimal>,
+
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl BalanceUpdate {
+    /// Update carrying the latest balance
+    pub fn with_balance(
+        wallet_id: impl Into<String>,
+        balance: Balance,
+        timestamp: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            wallet_id: wallet_id.into(),
+            currency: balance.currency.clone(),
+            balance: Some(balance),
+            delta: None,
+            timestamp,
+        }
+    }
+
+    /// Update carrying only the change amount
+    pub fn with_delta(
+        wallet_id: impl Into<String>,
+        currency: Currency,
+        delta: Decimal,
+        timestamp: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            wallet_id: wallet_id.into(),
+            currency,
+            balance: None,
+            delta: Some(delta),
+            timestamp,
+        }
+    }
+}
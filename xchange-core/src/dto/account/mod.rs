@@ -7,6 +7,7 @@ use std::str::FromStr;
 pub mod account_info;
 pub mod address_with_tag;
 pub mod balance;
+pub mod balance_update;
 pub mod fee;
 pub mod funding_record;
 pub mod open_position;
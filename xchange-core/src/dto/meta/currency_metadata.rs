@@ -22,7 +22,7 @@ pub struct CurrencyMetaData {
     pub min_withdrawal_amount: Option<Decimal>,
 
     /// Wallet health status.
-    #[serde(rename = "wallet_health")]
+    #[serde(rename = "wallet_health", default)]
     pub wallet_health: WalletHealth,
 }
 
@@ -64,6 +64,24 @@ impl CurrencyMetaData {
     }
 }
 
+impl CurrencyMetaData {
+    /// Overwrite the fields set in `other`, keep the rest
+    pub fn merge(&mut self, other: &CurrencyMetaData) {
+        if other.scale.is_some() {
+            self.scale = other.scale;
+        }
+        if other.withdrawal_fee.is_some() {
+            self.withdrawal_fee = other.withdrawal_fee;
+        }
+        if other.min_withdrawal_amount.is_some() {
+            self.min_withdrawal_amount = other.min_withdrawal_amount;
+        }
+        if other.wallet_health != WalletHealth::Unknown {
+            self.wallet_health = other.wallet_health.clone();
+        }
+    }
+}
+
 impl fmt::Display for CurrencyMetaData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
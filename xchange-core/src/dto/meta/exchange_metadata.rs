@@ -2,21 +2,24 @@ use crate::currency::currency::Currency;
 use crate::dto::meta::currency_metadata::CurrencyMetaData;
 use crate::dto::meta::instrument_metadata::InstrumentMetaData;
 use crate::dto::meta::rate_limit::RateLimit;
+use crate::error::exchange_error::ExchangeError;
 use crate::instrument::InstrumentDTO;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 /// Exchange metadata containing instruments, currencies and rate limits.
 ///
 /// This is loaded at startup and merges local JSON metadata + online exchange info.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ExchangeMetaData {
-    /// Map of InstrumentDTO -> InstrumentMetaData
-    #[serde(rename = "currency_pairs")]
+    /// Map of InstrumentDTO -> InstrumentMetaData, keyed by symbol (e.g. "BTC/USDT") in JSON
+    #[serde(rename = "currency_pairs", default, with = "symbol_keyed")]
     pub instruments: HashMap<InstrumentDTO, InstrumentMetaData>,
     // pub instruments: HashMap<Arc<dyn Instrument + Send + Sync>, Value>,
-    /// Map of Currency -> CurrencyMetaData
-    #[serde(rename = "currencies")]
+    /// Map of Currency -> CurrencyMetaData, keyed by currency code in JSON
+    #[serde(rename = "currencies", default, with = "symbol_keyed")]
     pub currencies: HashMap<Currency, CurrencyMetaData>,
 
     /// Public API rate limits
@@ -44,4 +47,202 @@ impl ExchangeMetaData {
     pub fn to_json_string(&self) -> String {
         serde_json::to_string(self).expect("Serialize ExchangeMetaData")
     }
+
+    /// Parse meta data JSON, compatible with the Java XChange meta data files
+    pub fn from_json_str(json: &str) -> Result<Self, ExchangeError> {
+        serde_json::from_str(json)
+            .map_err(|e| ExchangeError::Message(format!("Invalid meta data JSON: {}", e)))
+    }
+
+    /// Load meta data from a JSON file
+    pub fn load_json_file(path: impl AsRef<Path>) -> Result<Self, ExchangeError> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path).map_err(|e| {
+            ExchangeError::Message(format!("Read meta data {} failed: {}", path.display(), e))
+        })?;
+        Self::from_json_str(&json)
+    }
+
+    /// Write a pretty printed snapshot, replacing the target file atomically
+    pub fn write_json_file(&self, path: impl AsRef<Path>) -> Result<(), ExchangeError> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| ExchangeError::Message(format!("Serialize meta data failed: {}", e)))?;
+
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            let tmp = path.with_extension("json.tmp");
+            fs::write(&tmp, json)?;
+            fs::rename(&tmp, path)
+        };
+        write().map_err(|e| {
+            ExchangeError::Message(format!("Write meta data {} failed: {}", path.display(), e))
+        })
+    }
+
+    /// Merge `overrides` into `self`.
+    ///
+    /// Values present in `overrides` win, values it does not set are kept. Rate limits are
+    /// replaced only when `overrides` defines any.
+    pub fn merge(&mut self, overrides: &ExchangeMetaData) {
+        for (instrument, meta) in &overrides.instruments {
+            match self.instruments.get_mut(instrument) {
+                Some(existing) => existing.merge(meta),
+                None => {
+                    self.instruments.insert(instrument.clone(), meta.clone());
+                }
+            }
+        }
+
+        for (currency, meta) in &overrides.currencies {
+            match self.currencies.get_mut(currency) {
+                Some(existing) => existing.merge(meta),
+                None => {
+                    self.currencies.insert(currency.clone(), meta.clone());
+                }
+            }
+        }
+
+        if !overrides.public_rate_limits.is_empty() {
+            self.public_rate_limits = overrides.public_rate_limits.clone();
+        }
+        if !overrides.private_rate_limits.is_empty() {
+            self.private_rate_limits = overrides.private_rate_limits.clone();
+        }
+        self.share_rate_limits |= overrides.share_rate_limits;
+    }
+}
+
+/// serde_json 只支持字符串 map key：按 Display 写出（排序保证快照稳定），按 FromStr 读入
+mod symbol_keyed {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::collections::{BTreeMap, HashMap};
+    use std::fmt::Display;
+    use std::hash::Hash;
+    use std::str::FromStr;
+
+    pub fn serialize<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Display,
+        V: Serialize,
+        S: Serializer,
+    {
+        map.iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: FromStr + Eq + Hash,
+        K::Err: Display,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        HashMap::<String, V>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(key, value)| {
+                key.parse::<K>()
+                    .map(|key| (key, value))
+                    .map_err(|e| D::Error::custom(format!("invalid key {}: {}", key, e)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    const JAVA_META_DATA: &str = r#"{
+      "currency_pairs": {
+        "BTC/USDT": { "trading_fee": 0.001, "min_amount": 0.00001, "price_scale": 2, "trading_fee_currency": "USDT" },
+        "BTC/USDT/PERP": { "price_scale": 1, "market_order_enabled": true }
+      },
+      "currencies": {
+        "BTC": { "scale": 8, "withdrawal_fee": 0.0005, "wallet_health": "DEPOSITS_DISABLED" },
+        "USDT": { "scale": 2 }
+      },
+      "public_rate_limits": [{ "calls": 20, "time_span": 1, "time_unit": "seconds" }]
+    }"#;
+
+    fn btc_usdt() -> InstrumentDTO {
+        "BTC/USDT".parse().unwrap()
+    }
+
+    #[test]
+    fn test_java_meta_data_round_trip() {
+        let meta = ExchangeMetaData::from_json_str(JAVA_META_DATA).unwrap();
+
+        let spot = &meta.instruments[&btc_usdt()];
+        assert_eq!(spot.trading_fee, Some(Decimal::new(1, 3)));
+        assert_eq!(spot.price_scale, Some(2));
+        assert_eq!(spot.trading_fee_currency, Some(Currency::new("USDT")));
+        assert!(meta.instruments.contains_key(&InstrumentDTO::Futures {
+            base: "BTC".into(),
+            counter: "USDT".into(),
+            prompt: Some("PERP".into()),
+        }));
+        assert_eq!(
+            meta.currencies[&Currency::new("BTC")].wallet_health,
+            crate::dto::meta::WalletHealth::DepositsDisabled
+        );
+        assert_eq!(meta.public_rate_limits[0].calls, 20);
+
+        let reparsed = ExchangeMetaData::from_json_str(&meta.to_json_string()).unwrap();
+        assert_eq!(reparsed.to_json_string(), meta.to_json_string());
+    }
+
+    #[test]
+    fn test_merge_keeps_unset_fields() {
+        let mut remote = ExchangeMetaData::default();
+        remote.instruments.insert(
+            btc_usdt(),
+            InstrumentMetaData::builder()
+                .price_scale(4)
+                .minimum_amount(Decimal::ONE)
+                .build(),
+        );
+
+        let overrides = ExchangeMetaData::from_json_str(JAVA_META_DATA).unwrap();
+        remote.merge(&overrides);
+
+        let spot = &remote.instruments[&btc_usdt()];
+        assert_eq!(spot.price_scale, Some(2));
+        assert_eq!(spot.minimum_amount, Some(Decimal::new(1, 5)));
+        assert_eq!(spot.trading_fee, Some(Decimal::new(1, 3)));
+        assert_eq!(remote.instruments.len(), 2);
+        assert_eq!(remote.public_rate_limits.len(), 1);
+    }
+
+    #[test]
+    fn test_merge_can_disable_market_orders() {
+        let mut remote = ExchangeMetaData::default();
+        remote.instruments.insert(
+            btc_usdt(),
+            InstrumentMetaData::builder()
+                .market_order_enabled(true)
+                .build(),
+        );
+
+        // 未设置时保留远程值
+        let unset = ExchangeMetaData::from_json_str(
+            r#"{ "currency_pairs": { "BTC/USDT": { "price_scale": 2 } } }"#,
+        )
+        .unwrap();
+        remote.merge(&unset);
+        assert!(remote.instruments[&btc_usdt()].is_market_order_enabled());
+
+        let disabled = ExchangeMetaData::from_json_str(
+            r#"{ "currency_pairs": { "BTC/USDT": { "market_order_enabled": false } } }"#,
+        )
+        .unwrap();
+        remote.merge(&disabled);
+        assert!(!remote.instruments[&btc_usdt()].is_market_order_enabled());
+    }
 }
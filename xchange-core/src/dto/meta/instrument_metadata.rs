@@ -45,13 +45,13 @@ pub struct InstrumentMetaData {
     #[serde(rename = "price_step_size")]
     pub price_step_size: Option<Decimal>,
 
-    /// Trading fee currency
-    #[serde(rename = "trading_fee_currency")]
+    /// Trading fee currency, written as the currency code
+    #[serde(rename = "trading_fee_currency", default, with = "currency_code")]
     pub trading_fee_currency: Option<Currency>,
 
-    /// Market order enabled
+    /// Market order enabled, None when not set (e.g. omitted in an override file)
     #[serde(rename = "market_order_enabled", default)]
-    pub market_order_enabled: bool,
+    pub market_order_enabled: Option<bool>,
 
     /// Contract value
     #[serde(rename = "contract_value")]
@@ -89,14 +89,75 @@ impl InstrumentMetaData {
             amount_step_size: amount_step_size.map(|d| d.normalize()),
             price_step_size: price_step_size.map(|d| d.normalize()),
             trading_fee_currency,
-            market_order_enabled,
+            market_order_enabled: Some(market_order_enabled),
             contract_value,
         }
     }
 
+    /// 未设置时按不支持市价单处理
+    pub fn is_market_order_enabled(&self) -> bool {
+        self.market_order_enabled.unwrap_or(false)
+    }
+
     pub fn builder() -> InstrumentMetaDataBuilder {
         InstrumentMetaDataBuilder::default()
     }
+
+    /// Overwrite the fields set in `other`, keep the rest
+    pub fn merge(&mut self, other: &InstrumentMetaData) {
+        fn take<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                *target = value.clone();
+            }
+        }
+
+        take(&mut self.trading_fee, &other.trading_fee);
+        if !other.fee_tiers.is_empty() {
+            self.fee_tiers = other.fee_tiers.clone();
+        }
+        take(&mut self.minimum_amount, &other.minimum_amount);
+        take(&mut self.maximum_amount, &other.maximum_amount);
+        take(
+            &mut self.counter_minimum_amount,
+            &other.counter_minimum_amount,
+        );
+        take(
+            &mut self.counter_maximum_amount,
+            &other.counter_maximum_amount,
+        );
+        take(&mut self.price_scale, &other.price_scale);
+        take(&mut self.volume_scale, &other.volume_scale);
+        take(&mut self.amount_step_size, &other.amount_step_size);
+        take(&mut self.price_step_size, &other.price_step_size);
+        take(&mut self.trading_fee_currency, &other.trading_fee_currency);
+        take(&mut self.market_order_enabled, &other.market_order_enabled);
+        take(&mut self.contract_value, &other.contract_value);
+    }
+}
+
+/// Java XChange 以字符串形式保存 Currency
+mod currency_code {
+    use crate::currency::currency::Currency;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        currency: &Option<Currency>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match currency {
+            Some(currency) => serializer.serialize_str(&currency.code),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Currency>, D::Error> {
+        let code = Option::<String>::deserialize(deserializer)?;
+        Ok(code
+            .filter(|code| !code.trim().is_empty())
+            .map(|code| Currency::new(code.trim())))
+    }
 }
 
 #[derive(Debug, Default)]
@@ -112,7 +173,7 @@ pub struct InstrumentMetaDataBuilder {
     amount_step_size: Option<Decimal>,
     price_step_size: Option<Decimal>,
     trading_fee_currency: Option<Currency>,
-    market_order_enabled: bool,
+    market_order_enabled: Option<bool>,
     contract_value: Option<Decimal>,
 }
 
@@ -173,7 +234,7 @@ impl InstrumentMetaDataBuilder {
     }
 
     pub fn market_order_enabled(mut self, enabled: bool) -> Self {
-        self.market_order_enabled = enabled;
+        self.market_order_enabled = Some(enabled);
         self
     }
 
@@ -7,7 +7,10 @@ pub mod rate_limit;
 use serde::{Deserialize, Serialize};
 
 /// Represents the health status of a wallet on the exchange.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Serialized as `ONLINE`, `DEPOSITS_DISABLED`, ... like the Java XChange meta data files.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WalletHealth {
     /// You can deposit and withdraw funds from the exchange
     Online,
@@ -22,6 +25,7 @@ pub enum WalletHealth {
     Offline,
 
     /// The exchange does not inform us about the health of this wallet
+    #[default]
     Unknown,
 }
 
@@ -1,18 +1,229 @@
 use crate::currency::currency_pair::CurrencyPair;
 use crate::define_exchange_error;
 use crate::error::ExchangeErrorDetail;
-use thiserror::Error;
+use crate::error::kind::ErrorKind;
+use std::any::Any;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
 
 /// Core ExchangeError for all exchange-related errors
-#[derive(Debug, Error)]
+#[derive(Debug)]
 pub enum ExchangeError {
     /// Simple string message (lightweight)
-    #[error("Exchange error: {0}")]
     Message(String),
 
     /// Any custom error implementing ExchangeErrorDetail
-    #[error("{0}")]
     Custom(Box<dyn ExchangeErrorDetail>),
+
+    /// 交易所接口返回的结构化错误
+    Api(ExchangeApiError),
+}
+
+impl ExchangeError {
+    /// 取出具体的错误类型，例如 `err.downcast_ref::<RateLimitExceededError>()`，
+    /// `Api` 错误沿 source 链查找
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        match self {
+            ExchangeError::Message(_) => None,
+            ExchangeError::Custom(detail) => detail.as_any()?.downcast_ref::<T>(),
+            ExchangeError::Api(e) => {
+                let mut current: Option<&(dyn Error + 'static)> = Some(e);
+                while let Some(e) = current {
+                    if let Some(e) = e.downcast_ref::<T>() {
+                        return Some(e);
+                    }
+                    current = e.source();
+                }
+                None
+            }
+        }
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            ExchangeError::Message(_) => ErrorKind::Other,
+            ExchangeError::Custom(detail) => match detail.kind() {
+                ErrorKind::Other => detail.as_any().map_or(ErrorKind::Other, legacy_kind),
+                kind => kind,
+            },
+            ExchangeError::Api(e) => e.kind,
+        }
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    pub fn api_error(&self) -> Option<&ExchangeApiError> {
+        match self {
+            ExchangeError::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// 交易所原生错误码，例如 Binance 的 `-2010`
+    pub fn code(&self) -> Option<&str> {
+        self.api_error()?.code.as_deref()
+    }
+
+    pub fn http_status(&self) -> Option<u16> {
+        self.api_error()?.http_status
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.api_error()?.path.as_deref()
+    }
+
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.api_error()?.retry_after
+    }
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExchangeError::Message(message) => write!(f, "Exchange error: {}", message),
+            ExchangeError::Custom(detail) => fmt::Display::fmt(detail, f),
+            ExchangeError::Api(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for ExchangeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ExchangeError::Message(_) => None,
+            ExchangeError::Custom(detail) => detail.source(),
+            ExchangeError::Api(e) => e.source(),
+        }
+    }
+}
+
+impl From<ExchangeApiError> for ExchangeError {
+    fn from(err: ExchangeApiError) -> Self {
+        ExchangeError::Api(err)
+    }
+}
+
+/// 由 `define_exchange_error!` 定义的错误对应的分类
+fn legacy_kind(detail: &dyn Any) -> ErrorKind {
+    if detail.is::<ExchangeSecurityError>() {
+        ErrorKind::Auth
+    } else if detail.is::<ExchangeUnavailableError>() || detail.is::<InternalServerError>() {
+        ErrorKind::Unavailable
+    } else if detail.is::<FrequencyLimitExceededError>() || detail.is::<RateLimitExceededError>() {
+        ErrorKind::RateLimit
+    } else if detail.is::<FundsExceededError>() {
+        ErrorKind::InsufficientFunds
+    } else if detail.is::<InstrumentNotValidError>() || detail.is::<CurrencyPairNotValidError>() {
+        ErrorKind::UnknownInstrument
+    } else if detail.is::<MarketSuspendedError>() {
+        ErrorKind::MarketSuspended
+    } else if detail.is::<OrderNotFoundError>() {
+        ErrorKind::UnknownOrder
+    } else if detail.is::<NonceError>() {
+        ErrorKind::Nonce
+    } else if detail.is::<OrderNotValidError>() || detail.is::<OrderAmountUnderMinimumError>() {
+        ErrorKind::InvalidOrder
+    } else if detail.is::<NotYetImplementedForExchangeError>()
+        || detail.is::<NotAvailableFromExchangeError>()
+    {
+        ErrorKind::NotSupported
+    } else {
+        ErrorKind::Other
+    }
+}
+
+/// 结构化的交易所错误：分类、原生错误码、HTTP 状态、请求路径和原始错误
+#[derive(Debug)]
+pub struct ExchangeApiError {
+    pub kind: ErrorKind,
+    pub message: String,
+    pub code: Option<String>,
+    pub http_status: Option<u16>,
+    pub path: Option<String>,
+    /// 限流时交易所要求的等待时间
+    pub retry_after: Option<Duration>,
+    source: Option<Box<dyn Error + Send + Sync + 'static>>,
+}
+
+impl ExchangeApiError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            code: None,
+            http_status: None,
+            path: None,
+            retry_after: None,
+            source: None,
+        }
+    }
+
+    /// 按 source 链推断分类
+    pub fn from_source<E>(message: impl Into<String>, source: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        Self::new(ErrorKind::of(&source), message).with_source(source)
+    }
+
+    pub fn code(mut self, code: impl ToString) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn http_status(mut self, status: Option<u16>) -> Self {
+        self.http_status = status;
+        self
+    }
+
+    pub fn path(mut self, path: Option<impl Into<String>>) -> Self {
+        self.path = path.map(Into::into);
+        self
+    }
+
+    pub fn retry_after(mut self, retry_after: Option<Duration>) -> Self {
+        self.retry_after = retry_after;
+        self
+    }
+
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    pub fn is_retryable(&self) -> bool {
+        self.kind.is_retryable()
+    }
+}
+
+impl fmt::Display for ExchangeApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} error", self.kind)?;
+        if let Some(code) = &self.code {
+            write!(f, " {}", code)?;
+        }
+        if let Some(status) = self.http_status {
+            write!(f, " (HTTP {})", status)?;
+        }
+        if let Some(path) = &self.path {
+            write!(f, " on {}", path)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Error for ExchangeApiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|e| e.as_ref() as &(dyn Error + 'static))
+    }
 }
 
 // Indicates that the cause the error ware wrong credentials or insufficient privileges.
@@ -108,6 +319,9 @@ define_exchange_error!(
 // Exception indicating that an order placed or verified was not valid.
 define_exchange_error!(OrderNotValidError, "Invalid order");
 
+// Exception indicating that the order to query or cancel does not exist.
+define_exchange_error!(OrderNotFoundError, "Order not found");
+
 // Exception indicating that an order placed or verified was not valid.
 define_exchange_error!(OrderAmountUnderMinimumError, "Orders amount under minimum");
 
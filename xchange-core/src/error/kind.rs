@@ -0,0 +1,229 @@
+use crate::client::{
+    BulkheadFullError, CircuitBreakerOpenError, HttpStatusError, RateLimiterError, TimeoutError,
+};
+use crate::error::exchange_error::{ExchangeApiError, ExchangeError};
+use crate::rescu::HttpError;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// 错误分类，替代按错误信息字符串匹配
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// 凭证错误、签名错误、权限不足、IP 不在白名单
+    Auth,
+    /// 触发交易所或本地限流
+    RateLimit,
+    /// 余额不足
+    InsufficientFunds,
+    /// 订单参数不满足交易规则（价格、数量、名义价值等）
+    InvalidOrder,
+    /// 交易对 / 合约不存在
+    UnknownInstrument,
+    /// 订单不存在（已成交、已撤销或 id 错误）
+    UnknownOrder,
+    /// 交易对暂停交易
+    MarketSuspended,
+    /// nonce / timestamp 超出允许范围，需要校时后再重试
+    Nonce,
+    /// 交易所不可用、维护中、服务繁忙或熔断打开
+    Unavailable,
+    /// 请求超时，请求可能已到达交易所
+    Timeout,
+    /// 连接、TLS 等网络错误
+    Transport,
+    /// 响应无法解析
+    Decode,
+    /// 请求参数错误（非订单规则）
+    InvalidRequest,
+    /// 交易所或实现不支持
+    NotSupported,
+    Other,
+}
+
+impl ErrorKind {
+    /// 瞬时错误，稍后重试可能成功。
+    /// Timeout / Transport 时请求可能已被处理，非幂等请求需先确认状态；Nonce 需先校时
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorKind::RateLimit
+                | ErrorKind::Unavailable
+                | ErrorKind::Timeout
+                | ErrorKind::Transport
+        )
+    }
+
+    /// 沿 source 链查找第一个可分类的错误
+    pub fn of(error: &(dyn Error + 'static)) -> ErrorKind {
+        let mut current = Some(error);
+        while let Some(e) = current {
+            if let Some(kind) = Self::classify(e) {
+                return kind;
+            }
+            current = e.source();
+        }
+        ErrorKind::Other
+    }
+
+    fn classify(e: &(dyn Error + 'static)) -> Option<ErrorKind> {
+        if let Some(e) = e.downcast_ref::<ExchangeError>() {
+            return Some(e.kind()).filter(|kind| *kind != ErrorKind::Other);
+        }
+        if let Some(e) = e.downcast_ref::<ExchangeApiError>() {
+            return Some(e.kind);
+        }
+        if let Some(e) = e.downcast_ref::<HttpStatusError>() {
+            return Some(Self::from_http_status(e.status));
+        }
+        if let Some(HttpError::Fixture(_)) = e.downcast_ref::<HttpError>() {
+            return Some(ErrorKind::InvalidRequest);
+        }
+        if let Some(e) = e.downcast_ref::<io::Error>() {
+            // transport 的错误包装在 io::Error 中，回放未命中不应重试
+            let inner = e
+                .get_ref()
+                .and_then(|inner| inner.downcast_ref::<HttpError>());
+            if let Some(HttpError::Fixture(_)) = inner {
+                return Some(ErrorKind::InvalidRequest);
+            }
+            return Some(match e.kind() {
+                io::ErrorKind::TimedOut => ErrorKind::Timeout,
+                io::ErrorKind::InvalidData => ErrorKind::Decode,
+                _ => ErrorKind::Transport,
+            });
+        }
+        if e.is::<TimeoutError>() || e.is::<tokio::time::error::Elapsed>() {
+            return Some(ErrorKind::Timeout);
+        }
+        if e.is::<RateLimiterError>() {
+            return Some(ErrorKind::RateLimit);
+        }
+        if e.is::<CircuitBreakerOpenError>() || e.is::<BulkheadFullError>() {
+            return Some(ErrorKind::Unavailable);
+        }
+        if e.is::<serde_json::Error>() {
+            return Some(ErrorKind::Decode);
+        }
+        None
+    }
+
+    pub fn from_http_status(status: u16) -> ErrorKind {
+        match status {
+            401 | 403 => ErrorKind::Auth,
+            408 | 504 => ErrorKind::Timeout,
+            418 | 429 => ErrorKind::RateLimit,
+            404 => ErrorKind::NotSupported,
+            400..=499 => ErrorKind::InvalidRequest,
+            500..=599 => ErrorKind::Unavailable,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ErrorKind::Auth => "auth",
+            ErrorKind::RateLimit => "rate limit",
+            ErrorKind::InsufficientFunds => "insufficient funds",
+            ErrorKind::InvalidOrder => "invalid order",
+            ErrorKind::UnknownInstrument => "unknown instrument",
+            ErrorKind::UnknownOrder => "unknown order",
+            ErrorKind::MarketSuspended => "market suspended",
+            ErrorKind::Nonce => "nonce",
+            ErrorKind::Unavailable => "unavailable",
+            ErrorKind::Timeout => "timeout",
+            ErrorKind::Transport => "transport",
+            ErrorKind::Decode => "decode",
+            ErrorKind::InvalidRequest => "invalid request",
+            ErrorKind::NotSupported => "not supported",
+            ErrorKind::Other => "other",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::exchange_error::{
+        ExchangeSecurityError, FundsExceededError, NonceError, OrderNotFoundError,
+        RateLimitExceededError,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_legacy_errors_have_kinds() {
+        for (error, kind) in [
+            (
+                ExchangeError::from(ExchangeSecurityError::new()),
+                ErrorKind::Auth,
+            ),
+            (
+                ExchangeError::from(FundsExceededError::new()),
+                ErrorKind::InsufficientFunds,
+            ),
+            (ExchangeError::from(NonceError::new()), ErrorKind::Nonce),
+            (
+                ExchangeError::from(OrderNotFoundError::new()),
+                ErrorKind::UnknownOrder,
+            ),
+            (
+                ExchangeError::from(RateLimitExceededError::new()),
+                ErrorKind::RateLimit,
+            ),
+            (ExchangeError::Message("boom".into()), ErrorKind::Other),
+        ] {
+            assert_eq!(error.kind(), kind, "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_api_error() {
+        let error = ExchangeError::from(
+            ExchangeApiError::new(ErrorKind::RateLimit, "Too many requests")
+                .code(-1003)
+                .http_status(Some(429))
+                .path(Some("/api/v3/order"))
+                .retry_after(Some(Duration::from_secs(3)))
+                .with_source(RateLimitExceededError::with_message("Too many requests")),
+        );
+
+        assert_eq!(error.kind(), ErrorKind::RateLimit);
+        assert!(error.is_retryable());
+        assert_eq!(error.code(), Some("-1003"));
+        assert_eq!(error.http_status(), Some(429));
+        assert_eq!(error.path(), Some("/api/v3/order"));
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(3)));
+        assert!(error.downcast_ref::<RateLimitExceededError>().is_some());
+        assert!(error.source().is_some());
+        assert_eq!(
+            error.to_string(),
+            "rate limit error -1003 (HTTP 429) on /api/v3/order: Too many requests"
+        );
+    }
+
+    #[test]
+    fn test_kind_of_source_chain() {
+        let error = ExchangeApiError::from_source(
+            "request failed",
+            HttpStatusError::new(503, "unavailable"),
+        );
+        assert_eq!(error.kind, ErrorKind::Unavailable);
+
+        let error = ExchangeApiError::from_source(
+            "request failed",
+            io::Error::new(io::ErrorKind::ConnectionReset, "reset"),
+        );
+        assert_eq!(error.kind, ErrorKind::Transport);
+        assert_eq!(
+            ErrorKind::of(&TimeoutError::deadline_exceeded()),
+            ErrorKind::Timeout
+        );
+        assert!(!ErrorKind::Nonce.is_retryable());
+
+        let error = io::Error::other(HttpError::Fixture("no recorded response".into()));
+        assert_eq!(ErrorKind::of(&error), ErrorKind::InvalidRequest);
+    }
+}
@@ -104,7 +104,15 @@ macro_rules! define_exchange_error {
             }
         }
 
-        impl crate::error::ExchangeErrorDetail for $name {}
+        impl crate::error::ExchangeErrorDetail for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                std::error::Error::source(self)
+            }
+
+            fn as_any(&self) -> Option<&dyn std::any::Any> {
+                Some(self)
+            }
+        }
 
         impl From<$name> for crate::error::exchange_error::ExchangeError {
             fn from(err: $name) -> Self {
@@ -172,7 +180,15 @@ macro_rules! define_exchange_error {
             }
         }
 
-        impl crate::error::ExchangeErrorDetail for $name {}
+        impl crate::error::ExchangeErrorDetail for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                std::error::Error::source(self)
+            }
+
+            fn as_any(&self) -> Option<&dyn std::any::Any> {
+                Some(self)
+            }
+        }
 
         impl From<$name> for crate::error::exchange_error::ExchangeError {
             fn from(err: $name) -> Self {
@@ -1,7 +1,10 @@
 pub mod exchange_error;
+pub mod kind;
 pub mod macros;
 
-use std::{error::Error, fmt};
+pub use kind::ErrorKind;
+
+use std::{any::Any, error::Error, fmt};
 
 /// Trait for all custom, exchange-specific errors.
 /// All implementors are required to be Send + Sync + 'static.
@@ -10,4 +13,14 @@ pub trait ExchangeErrorDetail: fmt::Debug + fmt::Display + Send + Sync + 'static
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         None
     }
+
+    /// 用于向下转型到具体的错误类型
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// 错误分类，未覆盖时按 `define_exchange_error!` 定义的类型推断
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
 }
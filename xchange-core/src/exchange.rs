@@ -1,18 +1,26 @@
 use crate::TimeUnit::Milliseconds;
 use crate::ValueFactory;
 use crate::client::ResilienceRegistries;
+use crate::currency::currency_pair::CurrencyPair;
+use crate::derivative::futures_contract::FuturesContract;
+use crate::derivative::options_contract::OptionsContract;
 use crate::dto::meta::exchange_metadata::ExchangeMetaData;
 use crate::error::exchange_error::{ExchangeError, NotYetImplementedForExchangeError};
 use crate::exchange_specification::ExchangeSpecification;
-use crate::instrument::Instrument;
+use crate::instrument::{Instrument, InstrumentDTO, InstrumentKind};
 use crate::service::account::account_service::AccountService;
+use crate::service::account::streaming_account_service::StreamingAccountService;
 use crate::service::marketdata::market_data_service::MarketDataService;
+use crate::service::marketdata::streaming_market_data_service::StreamingMarketDataService;
+use crate::service::trade::streaming_trade_service::StreamingTradeService;
 use crate::service::trade::trade_service::TradeService;
 use crate::utils::time_nonce::TimeNonce;
 use async_trait::async_trait;
+use log::warn;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::Path;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,7 +71,26 @@ pub trait Exchange: Send + Sync {
 
     fn account_service(&self) -> Result<Arc<dyn AccountService + Send + Sync>, ExchangeError>;
 
-    async fn remote_init(&mut self) -> Result<(), ExchangeError> {
+    fn streaming_market_data_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingMarketDataService + Send + Sync>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("streaming_market_data_service").into())
+    }
+
+    fn streaming_trade_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingTradeService + Send + Sync>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("streaming_trade_service").into())
+    }
+
+    fn streaming_account_service(
+        &self,
+    ) -> Result<Arc<dyn StreamingAccountService + Send + Sync>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("streaming_account_service").into())
+    }
+
+    /// 从交易所加载远程 ExchangeMetaData，`should_load_remote_meta_data` 为 true 时调用
+    async fn remote_init(&self) -> Result<(), ExchangeError> {
         Ok(())
     }
 
@@ -79,22 +106,32 @@ pub struct BaseExchange {
     /// ExchangeSpecification，使用 RwLock 保证可动态修改
     pub spec: Arc<RwLock<ExchangeSpecification>>,
     pub meta_data: Arc<RwLock<ExchangeMetaData>>,
+    /// 由 meta_data 构建，meta data 更新时同步刷新
+    pub exchange_instruments: RwLock<Arc<Vec<Arc<dyn Instrument + Send + Sync>>>>,
     pub nonce_factory: Arc<dyn ValueFactory<u64> + Send + Sync>,
 
     pub market_service: RwLock<Option<Arc<dyn MarketDataService + Send + Sync>>>,
     pub trade_service: RwLock<Option<Arc<dyn TradeService + Send + Sync>>>,
     pub account_service: RwLock<Option<Arc<dyn AccountService + Send + Sync>>>,
+
+    pub streaming_market_service: RwLock<Option<Arc<dyn StreamingMarketDataService + Send + Sync>>>,
+    pub streaming_trade_service: RwLock<Option<Arc<dyn StreamingTradeService + Send + Sync>>>,
+    pub streaming_account_service: RwLock<Option<Arc<dyn StreamingAccountService + Send + Sync>>>,
 }
 
 impl BaseExchange {
     pub fn new(default_spec: ExchangeSpecification, meta_data: ExchangeMetaData) -> Self {
         Self {
             spec: Arc::new(RwLock::new(default_spec)),
+            exchange_instruments: RwLock::new(Arc::new(Self::adapt_instruments(&meta_data))),
             meta_data: Arc::new(RwLock::new(meta_data)),
             nonce_factory: Arc::new(TimeNonce::new(Milliseconds)),
             market_service: RwLock::new(None),
             trade_service: RwLock::new(None),
             account_service: RwLock::new(None),
+            streaming_market_service: RwLock::new(None),
+            streaming_trade_service: RwLock::new(None),
+            streaming_account_service: RwLock::new(None),
         }
     }
 
@@ -104,19 +141,111 @@ impl BaseExchange {
         let merged_spec = self.merge_default_specification(spec);
 
         // 修改 RwLock 内部值
-        let mut write_spec = self.spec.write();
-        *write_spec = merged_spec;
+        *self.spec.write() = merged_spec;
 
-        // 可选 JSON 初始化占位（加载 ExchangeMetaData）
-        if write_spec.should_load_remote_meta_data {
-            println!("Remote init placeholder (async load can be implemented here)");
-            // 可异步 spawn tokio 任务加载 meta_data
-        }
+        // 远程 ExchangeMetaData 由具体交易所在 Exchange::remote_init 中加载
 
         // 初始化服务，子类可覆盖 init_services
         self.init_services().await;
     }
 
+    /// 加载本地 meta data：快照（最后已知的远程数据）叠加 `meta_data_json_file_override`。
+    ///
+    /// 快照读取失败只记录日志；显式配置的 override 文件读取失败返回错误。
+    pub fn load_local_meta_data(&self) -> Result<(), ExchangeError> {
+        let snapshot_file = self.spec.read().meta_data_snapshot_file.clone();
+
+        let mut meta_data = ExchangeMetaData::default();
+        if let Some(path) = snapshot_file.filter(|path| Path::new(path).exists()) {
+            match ExchangeMetaData::load_json_file(&path) {
+                Ok(snapshot) => meta_data = snapshot,
+                Err(e) => warn!("Ignore meta data snapshot: {}", e),
+            }
+        }
+        if let Some(overrides) = self.meta_data_override()? {
+            meta_data.merge(&overrides);
+        }
+
+        self.set_meta_data(meta_data);
+        Ok(())
+    }
+
+    /// 应用远程 meta data：先写入快照，再叠加本地 override 后生效
+    pub fn apply_remote_meta_data(&self, remote: ExchangeMetaData) -> Result<(), ExchangeError> {
+        let snapshot_file = self.spec.read().meta_data_snapshot_file.clone();
+        if let Some(path) = snapshot_file
+            && let Err(e) = remote.write_json_file(&path)
+        {
+            warn!("Write meta data snapshot failed: {}", e);
+        }
+
+        let mut meta_data = remote;
+        if let Some(overrides) = self.meta_data_override()? {
+            meta_data.merge(&overrides);
+        }
+
+        self.set_meta_data(meta_data);
+        Ok(())
+    }
+
+    /// 更新 meta data 并刷新 exchange_instruments
+    pub fn set_meta_data(&self, meta_data: ExchangeMetaData) {
+        let instruments = Self::adapt_instruments(&meta_data);
+        *self.meta_data.write() = meta_data;
+        *self.exchange_instruments.write() = Arc::new(instruments);
+    }
+
+    /// meta data 中的 instrument → CurrencyPair / FuturesContract / OptionsContract，按 symbol 排序。
+    ///
+    /// 类型由 meta data key 决定：`BTC/USDT` 为现货，`BTC/USDT/PERP`（U 本位）与
+    /// `BTC/USD/PERP`（币本位）为合约，保留各自的计价币，`BTC/USD/210709/34000/C` 为期权
+    pub fn adapt_instruments(
+        meta_data: &ExchangeMetaData,
+    ) -> Vec<Arc<dyn Instrument + Send + Sync>> {
+        let mut instruments: Vec<&InstrumentDTO> = meta_data.instruments.keys().collect();
+        instruments.sort_by_cached_key(|instrument| instrument.to_string());
+
+        instruments
+            .into_iter()
+            .map(|instrument| {
+                let kind = match instrument {
+                    InstrumentDTO::Spot { base, counter } => {
+                        InstrumentKind::CurrencyPair(CurrencyPair::from_symbols(base, counter))
+                    }
+                    InstrumentDTO::Futures {
+                        base,
+                        counter,
+                        prompt,
+                    } => InstrumentKind::FuturesContract(FuturesContract::new(
+                        Arc::new(CurrencyPair::from_symbols(base, counter)),
+                        prompt.clone(),
+                    )),
+                    InstrumentDTO::Options {
+                        base,
+                        counter,
+                        strike,
+                        expire_date,
+                        option_type,
+                    } => InstrumentKind::OptionsContract(OptionsContract::new(
+                        CurrencyPair::from_symbols(base, counter),
+                        *expire_date,
+                        *strike,
+                        *option_type,
+                    )),
+                };
+                Arc::new(kind) as Arc<dyn Instrument + Send + Sync>
+            })
+            .collect()
+    }
+
+    /// 读取 `meta_data_json_file_override`，未配置时返回 None
+    fn meta_data_override(&self) -> Result<Option<ExchangeMetaData>, ExchangeError> {
+        let override_file = self.spec.read().meta_data_json_file_override.clone();
+        override_file
+            .map(ExchangeMetaData::load_json_file)
+            .transpose()
+    }
+
     /// 合并默认值（默认值来源可自定义）
     pub fn merge_default_specification(
         &self,
@@ -144,6 +273,12 @@ impl BaseExchange {
                 merged.extend(spec.exchange_specific_parameters.clone());
                 merged
             },
+            meta_data_json_file_override: spec
+                .meta_data_json_file_override
+                .or(default_spec_ref.meta_data_json_file_override.clone()),
+            meta_data_snapshot_file: spec
+                .meta_data_snapshot_file
+                .or(default_spec_ref.meta_data_snapshot_file.clone()),
             should_load_remote_meta_data: spec.should_load_remote_meta_data,
             ..default_spec_ref.clone() // 这里直接用 clone，不再解引用
         }
@@ -161,3 +296,43 @@ impl BaseExchange {
         self.spec.write().clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::meta::instrument_metadata::InstrumentMetaData;
+
+    #[test]
+    fn test_adapt_instruments_by_key_type() {
+        let mut meta_data = ExchangeMetaData::default();
+        for symbol in [
+            "BTC/USDT",
+            "BTC/USDT/PERP",
+            "BTC/USD/PERP",
+            "BTC/USD/210709/34000/C",
+        ] {
+            meta_data.instruments.insert(
+                symbol.parse().unwrap(),
+                InstrumentMetaData::builder().build(),
+            );
+        }
+
+        let instruments: Vec<(String, String)> = BaseExchange::adapt_instruments(&meta_data)
+            .iter()
+            .map(|instrument| {
+                let kind = format!("{:?}", instrument);
+                let kind = kind.split('(').next().unwrap().to_string();
+                (kind, instrument.counter().code.clone())
+            })
+            .collect();
+        assert_eq!(
+            instruments,
+            vec![
+                ("OptionsContract".to_string(), "USD".to_string()),
+                ("FuturesContract".to_string(), "USD".to_string()),
+                ("CurrencyPair".to_string(), "USDT".to_string()),
+                ("FuturesContract".to_string(), "USDT".to_string()),
+            ]
+        );
+    }
+}
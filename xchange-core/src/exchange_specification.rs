@@ -1,7 +1,13 @@
 use crate::exchange::ExchangeType;
+use crate::utils::credentials::{CredentialProvider, Credentials, Secret};
+use log::warn;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
-/// Configuration for resilience behavior of an exchange (retry and rate limiting).
+/// Configuration for resilience behavior of an exchange
+/// (retry, rate limiting, circuit breaker and bulkhead).
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ResilienceSpecification {
     /// If true, retry functionality is enabled (if implemented for the exchange)
@@ -9,14 +15,34 @@ pub struct ResilienceSpecification {
 
     /// If true, call rate limiting functionality is enabled (if implemented for the exchange)
     pub rate_limiter_enabled: bool,
+
+    /// If true, calls are guarded by circuit breakers (if implemented for the exchange)
+    pub circuit_breaker_enabled: bool,
+
+    /// If true, concurrent calls are limited by bulkheads (if implemented for the exchange)
+    pub bulkhead_enabled: bool,
+
+    /// Default time budget of a call, spanning retries and rate limiter waits (None = unbounded)
+    pub call_timeout: Option<Duration>,
 }
 
 impl ResilienceSpecification {
-    /// Create a new default specification (both features disabled)
+    /// Create a new default specification (all features disabled)
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Create a specification with all features enabled
+    pub fn enabled() -> Self {
+        Self {
+            retry_enabled: true,
+            rate_limiter_enabled: true,
+            circuit_breaker_enabled: true,
+            bulkhead_enabled: true,
+            call_timeout: None,
+        }
+    }
+
     /// Enable or disable retry
     pub fn set_retry_enabled(&mut self, enabled: bool) {
         self.retry_enabled = enabled;
@@ -36,6 +62,31 @@ impl ResilienceSpecification {
     pub fn is_rate_limiter_enabled(&self) -> bool {
         self.rate_limiter_enabled
     }
+
+    /// Enable or disable circuit breaker
+    pub fn set_circuit_breaker_enabled(&mut self, enabled: bool) {
+        self.circuit_breaker_enabled = enabled;
+    }
+
+    /// Check if circuit breaker is enabled
+    pub fn is_circuit_breaker_enabled(&self) -> bool {
+        self.circuit_breaker_enabled
+    }
+
+    /// Enable or disable bulkhead
+    pub fn set_bulkhead_enabled(&mut self, enabled: bool) {
+        self.bulkhead_enabled = enabled;
+    }
+
+    /// Check if bulkhead is enabled
+    pub fn is_bulkhead_enabled(&self) -> bool {
+        self.bulkhead_enabled
+    }
+
+    /// Set the default time budget of a call
+    pub fn set_call_timeout(&mut self, timeout: Option<Duration>) {
+        self.call_timeout = timeout;
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -65,25 +116,42 @@ impl ExchangeParam {
 }
 
 /// ExchangeSpecification with builder
+///
+/// password / secret_key / passphrase 为 `Secret`，Debug 时不输出明文
 #[derive(Debug, Clone)]
 pub struct ExchangeSpecification {
     pub exchange_name: Option<String>,
     pub exchange_description: Option<String>,
     pub user_name: Option<String>,
-    pub password: Option<String>,
-    pub secret_key: Option<String>,
+    pub password: Option<Secret>,
+    pub secret_key: Option<Secret>,
     pub api_key: Option<String>,
+    /// 加密私钥的口令，或交易所要求的 API passphrase
+    pub passphrase: Option<Secret>,
+    /// PEM 私钥文件，secret_key 为空时由交易所实现读取
+    pub private_key_file: Option<PathBuf>,
     pub ssl_uri: Option<String>,
     pub plain_text_uri: Option<String>,
     pub override_websocket_api_uri: Option<String>,
     pub host: Option<String>,
     pub port: u16,
+    /// 代理地址，`host` 或 `scheme://[user:password@]host[:port]`（http / https / socks5）
     pub proxy_host: Option<String>,
     pub proxy_port: Option<u16>,
     pub http_conn_timeout: u64,
     pub http_read_timeout: u64,
+    /// 每个 host 保留的空闲连接数
+    pub http_pool_max_idle_per_host: Option<usize>,
+    /// 空闲连接保留时间（毫秒），0 表示不限制
+    pub http_pool_idle_timeout: Option<u64>,
+    /// TCP keep-alive 间隔（毫秒），0 表示关闭
+    pub http_tcp_keep_alive: Option<u64>,
+    pub http_user_agent: Option<String>,
     pub resilience: ResilienceSpecification,
+    /// 本地 meta data JSON，优先级高于远程数据
     pub meta_data_json_file_override: Option<String>,
+    /// 远程 meta data 加载成功后写入的快照，远程不可用时作为最后已知数据使用
+    pub meta_data_snapshot_file: Option<String>,
     pub should_load_remote_meta_data: bool,
     pub exchange_specific_parameters: HashMap<String, ExchangeParam>,
     pub use_sandbox: bool,
@@ -98,6 +166,29 @@ impl ExchangeSpecification {
         self.exchange_specific_parameters.get(key)
     }
 
+    /// 用 provider 的凭证填充未设置的字段，已显式设置的字段优先
+    pub fn apply_credentials(&mut self, credentials: Credentials) {
+        let Credentials {
+            api_key,
+            secret_key,
+            passphrase,
+            private_key_file,
+        } = credentials;
+        self.api_key = self.api_key.take().or(api_key);
+        self.secret_key = self.secret_key.take().or(secret_key);
+        self.passphrase = self.passphrase.take().or(passphrase);
+        self.private_key_file = self.private_key_file.take().or(private_key_file);
+    }
+
+    /// 通过 provider 解析凭证，读取失败时保留现有字段
+    pub fn resolve_credentials(&mut self, provider: &dyn CredentialProvider) {
+        match provider.credentials() {
+            Ok(Some(credentials)) => self.apply_credentials(credentials),
+            Ok(None) => {}
+            Err(e) => warn!("resolve credentials from {:?} failed: {}", provider, e),
+        }
+    }
+
     /// 用 default 填充缺失字段
     pub fn fill_missing_from(&mut self, default: &ExchangeSpecification) {
         if self.exchange_name.is_none() {
@@ -138,9 +229,12 @@ pub struct ExchangeSpecificationBuilder {
     exchange_name: Option<String>,
     exchange_description: Option<String>,
     user_name: Option<String>,
-    password: Option<String>,
-    secret_key: Option<String>,
+    password: Option<Secret>,
+    secret_key: Option<Secret>,
     api_key: Option<String>,
+    passphrase: Option<Secret>,
+    private_key_file: Option<PathBuf>,
+    credential_provider: Option<Arc<dyn CredentialProvider>>,
     ssl_uri: Option<String>,
     plain_text_uri: Option<String>,
     override_websocket_api_uri: Option<String>,
@@ -150,8 +244,13 @@ pub struct ExchangeSpecificationBuilder {
     proxy_port: Option<u16>,
     http_conn_timeout: Option<u64>,
     http_read_timeout: Option<u64>,
+    http_pool_max_idle_per_host: Option<usize>,
+    http_pool_idle_timeout: Option<u64>,
+    http_tcp_keep_alive: Option<u64>,
+    http_user_agent: Option<String>,
     resilience: Option<ResilienceSpecification>,
     meta_data_json_file_override: Option<String>,
+    meta_data_snapshot_file: Option<String>,
     should_load_remote_meta_data: Option<bool>,
     exchange_specific_parameters: HashMap<String, ExchangeParam>,
 }
@@ -165,6 +264,9 @@ impl Default for ExchangeSpecificationBuilder {
             password: None,
             secret_key: None,
             api_key: None,
+            passphrase: None,
+            private_key_file: None,
+            credential_provider: None,
             ssl_uri: None,
             plain_text_uri: None,
             override_websocket_api_uri: None,
@@ -174,8 +276,13 @@ impl Default for ExchangeSpecificationBuilder {
             proxy_port: None,
             http_conn_timeout: None,
             http_read_timeout: None,
+            http_pool_max_idle_per_host: None,
+            http_pool_idle_timeout: None,
+            http_tcp_keep_alive: None,
+            http_user_agent: None,
             resilience: None,
             meta_data_json_file_override: None,
+            meta_data_snapshot_file: None,
             should_load_remote_meta_data: None,
             exchange_specific_parameters: HashMap::new(),
         }
@@ -199,12 +306,12 @@ impl ExchangeSpecificationBuilder {
     }
 
     pub fn password(mut self, pwd: impl Into<String>) -> Self {
-        self.password = Some(pwd.into());
+        self.password = Some(Secret::new(pwd));
         self
     }
 
     pub fn secret_key(mut self, key: impl Into<String>) -> Self {
-        self.secret_key = Some(key.into());
+        self.secret_key = Some(Secret::new(key));
         self
     }
 
@@ -213,6 +320,22 @@ impl ExchangeSpecificationBuilder {
         self
     }
 
+    pub fn passphrase(mut self, passphrase: impl Into<String>) -> Self {
+        self.passphrase = Some(Secret::new(passphrase));
+        self
+    }
+
+    pub fn private_key_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.private_key_file = Some(path.into());
+        self
+    }
+
+    /// build 时从 provider 解析凭证，只填充未显式设置的字段
+    pub fn credential_provider(mut self, provider: Arc<dyn CredentialProvider>) -> Self {
+        self.credential_provider = Some(provider);
+        self
+    }
+
     pub fn ssl_uri(mut self, uri: impl Into<String>) -> Self {
         self.ssl_uri = Some(uri.into());
         self
@@ -223,6 +346,11 @@ impl ExchangeSpecificationBuilder {
         self
     }
 
+    pub fn override_websocket_api_uri(mut self, uri: impl Into<String>) -> Self {
+        self.override_websocket_api_uri = Some(uri.into());
+        self
+    }
+
     pub fn host(mut self, host: impl Into<String>) -> Self {
         self.host = Some(host.into());
         self
@@ -253,6 +381,26 @@ impl ExchangeSpecificationBuilder {
         self
     }
 
+    pub fn http_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.http_pool_max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    pub fn http_pool_idle_timeout(mut self, timeout: u64) -> Self {
+        self.http_pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    pub fn http_tcp_keep_alive(mut self, interval: u64) -> Self {
+        self.http_tcp_keep_alive = Some(interval);
+        self
+    }
+
+    pub fn http_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http_user_agent = Some(user_agent.into());
+        self
+    }
+
     pub fn resilience(mut self, resilience: ResilienceSpecification) -> Self {
         self.resilience = Some(resilience);
         self
@@ -263,6 +411,11 @@ impl ExchangeSpecificationBuilder {
         self
     }
 
+    pub fn meta_data_snapshot_file(mut self, path: impl Into<String>) -> Self {
+        self.meta_data_snapshot_file = Some(path.into());
+        self
+    }
+
     pub fn should_load_remote_meta_data(mut self, value: bool) -> Self {
         self.should_load_remote_meta_data = Some(value);
         self
@@ -278,10 +431,14 @@ impl ExchangeSpecificationBuilder {
     }
 
     pub fn build(self) -> ExchangeSpecification {
-        ExchangeSpecification {
+        let mut spec = ExchangeSpecification {
             port: self.port.unwrap_or(80),
             http_conn_timeout: self.http_conn_timeout.unwrap_or(10_000),
             http_read_timeout: self.http_read_timeout.unwrap_or(10_000),
+            http_pool_max_idle_per_host: self.http_pool_max_idle_per_host,
+            http_pool_idle_timeout: self.http_pool_idle_timeout,
+            http_tcp_keep_alive: self.http_tcp_keep_alive,
+            http_user_agent: self.http_user_agent,
             should_load_remote_meta_data: self.should_load_remote_meta_data.unwrap_or(true),
             resilience: self.resilience.unwrap_or_default(),
             exchange_name: self.exchange_name,
@@ -290,6 +447,8 @@ impl ExchangeSpecificationBuilder {
             password: self.password,
             secret_key: self.secret_key,
             api_key: self.api_key,
+            passphrase: self.passphrase,
+            private_key_file: self.private_key_file,
             ssl_uri: self.ssl_uri,
             plain_text_uri: self.plain_text_uri,
             override_websocket_api_uri: self.override_websocket_api_uri,
@@ -298,7 +457,12 @@ impl ExchangeSpecificationBuilder {
             proxy_port: self.proxy_port,
             exchange_specific_parameters: self.exchange_specific_parameters,
             meta_data_json_file_override: self.meta_data_json_file_override,
+            meta_data_snapshot_file: self.meta_data_snapshot_file,
             use_sandbox: false,
+        };
+        if let Some(provider) = &self.credential_provider {
+            spec.resolve_credentials(provider.as_ref());
         }
+        spec
     }
 }
@@ -7,6 +7,7 @@ use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// `Instrument` trait:
@@ -156,3 +157,74 @@ impl InstrumentDTO {
         }
     }
 }
+
+/// 标准 symbol，与 Java XChange 一致：
+/// - Spot: `BTC/USDT`
+/// - Futures: `BTC/USDT/PERP`、`BTC/USD/240329`
+/// - Options: `BTC/USD/210709/34000/C`
+impl fmt::Display for InstrumentDTO {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstrumentDTO::Spot { base, counter } => write!(f, "{}/{}", base, counter),
+            InstrumentDTO::Futures {
+                base,
+                counter,
+                prompt,
+            } => write!(
+                f,
+                "{}/{}/{}",
+                base,
+                counter,
+                prompt.as_deref().unwrap_or_default()
+            ),
+            InstrumentDTO::Options {
+                base,
+                counter,
+                strike,
+                expire_date,
+                option_type,
+            } => write!(
+                f,
+                "{}/{}/{}/{}/{}",
+                base,
+                counter,
+                expire_date.format("%y%m%d"),
+                strike.normalize(),
+                option_type
+            ),
+        }
+    }
+}
+
+impl FromStr for InstrumentDTO {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('/').map(str::trim).collect();
+        if parts.len() < 2 || parts[0].is_empty() || parts[1].is_empty() {
+            return Err(format!("Invalid instrument symbol: {}", s));
+        }
+
+        let base = parts[0].to_uppercase();
+        let counter = parts[1].to_uppercase();
+        match parts.len() {
+            2 => Ok(InstrumentDTO::Spot { base, counter }),
+            3 => Ok(InstrumentDTO::Futures {
+                base,
+                counter,
+                prompt: Some(parts[2].to_string()).filter(|p| !p.is_empty()),
+            }),
+            5 => Ok(InstrumentDTO::Options {
+                base,
+                counter,
+                expire_date: NaiveDate::parse_from_str(parts[2], "%y%m%d")
+                    .map_err(|e| format!("Invalid option expire date {}: {}", parts[2], e))?,
+                strike: parts[3]
+                    .parse()
+                    .map_err(|e| format!("Invalid option strike {}: {}", parts[3], e))?,
+                option_type: parts[4].parse()?,
+            }),
+            _ => Err(format!("Invalid instrument symbol: {}", s)),
+        }
+    }
+}
@@ -1,29 +1,46 @@
 use std::time::SystemTimeError;
 use std::{fmt, io};
 
+pub mod client_config;
 pub mod params_digest;
+pub mod replay;
+pub mod transport;
+
 #[derive(Debug)]
 pub enum HttpError {
     InvalidProxy(String),
+    InvalidHeader(String),
     Io(io::Error),
     InvalidKey(String),
     InvalidTimestamp(SystemTimeError),
     UnsupportedMethod(String),
+    /// 录制 / 回放 fixture 出错，包括回放时找不到匹配的请求
+    Fixture(String),
 }
 
 impl fmt::Display for HttpError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             HttpError::InvalidProxy(p) => write!(f, "invalid proxy: {}", p),
+            HttpError::InvalidHeader(h) => write!(f, "invalid header: {}", h),
             HttpError::Io(e) => write!(f, "I/O error: {}", e),
             HttpError::InvalidKey(d) => write!(f, "Params Digest Invalid: {}", d),
             HttpError::InvalidTimestamp(t) => write!(f, "Timestamp Invalid: {}", t),
             HttpError::UnsupportedMethod(u) => write!(f, "Unsupported Method: {}", u),
+            HttpError::Fixture(m) => write!(f, "HTTP fixture error: {}", m),
         }
     }
 }
 
-impl std::error::Error for HttpError {}
+impl std::error::Error for HttpError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HttpError::Io(e) => Some(e),
+            HttpError::InvalidTimestamp(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 impl From<io::Error> for HttpError {
     fn from(err: io::Error) -> Self {
@@ -24,6 +24,14 @@ pub trait ParamsDigest: Send + Sync {
         query: &[(String, String)],
         body: Option<&str>,
     ) -> Result<String, HttpError>;
+
+    /// Sign an already assembled payload exactly as it will be sent
+    /// (e.g. the encoded query string followed by the body)
+    fn digest_payload(&self, _payload: &str) -> Result<String, HttpError> {
+        Err(HttpError::UnsupportedMethod(
+            "digest_payload is not supported by this digest".to_string(),
+        ))
+    }
 }
 
 /// -------------------------
@@ -88,22 +96,30 @@ pub enum DigestError {
 /// 公共方法实现
 /// -------------------------
 impl BaseParamsDigest {
-    /// 创建 BaseParamsDigest
+    /// 创建 BaseParamsDigest，secret 为 base64 编码
     pub fn new(secret_base64: &str, algo: HmacAlgorithm) -> Result<Arc<Self>, DigestError> {
         let key_bytes =
             base64::decode(secret_base64).map_err(|e| DigestError::InvalidKey(e.to_string()))?;
+        Self::from_bytes(&key_bytes, algo)
+    }
+
+    /// secret 原样作为 HMAC key，例如 Binance 的 API secret
+    pub fn from_raw(secret: &str, algo: HmacAlgorithm) -> Result<Arc<Self>, DigestError> {
+        Self::from_bytes(secret.as_bytes(), algo)
+    }
 
+    pub fn from_bytes(key_bytes: &[u8], algo: HmacAlgorithm) -> Result<Arc<Self>, DigestError> {
         let mac: Arc<dyn MacTrait> = match algo {
             HmacAlgorithm::Sha256 => Arc::new(
-                Hmac::<Sha256>::new_from_slice(&key_bytes)
+                Hmac::<Sha256>::new_from_slice(key_bytes)
                     .map_err(|e| DigestError::InvalidKey(e.to_string()))?,
             ),
             HmacAlgorithm::Sha384 => Arc::new(
-                Hmac::<Sha384>::new_from_slice(&key_bytes)
+                Hmac::<Sha384>::new_from_slice(key_bytes)
                     .map_err(|e| DigestError::InvalidKey(e.to_string()))?,
             ),
             HmacAlgorithm::Sha512 => Arc::new(
-                Hmac::<Sha512>::new_from_slice(&key_bytes)
+                Hmac::<Sha512>::new_from_slice(key_bytes)
                     .map_err(|e| DigestError::InvalidKey(e.to_string()))?,
             ),
         };
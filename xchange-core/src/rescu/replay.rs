@@ -0,0 +1,566 @@
+use crate::rescu::HttpError;
+use crate::rescu::client_config::HttpClientConfig;
+use crate::rescu::transport::{HttpRequest, HttpResponse, HttpTransport, ReqwestTransport};
+use async_trait::async_trait;
+use log::error;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use url::Url;
+
+/// 选择 HTTP 模式的环境变量：live / record / replay，未设置或为空时为 replay
+pub const HTTP_MODE_ENV: &str = "XCHANGE_HTTP_MODE";
+
+/// 录制时替换凭证的占位值
+pub const SCRUBBED: &str = "<scrubbed>";
+
+/// 录制时脱敏的请求头 / 响应头
+pub const DEFAULT_SCRUBBED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-mbx-apikey",
+];
+
+/// 录制时脱敏的 query 参数
+pub const DEFAULT_SCRUBBED_PARAMS: &[&str] = &["signature"];
+
+/// 回放时不参与匹配的 query 参数，每次请求都会变化
+pub const DEFAULT_IGNORED_PARAMS: &[&str] = &["timestamp", "signature"];
+
+/// 测试使用的 HTTP 模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FixtureMode {
+    /// 直接访问交易所
+    Live,
+    /// 访问交易所，并把请求 / 响应写入 fixture
+    Record,
+    /// 只从 fixture 返回响应，不访问网络
+    #[default]
+    Replay,
+}
+
+impl FixtureMode {
+    /// 读取 `XCHANGE_HTTP_MODE`，未设置或为空（如 `XCHANGE_HTTP_MODE= cargo test`）时为 Replay，
+    /// 其他无法识别的值返回错误
+    pub fn from_env() -> Result<Self, HttpError> {
+        match std::env::var(HTTP_MODE_ENV) {
+            Ok(mode) if !mode.trim().is_empty() => mode.parse(),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// 按模式创建 transport，Live 返回 None，由调用方使用默认的 HTTP client
+    pub fn transport(
+        self,
+        path: impl AsRef<Path>,
+        config: &HttpClientConfig,
+    ) -> Result<Option<Arc<dyn HttpTransport>>, HttpError> {
+        let transport: Arc<dyn HttpTransport> = match self {
+            FixtureMode::Live => return Ok(None),
+            FixtureMode::Record => Arc::new(RecordingTransport::new(
+                Arc::new(ReqwestTransport::new(config)?),
+                path,
+            )),
+            FixtureMode::Replay => Arc::new(ReplayTransport::load(path)?),
+        };
+        Ok(Some(transport))
+    }
+}
+
+impl FromStr for FixtureMode {
+    type Err = HttpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "live" => Ok(FixtureMode::Live),
+            "record" => Ok(FixtureMode::Record),
+            "replay" => Ok(FixtureMode::Replay),
+            other => Err(HttpError::Fixture(format!(
+                "unknown {} '{}', expected live, record or replay",
+                HTTP_MODE_ENV, other
+            ))),
+        }
+    }
+}
+
+/// 按 `XCHANGE_HTTP_MODE` 创建测试用 transport，path 为该测试的 fixture 文件
+pub fn fixture_transport(
+    path: impl AsRef<Path>,
+) -> Result<Option<Arc<dyn HttpTransport>>, HttpError> {
+    FixtureMode::from_env()?.transport(path, &HttpClientConfig::default())
+}
+
+/// 录制的请求体 / 响应体，JSON 原样保存便于阅读和修改
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FixtureBody {
+    Json(serde_json::Value),
+    Text(String),
+}
+
+impl FixtureBody {
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        match serde_json::from_slice(bytes) {
+            Ok(value) => FixtureBody::Json(value),
+            Err(_) => FixtureBody::Text(String::from_utf8_lossy(bytes).into_owned()),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            FixtureBody::Json(value) => value.to_string().into_bytes(),
+            FixtureBody::Text(text) => text.clone().into_bytes(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<FixtureBody>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    pub body: FixtureBody,
+}
+
+impl From<&RecordedResponse> for HttpResponse {
+    fn from(response: &RecordedResponse) -> Self {
+        HttpResponse {
+            status: response.status,
+            headers: response.headers.clone(),
+            body: response.body.to_bytes(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// fixture 文件：按发送顺序保存的请求 / 响应
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Fixture {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Fixture {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HttpError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| {
+            HttpError::Fixture(format!(
+                "cannot read {}: {} (record it with {}=record)",
+                path.display(),
+                e,
+                HTTP_MODE_ENV
+            ))
+        })?;
+        serde_json::from_str(&content)
+            .map_err(|e| HttpError::Fixture(format!("cannot parse {}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), HttpError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut content = serde_json::to_string_pretty(self)
+            .map_err(|e| HttpError::Fixture(format!("cannot serialize fixture: {}", e)))?;
+        content.push('\n');
+        fs::write(path, content)?;
+        Ok(())
+    }
+}
+
+/// 回放时用于匹配的请求：方法 + 路径 + query 参数（忽略顺序）+ 请求体。
+///
+/// 不比较 host 和请求头，sandbox 与正式环境共用 fixture
+#[derive(Debug, PartialEq)]
+struct RequestKey {
+    method: String,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<FixtureBody>,
+}
+
+impl RequestKey {
+    fn new(method: &str, url: &Url, body: Option<FixtureBody>, ignored: &[String]) -> Self {
+        let mut query: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| !ignored.iter().any(|ignored| ignored == name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        query.sort();
+        Self {
+            method: method.to_ascii_uppercase(),
+            path: url.path().to_string(),
+            query,
+            body,
+        }
+    }
+}
+
+impl fmt::Display for RequestKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        for (i, (name, value)) in self.query.iter().enumerate() {
+            write!(f, "{}{}={}", if i == 0 { '?' } else { '&' }, name, value)?;
+        }
+        if let Some(body) = &self.body {
+            write!(f, " body={}", String::from_utf8_lossy(&body.to_bytes()))?;
+        }
+        Ok(())
+    }
+}
+
+/// 转发给内部 transport，并把脱敏后的请求 / 响应写入 fixture 文件。
+///
+/// 每次请求后重写整个文件；发送失败（无响应）的请求不录制
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn HttpTransport>,
+    path: PathBuf,
+    scrubbed_headers: Vec<String>,
+    scrubbed_params: Vec<String>,
+    fixture: Mutex<Fixture>,
+}
+
+impl RecordingTransport {
+    pub fn new(inner: Arc<dyn HttpTransport>, path: impl AsRef<Path>) -> Self {
+        Self {
+            inner,
+            path: path.as_ref().to_path_buf(),
+            scrubbed_headers: DEFAULT_SCRUBBED_HEADERS
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            scrubbed_params: DEFAULT_SCRUBBED_PARAMS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            fixture: Mutex::new(Fixture::default()),
+        }
+    }
+
+    /// 额外脱敏的 header，不区分大小写
+    pub fn scrub_header(mut self, name: impl Into<String>) -> Self {
+        self.scrubbed_headers.push(name.into());
+        self
+    }
+
+    /// 额外脱敏的 query 参数
+    pub fn scrub_param(mut self, name: impl Into<String>) -> Self {
+        self.scrubbed_params.push(name.into());
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn scrub_headers(&self, headers: &[(String, String)]) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let scrubbed = self
+                    .scrubbed_headers
+                    .iter()
+                    .any(|scrubbed| scrubbed.eq_ignore_ascii_case(name));
+                let value = if scrubbed { SCRUBBED } else { value.as_str() };
+                (name.clone(), value.to_string())
+            })
+            .collect()
+    }
+
+    fn scrub_url(&self, url: &Url) -> Url {
+        let mut url = url.clone();
+        if url.query().is_some() {
+            let pairs: Vec<(String, String)> = url
+                .query_pairs()
+                .map(|(name, value)| {
+                    let value = if self.scrubbed_params.iter().any(|p| p == &name) {
+                        SCRUBBED.to_string()
+                    } else {
+                        value.into_owned()
+                    };
+                    (name.into_owned(), value)
+                })
+                .collect();
+            url.query_pairs_mut().clear().extend_pairs(pairs);
+        }
+        url
+    }
+}
+
+#[async_trait]
+impl HttpTransport for RecordingTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let recorded = RecordedRequest {
+            method: request.method.clone(),
+            url: self.scrub_url(&request.url).to_string(),
+            headers: self.scrub_headers(&request.headers),
+            body: request.body.as_deref().map(FixtureBody::from_bytes),
+        };
+
+        let response = self.inner.execute(request).await?;
+
+        let mut fixture = self.fixture.lock();
+        fixture.interactions.push(Interaction {
+            request: recorded,
+            response: RecordedResponse {
+                status: response.status,
+                headers: self.scrub_headers(&response.headers),
+                body: FixtureBody::from_bytes(&response.body),
+            },
+        });
+        fixture.save(&self.path)?;
+
+        Ok(response)
+    }
+}
+
+/// 只从 fixture 返回响应，不访问网络。
+///
+/// 同一请求按录制顺序依次返回，用完后重复返回最后一个（轮询、时钟同步等）；
+/// 找不到匹配的录制时返回 `HttpError::Fixture`，不会降级为真实请求
+#[derive(Debug)]
+pub struct ReplayTransport {
+    path: PathBuf,
+    interactions: Vec<(Url, Interaction)>,
+    ignored_params: Vec<String>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, HttpError> {
+        let path = path.as_ref();
+        Self::new(path, Fixture::load(path)?)
+    }
+
+    pub fn new(path: impl AsRef<Path>, fixture: Fixture) -> Result<Self, HttpError> {
+        let path = path.as_ref().to_path_buf();
+        let interactions = fixture
+            .interactions
+            .into_iter()
+            .map(|interaction| {
+                let url = Url::parse(&interaction.request.url).map_err(|e| {
+                    HttpError::Fixture(format!(
+                        "invalid url '{}' in {}: {}",
+                        interaction.request.url,
+                        path.display(),
+                        e
+                    ))
+                })?;
+                Ok((url, interaction))
+            })
+            .collect::<Result<Vec<_>, HttpError>>()?;
+
+        Ok(Self {
+            used: Mutex::new(vec![false; interactions.len()]),
+            path,
+            interactions,
+            ignored_params: DEFAULT_IGNORED_PARAMS
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        })
+    }
+
+    /// 额外不参与匹配的 query 参数
+    pub fn ignore_param(mut self, name: impl Into<String>) -> Self {
+        self.ignored_params.push(name.into());
+        self
+    }
+
+    /// 尚未被请求过的录制
+    pub fn unused(&self) -> Vec<String> {
+        let used = self.used.lock();
+        self.interactions
+            .iter()
+            .zip(used.iter())
+            .filter(|(_, used)| !**used)
+            .map(|((url, interaction), _)| self.recorded_key(url, interaction).to_string())
+            .collect()
+    }
+
+    fn recorded_key(&self, url: &Url, interaction: &Interaction) -> RequestKey {
+        RequestKey::new(
+            &interaction.request.method,
+            url,
+            interaction.request.body.clone(),
+            &self.ignored_params,
+        )
+    }
+
+    fn unmatched(&self, key: &RequestKey) -> HttpError {
+        let mut message = format!(
+            "no recorded response for {} in {}; recorded requests:",
+            key,
+            self.path.display()
+        );
+        for (url, interaction) in &self.interactions {
+            message.push_str(&format!("\n  {}", self.recorded_key(url, interaction)));
+        }
+        message.push_str(&format!(
+            "\nre-record the fixture with {}=record",
+            HTTP_MODE_ENV
+        ));
+        error!("{}", message);
+        HttpError::Fixture(message)
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReplayTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let key = RequestKey::new(
+            &request.method,
+            &request.url,
+            request.body.as_deref().map(FixtureBody::from_bytes),
+            &self.ignored_params,
+        );
+
+        let matches: Vec<usize> = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, (url, interaction))| self.recorded_key(url, interaction) == key)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut used = self.used.lock();
+        let index = match matches.iter().find(|&&i| !used[i]).or(matches.last()) {
+            Some(&index) => index,
+            None => return Err(self.unmatched(&key)),
+        };
+        used[index] = true;
+
+        Ok(HttpResponse::from(&self.interactions[index].1.response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rescu::transport::FnTransport;
+
+    fn fixture_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("xchange-{}-{}.json", name, std::process::id()))
+    }
+
+    fn signed_request(timestamp: u64) -> HttpRequest {
+        let url = format!(
+            "https://api.binance.com/api/v3/order?symbol=BTCUSDT&orderId=28&timestamp={}&signature=abc{}",
+            timestamp, timestamp
+        );
+        HttpRequest::new("GET", Url::parse(&url).unwrap()).header("X-MBX-APIKEY", "real-key")
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let path = fixture_path("record-replay");
+        let fake = FnTransport::new(|request: HttpRequest| {
+            let body = match request.url.path() {
+                "/api/v3/time" => r#"{"serverTime":1499827319559}"#,
+                _ => r#"{"orderId":28,"status":"FILLED"}"#,
+            };
+            Ok(HttpResponse::new(200, body).header("Set-Cookie", "session=1"))
+        });
+        let recorder = RecordingTransport::new(Arc::new(fake), &path);
+        let time = HttpRequest::new(
+            "GET",
+            Url::parse("https://api.binance.com/api/v3/time").unwrap(),
+        );
+        recorder.execute(time.clone()).await.unwrap();
+        recorder.execute(signed_request(1)).await.unwrap();
+
+        // 凭证不写入 fixture
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(!content.contains("real-key"));
+        assert!(!content.contains("abc1"));
+        assert!(!content.contains("session=1"));
+        assert!(content.contains("\"serverTime\": 1499827319559"));
+
+        let replay = ReplayTransport::load(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        // timestamp / signature 不同、query 顺序不同仍然匹配
+        let response = replay.execute(signed_request(2)).await.unwrap();
+        assert_eq!(
+            response.json::<serde_json::Value>().unwrap()["status"],
+            "FILLED"
+        );
+        assert_eq!(replay.unused(), vec!["GET /api/v3/time".to_string()]);
+
+        let response = replay.execute(time.clone()).await.unwrap();
+        assert_eq!(response.text(), r#"{"serverTime":1499827319559}"#);
+        // 用完后重复返回最后一个
+        assert!(replay.execute(time).await.is_ok());
+        assert!(replay.unused().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_unmatched_request_fails() {
+        let path = fixture_path("unmatched");
+        let replay = ReplayTransport::new(
+            &path,
+            serde_json::from_str(
+                r#"{"interactions": [{
+                    "request": {"method": "GET", "url": "https://api.binance.com/api/v3/ping"},
+                    "response": {"status": 200, "body": {"json": {}}}
+                }]}"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let request = HttpRequest::new(
+            "GET",
+            Url::parse("https://api.binance.com/api/v3/klines?symbol=BTCUSDT&interval=1m").unwrap(),
+        );
+        match replay.execute(request).await {
+            Err(HttpError::Fixture(message)) => {
+                assert!(message.contains("GET /api/v3/klines?interval=1m&symbol=BTCUSDT"));
+                assert!(message.contains("GET /api/v3/ping"));
+            }
+            other => panic!("expected fixture error, got {:?}", other),
+        }
+
+        // 请求体不同也不匹配
+        let request = HttpRequest::new(
+            "POST",
+            Url::parse("https://api.binance.com/api/v3/ping").unwrap(),
+        )
+        .body("a=1");
+        assert!(replay.execute(request).await.is_err());
+        assert!(ReplayTransport::load(fixture_path("missing")).is_err());
+    }
+
+    #[test]
+    fn test_fixture_mode() {
+        assert_eq!(
+            "record".parse::<FixtureMode>().unwrap(),
+            FixtureMode::Record
+        );
+        assert_eq!("LIVE".parse::<FixtureMode>().unwrap(), FixtureMode::Live);
+        assert!("".parse::<FixtureMode>().is_err());
+        assert!("offline".parse::<FixtureMode>().is_err());
+        assert_eq!(FixtureMode::default(), FixtureMode::Replay);
+    }
+}
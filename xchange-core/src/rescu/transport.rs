@@ -0,0 +1,382 @@
+use crate::rescu::HttpError;
+use crate::rescu::client_config::HttpClientConfig;
+use async_trait::async_trait;
+use log::debug;
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::time::Instant;
+use url::Url;
+
+/// 发往交易所的 HTTP 请求，签名等中间件处理完后的最终形态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// 大写的 HTTP 方法，如 GET / POST
+    pub method: String,
+    pub url: Url,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub fn new(method: impl Into<String>, url: Url) -> Self {
+        Self {
+            method: method.into().to_ascii_uppercase(),
+            url,
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// 读取请求头，header 名不区分大小写
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+}
+
+/// 交易所返回的 HTTP 响应，包括 4xx / 5xx
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// 读取响应头，header 名不区分大小写
+    pub fn header_value(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// HTTP 传输层：请求进，状态码 / 响应头 / 响应体出。
+///
+/// 非 2xx 响应也返回 `Ok`，由上层按交易所的错误格式解析；只有无法得到响应时返回错误
+#[async_trait]
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError>;
+}
+
+/// 传输层中间件，可修改请求 / 响应，或直接返回响应不再向下传递
+#[async_trait]
+pub trait HttpMiddleware: fmt::Debug + Send + Sync {
+    async fn handle(&self, request: HttpRequest, next: Next<'_>)
+    -> Result<HttpResponse, HttpError>;
+}
+
+/// 剩余的中间件和最终的 transport
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn HttpMiddleware>],
+    transport: &'a dyn HttpTransport,
+}
+
+impl Next<'_> {
+    pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        match self.middlewares.split_first() {
+            Some((middleware, rest)) => {
+                middleware
+                    .handle(
+                        request,
+                        Next {
+                            middlewares: rest,
+                            transport: self.transport,
+                        },
+                    )
+                    .await
+            }
+            None => self.transport.execute(request).await,
+        }
+    }
+}
+
+/// 按添加顺序依次经过中间件，最后交给内部 transport
+#[derive(Debug, Clone)]
+pub struct MiddlewareTransport {
+    transport: Arc<dyn HttpTransport>,
+    middlewares: Vec<Arc<dyn HttpMiddleware>>,
+}
+
+impl MiddlewareTransport {
+    pub fn new(transport: Arc<dyn HttpTransport>) -> Self {
+        Self {
+            transport,
+            middlewares: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, middleware: impl HttpMiddleware + 'static) -> Self {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    pub fn with_arc(mut self, middleware: Arc<dyn HttpMiddleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+}
+
+#[async_trait]
+impl HttpTransport for MiddlewareTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        Next {
+            middlewares: &self.middlewares,
+            transport: self.transport.as_ref(),
+        }
+        .run(request)
+        .await
+    }
+}
+
+/// 记录请求方法、路径、状态码和耗时（不输出 query，避免泄露签名）
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LoggingMiddleware;
+
+#[async_trait]
+impl HttpMiddleware for LoggingMiddleware {
+    async fn handle(
+        &self,
+        request: HttpRequest,
+        next: Next<'_>,
+    ) -> Result<HttpResponse, HttpError> {
+        let method = request.method.clone();
+        let path = request.url.path().to_string();
+        let start = Instant::now();
+        let result = next.run(request).await;
+        match &result {
+            Ok(response) => debug!(
+                "{} {} -> {} ({:?})",
+                method,
+                path,
+                response.status,
+                start.elapsed()
+            ),
+            Err(e) => debug!("{} {} failed ({:?}): {}", method, path, start.elapsed(), e),
+        }
+        result
+    }
+}
+
+/// 由闭包返回响应的 transport，用于测试中的进程内 fake
+pub struct FnTransport<F> {
+    handler: F,
+}
+
+impl<F> FnTransport<F>
+where
+    F: Fn(HttpRequest) -> Result<HttpResponse, HttpError> + Send + Sync,
+{
+    pub fn new(handler: F) -> Self {
+        Self { handler }
+    }
+}
+
+impl<F> fmt::Debug for FnTransport<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FnTransport").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<F> HttpTransport for FnTransport<F>
+where
+    F: Fn(HttpRequest) -> Result<HttpResponse, HttpError> + Send + Sync,
+{
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        (self.handler)(request)
+    }
+}
+
+/// 基于 reqwest 的默认 transport，按 `HttpClientConfig` 设置代理、超时和连接池
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(config: &HttpClientConfig) -> Result<Self, HttpError> {
+        Ok(Self {
+            client: Self::client(config)?,
+        })
+    }
+
+    pub fn with_client(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+
+    pub fn inner(&self) -> &reqwest::Client {
+        &self.client
+    }
+
+    /// 按 config 创建 reqwest client
+    pub fn client(config: &HttpClientConfig) -> Result<reqwest::Client, HttpError> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(config.user_agent.as_str())
+            .pool_max_idle_per_host(config.pool_max_idle_per_host)
+            .pool_idle_timeout(config.pool_idle_timeout)
+            .tcp_keepalive(config.tcp_keep_alive);
+        if let Some(timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = config.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(proxy) = &config.proxy {
+            let proxy = reqwest::Proxy::all(proxy.url())
+                .map_err(|e| HttpError::InvalidProxy(format!("{}: {}", proxy, e)))?;
+            builder = builder.proxy(proxy);
+        }
+        builder.build().map_err(|e| {
+            HttpError::Io(io::Error::other(format!("build HTTP client failed: {}", e)))
+        })
+    }
+
+    fn io_error(e: reqwest::Error) -> HttpError {
+        let kind = if e.is_timeout() {
+            io::ErrorKind::TimedOut
+        } else {
+            io::ErrorKind::Other
+        };
+        HttpError::Io(io::Error::new(kind, e))
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(&self, request: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())
+            .map_err(|_| HttpError::UnsupportedMethod(request.method.clone()))?;
+        let mut builder = self.client.request(method, request.url);
+        for (name, value) in request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        let response = builder.send().await.map_err(Self::io_error)?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        let body = response.bytes().await.map_err(Self::io_error)?.to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Debug)]
+    struct HeaderMiddleware;
+
+    #[async_trait]
+    impl HttpMiddleware for HeaderMiddleware {
+        async fn handle(
+            &self,
+            request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, HttpError> {
+            let response = next.run(request.header("X-Trace", "1")).await?;
+            Ok(response.header("X-Handled", "true"))
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingMiddleware(AtomicUsize);
+
+    #[async_trait]
+    impl HttpMiddleware for Arc<CountingMiddleware> {
+        async fn handle(
+            &self,
+            request: HttpRequest,
+            next: Next<'_>,
+        ) -> Result<HttpResponse, HttpError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            next.run(request).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_middleware_chain() {
+        let fake = FnTransport::new(|request: HttpRequest| {
+            assert_eq!(request.method, "GET");
+            assert_eq!(request.header_value("x-trace"), Some("1"));
+            Ok(HttpResponse::new(200, r#"{"serverTime":1}"#).header("X-MBX-USED-WEIGHT-1M", "2"))
+        });
+        let counter = Arc::new(CountingMiddleware::default());
+        let transport = MiddlewareTransport::new(Arc::new(fake))
+            .with(counter.clone())
+            .with(LoggingMiddleware)
+            .with(HeaderMiddleware);
+
+        let request = HttpRequest::new(
+            "get",
+            Url::parse("https://api.binance.com/api/v3/time").unwrap(),
+        );
+        let response = transport.execute(request).await.unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.header_value("x-handled"), Some("true"));
+        assert_eq!(response.header_value("x-mbx-used-weight-1m"), Some("2"));
+        assert_eq!(
+            response.json::<serde_json::Value>().unwrap()["serverTime"],
+            1
+        );
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+}
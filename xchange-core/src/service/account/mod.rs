@@ -1,2 +1,3 @@
 pub mod account_service;
 pub mod params;
+pub mod streaming_account_service;
@@ -0,0 +1,28 @@
+use crate::currency::currency::Currency;
+use crate::dto::account::balance_update::BalanceUpdate;
+use crate::dto::account::open_position::OpenPosition;
+use crate::error::exchange_error::{ExchangeError, NotYetImplementedForExchangeError};
+use crate::instrument::InstrumentDTO;
+use crate::service::{BaseService, ExchangeStream};
+use async_trait::async_trait;
+
+/// Service to subscribe to pushed balance and position changes of the authenticated account
+#[async_trait]
+pub trait StreamingAccountService: BaseService + Send + Sync {
+    /// Subscribe to balance changes of every wallet, `currency = None` for all currencies
+    async fn balance_changes(
+        &self,
+        _currency: Option<&Currency>,
+    ) -> Result<ExchangeStream<BalanceUpdate>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("balance_changes").into())
+    }
+
+    /// Subscribe to open position updates, `instrument = None` for all instruments.
+    /// A position with a size of zero has been closed.
+    async fn position_changes(
+        &self,
+        _instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<OpenPosition>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("position_changes").into())
+    }
+}
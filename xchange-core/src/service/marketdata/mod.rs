@@ -1,2 +1,3 @@
 pub mod market_data_service;
 pub mod params;
+pub mod streaming_market_data_service;
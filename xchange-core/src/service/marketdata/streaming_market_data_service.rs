@@ -0,0 +1,58 @@
+use crate::dto::marketdata::candle_stick::CandleStick;
+use crate::dto::marketdata::order_book::OrderBook;
+use crate::dto::marketdata::order_book_update::OrderBookUpdate;
+use crate::dto::marketdata::ticker::Ticker;
+use crate::dto::marketdata::trade::Trade;
+use crate::error::exchange_error::{ExchangeError, NotYetImplementedForExchangeError};
+use crate::instrument::InstrumentDTO;
+use crate::service::{BaseService, ExchangeStream};
+use async_trait::async_trait;
+
+/// Service to subscribe to pushed market data
+///
+/// Every method returns a stream that stays open until the caller drops it or the
+/// exchange connection is shut down.
+#[async_trait]
+pub trait StreamingMarketDataService: BaseService + Send + Sync {
+    /// Subscribe to ticker updates of an instrument
+    async fn ticker_stream(
+        &self,
+        _instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Ticker>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("ticker_stream").into())
+    }
+
+    /// Subscribe to public trades of an instrument
+    async fn trade_stream(
+        &self,
+        _instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Trade>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("trade_stream").into())
+    }
+
+    /// Subscribe to order book snapshots of an instrument
+    async fn order_book_stream(
+        &self,
+        _instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<OrderBook>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("order_book_stream").into())
+    }
+
+    /// Subscribe to incremental order book updates of an instrument.
+    /// Each item holds all price levels changed by one exchange event.
+    async fn order_book_update_stream(
+        &self,
+        _instrument: &InstrumentDTO,
+    ) -> Result<ExchangeStream<Vec<OrderBookUpdate>>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("order_book_update_stream").into())
+    }
+
+    /// Subscribe to candlesticks of an instrument, `period_secs` is the candle length in seconds
+    async fn candle_stick_stream(
+        &self,
+        _instrument: &InstrumentDTO,
+        _period_secs: u64,
+    ) -> Result<ExchangeStream<CandleStick>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("candle_stick_stream").into())
+    }
+}
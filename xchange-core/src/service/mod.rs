@@ -1,13 +1,19 @@
 use crate::dto::meta::exchange_metadata::ExchangeMetaData;
 use crate::dto::trade::limit_order::LimitOrder;
 use crate::dto::trade::market_order::MarketOrder;
+use crate::error::exchange_error::ExchangeError;
 use crate::exchange::Exchange;
+use crate::service::trade::order_validator::OrderValidator;
+use futures::stream::BoxStream;
 use std::any::Any;
 
 pub mod account;
 pub mod marketdata;
 pub mod trade;
 
+/// 推送类服务（Streaming*Service）返回的数据流
+pub type ExchangeStream<T> = BoxStream<'static, Result<T, ExchangeError>>;
+
 // #[async_trait]
 pub trait BaseService: Send + Sync {
     fn as_any(&self) -> &dyn Any;
@@ -16,32 +22,15 @@ pub trait BaseService: Send + Sync {
 pub trait ExchangeService<E: Exchange> {
     fn exchange(&self) -> &E;
 
-    fn verify_limit_order(&self, order: &LimitOrder) -> Result<(), String> {
-        // let meta = self.exchange().exchange_meta_data();
-        // self.verify_order(order, &meta.clone())?;
-        // let price = order.limit_price().ok_or("Missing limit price")?;
-        // if price.scale() > meta.price_scale(order.currency_pair()) {
-        //     return Err(format!("Unsupported price scale {}", price.scale()));
-        // }
-        Ok(())
+    fn verify_limit_order(&self, order: &LimitOrder) -> Result<(), ExchangeError> {
+        OrderValidator::verify_limit_order(order, &self.exchange().exchange_meta_data())
     }
-    // <O: BaseOrder>
+
     fn verify_order(
         &self,
         order: &MarketOrder,
         meta_data: &ExchangeMetaData,
-    ) -> Result<(), String> {
-        // let meta = meta_data.get_instrument(order.currency_pair())
-        //     .ok_or("Invalid CurrencyPair")?;
-        // let amount = order.original_amount().ok_or("Missing originalAmount")?;
-        // let amount = amount.strip_trailing_zeros();
-        // if let Some(min_amount) = meta.minimum_amount() {
-        //     if amount.scale() > min_amount.scale() {
-        //         return Err(format!("Unsupported amount scale {}", amount.scale()));
-        //     } else if amount < min_amount {
-        //         return Err("Order amount less than minimum".to_string());
-        //     }
-        // }
-        Ok(())
+    ) -> Result<(), ExchangeError> {
+        OrderValidator::verify_market_order(order, meta_data)
     }
 }
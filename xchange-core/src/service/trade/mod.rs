@@ -1,2 +1,5 @@
+pub mod order_quantizer;
+pub mod order_validator;
 pub mod params;
+pub mod streaming_trade_service;
 pub mod trade_service;
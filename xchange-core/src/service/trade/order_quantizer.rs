@@ -0,0 +1,368 @@
+use crate::dto::meta::instrument_metadata::InstrumentMetaData;
+use crate::dto::order::{Order, OrderType};
+use crate::dto::trade::limit_order::LimitOrder;
+use crate::dto::trade::market_order::MarketOrder;
+use crate::dto::trade::stop_order::StopOrder;
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// 取整方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// 远离市场：买价向下、卖价向上，挂单更保守
+    AwayFromMarket,
+    /// 靠近市场：买价向上、卖价向下，更容易成交
+    TowardMarket,
+    Floor,
+    Ceil,
+}
+
+/// 把订单价格 / 数量对齐到交易所的 `price_step_size` / `amount_step_size`。
+///
+/// 没有步长时按 `price_scale` / `volume_scale` 取整。数量和 Binance LOT_SIZE 一样
+/// 从 `minimum_amount` 起按步长对齐；数量没有“市场方向”，
+/// `TowardMarket` / `AwayFromMarket` 分别等同 `Ceil` / `Floor`。
+///
+/// ```ignore
+/// let quantizer = OrderQuantizer::new().price_rounding(RoundingMode::TowardMarket);
+/// let order = quantizer.quantize_limit_order(&order, &meta);
+/// let price = OrderQuantizer::format_price(order.limit_price.unwrap(), &meta);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct OrderQuantizer {
+    price_rounding: RoundingMode,
+    amount_rounding: RoundingMode,
+}
+
+impl Default for OrderQuantizer {
+    /// 价格远离市场、数量向下取整：不会比原订单更激进，也不会超出原数量
+    fn default() -> Self {
+        Self {
+            price_rounding: RoundingMode::AwayFromMarket,
+            amount_rounding: RoundingMode::Floor,
+        }
+    }
+}
+
+impl OrderQuantizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn price_rounding(mut self, mode: RoundingMode) -> Self {
+        self.price_rounding = mode;
+        self
+    }
+
+    pub fn amount_rounding(mut self, mode: RoundingMode) -> Self {
+        self.amount_rounding = mode;
+        self
+    }
+
+    pub fn quantize_price(
+        &self,
+        price: Decimal,
+        order_type: &OrderType,
+        meta: &InstrumentMetaData,
+    ) -> Decimal {
+        let strategy = match (self.price_rounding, is_buy(order_type)) {
+            (RoundingMode::Floor, _)
+            | (RoundingMode::AwayFromMarket, true)
+            | (RoundingMode::TowardMarket, false) => RoundingStrategy::ToNegativeInfinity,
+            (RoundingMode::Ceil, _)
+            | (RoundingMode::AwayFromMarket, false)
+            | (RoundingMode::TowardMarket, true) => RoundingStrategy::ToPositiveInfinity,
+        };
+        quantize(
+            price,
+            None,
+            meta.price_step_size,
+            meta.price_scale,
+            strategy,
+        )
+    }
+
+    pub fn quantize_amount(&self, amount: Decimal, meta: &InstrumentMetaData) -> Decimal {
+        let strategy = match self.amount_rounding {
+            RoundingMode::Floor | RoundingMode::AwayFromMarket => {
+                RoundingStrategy::ToNegativeInfinity
+            }
+            RoundingMode::Ceil | RoundingMode::TowardMarket => RoundingStrategy::ToPositiveInfinity,
+        };
+        quantize(
+            amount,
+            meta.minimum_amount,
+            meta.amount_step_size,
+            meta.volume_scale,
+            strategy,
+        )
+    }
+
+    pub fn quantize_limit_order(
+        &self,
+        order: &LimitOrder,
+        meta: &InstrumentMetaData,
+    ) -> LimitOrder {
+        let mut order = order.clone();
+        order.limit_price = order
+            .limit_price
+            .map(|price| self.quantize_price(price, &order.order_base.type_, meta));
+        order.order_base.original_amount = order
+            .order_base
+            .original_amount
+            .map(|amount| self.quantize_amount(amount, meta));
+        order
+    }
+
+    pub fn quantize_market_order(
+        &self,
+        order: &MarketOrder,
+        meta: &InstrumentMetaData,
+    ) -> MarketOrder {
+        let mut order = order.clone();
+        order.order_base.original_amount = order
+            .order_base
+            .original_amount
+            .map(|amount| self.quantize_amount(amount, meta));
+        order
+    }
+
+    pub fn quantize_stop_order(&self, order: &StopOrder, meta: &InstrumentMetaData) -> StopOrder {
+        let mut order = order.clone();
+        let order_type = order.order_base.type_.clone();
+        order.stop_price = self.quantize_price(order.stop_price, &order_type, meta);
+        order.limit_price = order
+            .limit_price
+            .map(|price| self.quantize_price(price, &order_type, meta));
+        order.order_base.original_amount = order
+            .order_base
+            .original_amount
+            .map(|amount| self.quantize_amount(amount, meta));
+        order
+    }
+
+    pub fn quantize_order(&self, order: &Order, meta: &InstrumentMetaData) -> Order {
+        match order {
+            Order::LimitOrder(order) => Order::LimitOrder(self.quantize_limit_order(order, meta)),
+            Order::StopOrder(order) => Order::StopOrder(self.quantize_stop_order(order, meta)),
+            Order::MarketOrder(order) => {
+                Order::MarketOrder(self.quantize_market_order(order, meta))
+            }
+        }
+    }
+
+    /// 按价格精度格式化，不带多余的尾随 0，也不使用科学计数法
+    pub fn format_price(price: Decimal, meta: &InstrumentMetaData) -> String {
+        format_decimal(
+            price,
+            scale_of(None, meta.price_step_size, meta.price_scale),
+        )
+    }
+
+    /// 按数量精度格式化
+    pub fn format_amount(amount: Decimal, meta: &InstrumentMetaData) -> String {
+        format_decimal(
+            amount,
+            scale_of(
+                meta.minimum_amount,
+                meta.amount_step_size,
+                meta.volume_scale,
+            ),
+        )
+    }
+}
+
+/// 开多（Bid）和平空（ExitAsk）为买入方向
+fn is_buy(order_type: &OrderType) -> bool {
+    matches!(order_type, OrderType::Bid | OrderType::ExitAsk)
+}
+
+/// 从 `origin` 起按步长对齐，没有 `origin` 时从 0 起
+fn quantize(
+    value: Decimal,
+    origin: Option<Decimal>,
+    step: Option<Decimal>,
+    scale: Option<u32>,
+    strategy: RoundingStrategy,
+) -> Decimal {
+    let value = match step.filter(|step| *step > Decimal::ZERO) {
+        Some(step) => {
+            let origin = origin.unwrap_or(Decimal::ZERO);
+            origin + ((value - origin) / step).round_dp_with_strategy(0, strategy) * step
+        }
+        None => value,
+    };
+    match scale_of(origin, step, scale) {
+        Some(scale) => value.round_dp_with_strategy(scale, strategy).normalize(),
+        None => value.normalize(),
+    }
+}
+
+/// 精度不小于步长（及起点）的小数位数，避免已按步长对齐的值被 scale 再次取整到步长之外
+fn scale_of(origin: Option<Decimal>, step: Option<Decimal>, scale: Option<u32>) -> Option<u32> {
+    let step_scale = step.filter(|step| *step > Decimal::ZERO).map(|step| {
+        let origin_scale = origin.map_or(0, |origin| origin.normalize().scale());
+        step.normalize().scale().max(origin_scale)
+    });
+    match (step_scale, scale) {
+        (Some(step_scale), Some(scale)) => Some(step_scale.max(scale)),
+        (step_scale, scale) => step_scale.or(scale),
+    }
+}
+
+/// 只截断多余的小数位，不会把已对齐的值再向上取整
+fn format_decimal(value: Decimal, scale: Option<u32>) -> String {
+    let value = match scale {
+        Some(scale) => value.round_dp_with_strategy(scale, RoundingStrategy::ToZero),
+        None => value,
+    };
+    value.normalize().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::meta::exchange_metadata::ExchangeMetaData;
+    use crate::service::trade::order_validator::OrderValidator;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn meta() -> InstrumentMetaData {
+        InstrumentMetaData::builder()
+            .price_step_size(dec("0.05"))
+            .price_scale(2)
+            .amount_step_size(dec("0.001"))
+            .volume_scale(3)
+            .build()
+    }
+
+    fn limit(order_type: OrderType, amount: &str, price: &str) -> LimitOrder {
+        LimitOrder::new(
+            order_type,
+            Some(dec(amount)),
+            "BTC/USDT".parse().unwrap(),
+            String::new(),
+            None,
+            Some(dec(price)),
+        )
+    }
+
+    #[test]
+    fn test_default_rounds_away_from_market() {
+        let quantizer = OrderQuantizer::new();
+        let bid =
+            quantizer.quantize_limit_order(&limit(OrderType::Bid, "1.23456", "100.12"), &meta());
+        assert_eq!(bid.limit_price, Some(dec("100.10")));
+        assert_eq!(bid.order_base.original_amount, Some(dec("1.234")));
+
+        let ask =
+            quantizer.quantize_limit_order(&limit(OrderType::Ask, "1.23456", "100.12"), &meta());
+        assert_eq!(ask.limit_price, Some(dec("100.15")));
+    }
+
+    #[test]
+    fn test_toward_market_and_fixed_direction() {
+        let toward = OrderQuantizer::new().price_rounding(RoundingMode::TowardMarket);
+        assert_eq!(
+            toward.quantize_price(dec("100.12"), &OrderType::Bid, &meta()),
+            dec("100.15")
+        );
+        assert_eq!(
+            toward.quantize_price(dec("100.12"), &OrderType::Ask, &meta()),
+            dec("100.10")
+        );
+        // 平空为买入方向
+        assert_eq!(
+            toward.quantize_price(dec("100.12"), &OrderType::ExitAsk, &meta()),
+            dec("100.15")
+        );
+
+        let ceil = OrderQuantizer::new()
+            .price_rounding(RoundingMode::Ceil)
+            .amount_rounding(RoundingMode::Ceil);
+        assert_eq!(
+            ceil.quantize_price(dec("100.11"), &OrderType::Ask, &meta()),
+            dec("100.15")
+        );
+        assert_eq!(ceil.quantize_amount(dec("1.2341"), &meta()), dec("1.235"));
+    }
+
+    #[test]
+    fn test_step_finer_than_scale() {
+        let meta = InstrumentMetaData::builder()
+            .price_step_size(dec("0.005"))
+            .price_scale(2)
+            .build();
+
+        // 先按步长对齐，再按步长的精度取整，结果仍在步长上
+        let quantizer = OrderQuantizer::new();
+        assert_eq!(
+            quantizer.quantize_price(dec("100.1234"), &OrderType::Ask, &meta),
+            dec("100.125")
+        );
+        assert_eq!(
+            quantizer.quantize_price(dec("100.1234"), &OrderType::Bid, &meta),
+            dec("100.12")
+        );
+        assert_eq!(
+            OrderQuantizer::format_price(dec("100.125"), &meta),
+            "100.125"
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(
+            OrderQuantizer::format_price(dec("100.10"), &meta()),
+            "100.1"
+        );
+        assert_eq!(
+            OrderQuantizer::format_price(dec("30000.000"), &meta()),
+            "30000"
+        );
+        assert_eq!(
+            OrderQuantizer::format_amount(dec("0.00000001"), &meta()),
+            "0"
+        );
+        assert_eq!(
+            OrderQuantizer::format_amount(dec("1.2340000"), &meta()),
+            "1.234"
+        );
+    }
+
+    #[test]
+    fn test_amount_step_from_minimum() {
+        let meta = InstrumentMetaData::builder()
+            .minimum_amount(dec("0.15"))
+            .amount_step_size(dec("0.1"))
+            .price_step_size(dec("0.01"))
+            .build();
+        let mut meta_data = ExchangeMetaData::default();
+        meta_data
+            .instruments
+            .insert("BTC/USDT".parse().unwrap(), meta.clone());
+
+        // 0.3 不在 0.15 起的步长上，向下对齐到 0.25
+        let quantizer = OrderQuantizer::new();
+        let order = quantizer.quantize_limit_order(&limit(OrderType::Bid, "0.3", "100.123"), &meta);
+        assert_eq!(order.order_base.original_amount, Some(dec("0.25")));
+        assert!(OrderValidator::verify_limit_order(&order, &meta_data).is_ok());
+        assert_eq!(OrderQuantizer::format_amount(dec("0.25"), &meta), "0.25");
+
+        let ceil = OrderQuantizer::new().amount_rounding(RoundingMode::Ceil);
+        assert_eq!(ceil.quantize_amount(dec("0.3"), &meta), dec("0.35"));
+    }
+
+    #[test]
+    fn test_format_does_not_round_up() {
+        assert_eq!(
+            OrderQuantizer::format_amount(dec("1.2345"), &meta()),
+            "1.234"
+        );
+        assert_eq!(
+            OrderQuantizer::format_price(dec("100.129"), &meta()),
+            "100.12"
+        );
+    }
+}
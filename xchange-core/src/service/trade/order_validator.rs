@@ -0,0 +1,335 @@
+use crate::dto::meta::exchange_metadata::ExchangeMetaData;
+use crate::dto::meta::instrument_metadata::InstrumentMetaData;
+use crate::dto::order::OrderBase;
+use crate::dto::trade::limit_order::LimitOrder;
+use crate::dto::trade::market_order::MarketOrder;
+use crate::error::exchange_error::{
+    ExchangeError, InstrumentNotValidError, OrderAmountUnderMinimumError, OrderNotValidError,
+};
+use rust_decimal::Decimal;
+
+/// 下单前按 InstrumentMetaData 校验订单，尽量在本地拦截交易所会拒绝的订单。
+///
+/// meta data 中未设置的规则不做校验。
+pub struct OrderValidator;
+
+impl OrderValidator {
+    pub fn verify_limit_order(
+        order: &LimitOrder,
+        meta_data: &ExchangeMetaData,
+    ) -> Result<(), ExchangeError> {
+        let meta = Self::instrument_meta_data(&order.order_base, meta_data)?;
+        let amount = Self::verify_amount(&order.order_base, meta)?;
+
+        let price = order
+            .limit_price
+            .ok_or_else(|| OrderNotValidError::with_message("Missing limit price"))?;
+        if price <= Decimal::ZERO {
+            return Err(OrderNotValidError::with_message(format!(
+                "Limit price {} must be positive",
+                price
+            ))
+            .into());
+        }
+        if let Some(scale) = meta.price_scale
+            && price.normalize().scale() > scale
+        {
+            return Err(OrderNotValidError::with_message(format!(
+                "Unsupported price scale {}, max scale is {}",
+                price.normalize().scale(),
+                scale
+            ))
+            .into());
+        }
+        if let Some(step) = meta.price_step_size.filter(|step| !step.is_zero())
+            && !(price % step).is_zero()
+        {
+            return Err(OrderNotValidError::with_message(format!(
+                "Limit price {} is not a multiple of price step size {}",
+                price, step
+            ))
+            .into());
+        }
+
+        Self::verify_notional(amount * price, meta)
+    }
+
+    /// 市价单没有价格，不校验名义价值
+    pub fn verify_market_order(
+        order: &MarketOrder,
+        meta_data: &ExchangeMetaData,
+    ) -> Result<(), ExchangeError> {
+        let meta = Self::instrument_meta_data(&order.order_base, meta_data)?;
+        if !meta.is_market_order_enabled() {
+            return Err(OrderNotValidError::with_message(format!(
+                "Market orders are not enabled for {}",
+                order.order_base.instrument
+            ))
+            .into());
+        }
+
+        Self::verify_amount(&order.order_base, meta).map(|_| ())
+    }
+
+    fn instrument_meta_data<'a>(
+        order: &OrderBase,
+        meta_data: &'a ExchangeMetaData,
+    ) -> Result<&'a InstrumentMetaData, ExchangeError> {
+        meta_data.instruments.get(&order.instrument).ok_or_else(|| {
+            InstrumentNotValidError::with_message_and_field(
+                format!("No meta data for instrument {}", order.instrument),
+                order.instrument.to_string(),
+            )
+            .into()
+        })
+    }
+
+    /// 校验数量：正数、最小 / 最大数量、步长和精度，返回订单数量
+    fn verify_amount(
+        order: &OrderBase,
+        meta: &InstrumentMetaData,
+    ) -> Result<Decimal, ExchangeError> {
+        let amount = order
+            .original_amount
+            .ok_or_else(|| OrderNotValidError::with_message("Missing original amount"))?;
+        if amount <= Decimal::ZERO {
+            return Err(OrderNotValidError::with_message(format!(
+                "Order amount {} must be positive",
+                amount
+            ))
+            .into());
+        }
+
+        if let Some(min) = meta.minimum_amount
+            && amount < min
+        {
+            return Err(OrderAmountUnderMinimumError::with_message(format!(
+                "Order amount {} is less than minimum amount {}",
+                amount, min
+            ))
+            .into());
+        }
+        if let Some(max) = meta.maximum_amount
+            && amount > max
+        {
+            return Err(OrderNotValidError::with_message(format!(
+                "Order amount {} is greater than maximum amount {}",
+                amount, max
+            ))
+            .into());
+        }
+        if let Some(scale) = meta.volume_scale
+            && amount.normalize().scale() > scale
+        {
+            return Err(OrderNotValidError::with_message(format!(
+                "Unsupported amount scale {}, max scale is {}",
+                amount.normalize().scale(),
+                scale
+            ))
+            .into());
+        }
+        // 与 Binance LOT_SIZE 一致，步长从最小数量起算：(amount - minQty) % stepSize == 0
+        if let Some(step) = meta.amount_step_size.filter(|step| !step.is_zero()) {
+            let min = meta.minimum_amount.unwrap_or(Decimal::ZERO);
+            if !((amount - min) % step).is_zero() {
+                return Err(OrderNotValidError::with_message(format!(
+                    "Order amount {} is not a multiple of amount step size {} from minimum amount {}",
+                    amount, step, min
+                ))
+                .into());
+            }
+        }
+
+        Ok(amount)
+    }
+
+    /// 校验名义价值（数量 × 价格）
+    fn verify_notional(notional: Decimal, meta: &InstrumentMetaData) -> Result<(), ExchangeError> {
+        if let Some(min) = meta.counter_minimum_amount
+            && notional < min
+        {
+            return Err(OrderAmountUnderMinimumError::with_message(format!(
+                "Order notional {} is less than minimum notional {}",
+                notional, min
+            ))
+            .into());
+        }
+        if let Some(max) = meta.counter_maximum_amount
+            && notional > max
+        {
+            return Err(OrderNotValidError::with_message(format!(
+                "Order notional {} is greater than maximum notional {}",
+                notional, max
+            ))
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dto::order::{OrderStatus, OrderType};
+    use crate::instrument::InstrumentDTO;
+
+    fn dec(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    fn btc_usdt() -> InstrumentDTO {
+        "BTC/USDT".parse().unwrap()
+    }
+
+    fn meta_data(market_order_enabled: bool) -> ExchangeMetaData {
+        let mut meta_data = ExchangeMetaData::default();
+        meta_data.instruments.insert(
+            btc_usdt(),
+            InstrumentMetaData::builder()
+                .minimum_amount(dec("0.001"))
+                .maximum_amount(dec("100"))
+                .amount_step_size(dec("0.001"))
+                .volume_scale(3)
+                .price_step_size(dec("0.01"))
+                .price_scale(2)
+                .counter_minimum_amount(dec("5"))
+                .market_order_enabled(market_order_enabled)
+                .build(),
+        );
+        meta_data
+    }
+
+    fn limit(amount: &str, price: &str) -> LimitOrder {
+        LimitOrder::new(
+            OrderType::Bid,
+            Some(dec(amount)),
+            btc_usdt(),
+            String::new(),
+            None,
+            Some(dec(price)),
+        )
+    }
+
+    fn market(amount: &str) -> MarketOrder {
+        MarketOrder::new(
+            OrderType::Ask,
+            dec(amount),
+            btc_usdt(),
+            String::new(),
+            None,
+            None,
+            None,
+            None,
+            OrderStatus::PendingNew,
+            None,
+        )
+    }
+
+    fn message(result: Result<(), ExchangeError>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_valid_orders() {
+        assert!(
+            OrderValidator::verify_limit_order(&limit("0.5", "30000.01"), &meta_data(true)).is_ok()
+        );
+        assert!(OrderValidator::verify_market_order(&market("0.5"), &meta_data(true)).is_ok());
+    }
+
+    #[test]
+    fn test_amount_rules() {
+        let meta_data = meta_data(true);
+        let under = message(OrderValidator::verify_limit_order(
+            &limit("0.0001", "30000"),
+            &meta_data,
+        ));
+        assert!(
+            under.starts_with("OrderAmountUnderMinimumError"),
+            "{}",
+            under
+        );
+
+        let over = message(OrderValidator::verify_limit_order(
+            &limit("101", "1"),
+            &meta_data,
+        ));
+        assert!(over.contains("maximum amount"), "{}", over);
+
+        let step = message(OrderValidator::verify_market_order(
+            &market("0.0015"),
+            &meta_data,
+        ));
+        assert!(
+            step.contains("scale") || step.contains("step size"),
+            "{}",
+            step
+        );
+    }
+
+    #[test]
+    fn test_amount_step_from_minimum() {
+        let mut meta_data = ExchangeMetaData::default();
+        meta_data.instruments.insert(
+            btc_usdt(),
+            InstrumentMetaData::builder()
+                .minimum_amount(dec("0.15"))
+                .amount_step_size(dec("0.1"))
+                .market_order_enabled(true)
+                .build(),
+        );
+
+        // 0.25 = 0.15 + 0.1，不是 0.1 的整数倍但符合步长
+        assert!(OrderValidator::verify_market_order(&market("0.25"), &meta_data).is_ok());
+        let step = message(OrderValidator::verify_market_order(
+            &market("0.3"),
+            &meta_data,
+        ));
+        assert!(step.contains("step size"), "{}", step);
+    }
+
+    #[test]
+    fn test_price_and_notional_rules() {
+        let meta_data = meta_data(true);
+        let scale = message(OrderValidator::verify_limit_order(
+            &limit("0.5", "30000.001"),
+            &meta_data,
+        ));
+        assert!(scale.contains("price scale"), "{}", scale);
+
+        let notional = message(OrderValidator::verify_limit_order(
+            &limit("0.001", "100"),
+            &meta_data,
+        ));
+        assert!(
+            notional.starts_with("OrderAmountUnderMinimumError"),
+            "{}",
+            notional
+        );
+        assert!(notional.contains("notional"), "{}", notional);
+    }
+
+    #[test]
+    fn test_market_order_disabled_and_unknown_instrument() {
+        let disabled = message(OrderValidator::verify_market_order(
+            &market("0.5"),
+            &meta_data(false),
+        ));
+        assert!(
+            disabled.contains("Market orders are not enabled"),
+            "{}",
+            disabled
+        );
+
+        let unknown = message(OrderValidator::verify_market_order(
+            &market("0.5"),
+            &ExchangeMetaData::default(),
+        ));
+        assert!(
+            unknown.starts_with("InstrumentNotValidError"),
+            "{}",
+            unknown
+        );
+    }
+}
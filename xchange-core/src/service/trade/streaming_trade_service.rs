@@ -0,0 +1,29 @@
+use crate::dto::order::Order;
+use crate::dto::trade::user_trade::UserTrade;
+use crate::error::exchange_error::{ExchangeError, NotYetImplementedForExchangeError};
+use crate::instrument::InstrumentDTO;
+use crate::service::{BaseService, ExchangeStream};
+use async_trait::async_trait;
+
+/// Service to subscribe to private order and fill events of the authenticated account
+///
+/// `instrument = None` subscribes to events of all instruments.
+#[async_trait]
+pub trait StreamingTradeService: BaseService + Send + Sync {
+    /// Subscribe to order state changes.
+    /// Every item carries the latest `status`, `cumulative_amount` and `average_price` of the order.
+    async fn order_changes(
+        &self,
+        _instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<Order>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("order_changes").into())
+    }
+
+    /// Subscribe to fills of the account's orders
+    async fn user_trades(
+        &self,
+        _instrument: Option<&InstrumentDTO>,
+    ) -> Result<ExchangeStream<UserTrade>, ExchangeError> {
+        Err(NotYetImplementedForExchangeError::with_message("user_trades").into())
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use thiserror::Error;
+use zeroize::{Zeroize, Zeroizing};
+
+/// 敏感字符串：释放时清零，Debug 不输出内容
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// 读取明文，只在签名 / 鉴权时使用
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CredentialError {
+    #[error("read credentials from {} failed: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("invalid credentials: {0}")]
+    Invalid(String),
+}
+
+/// 交易所凭证，每个字段都可能缺失
+#[derive(Debug, Clone, Default)]
+pub struct Credentials {
+    pub api_key: Option<String>,
+    pub secret_key: Option<Secret>,
+    /// 加密私钥的口令，或交易所要求的 API passphrase
+    pub passphrase: Option<Secret>,
+    /// PEM 私钥文件，secret_key 为空时使用
+    pub private_key_file: Option<PathBuf>,
+}
+
+impl Credentials {
+    pub const API_KEY: &'static str = "apiKey";
+    pub const SECRET_KEY: &'static str = "secretKey";
+    pub const PASSPHRASE: &'static str = "passphrase";
+    pub const PRIVATE_KEY_FILE: &'static str = "privateKeyFile";
+
+    pub fn is_empty(&self) -> bool {
+        self.api_key.is_none()
+            && self.secret_key.is_none()
+            && self.passphrase.is_none()
+            && self.private_key_file.is_none()
+    }
+
+    /// 用 other 补齐缺失字段，已有字段优先
+    pub fn or(self, other: Credentials) -> Credentials {
+        Credentials {
+            api_key: self.api_key.or(other.api_key),
+            secret_key: self.secret_key.or(other.secret_key),
+            passphrase: self.passphrase.or(other.passphrase),
+            private_key_file: self.private_key_file.or(other.private_key_file),
+        }
+    }
+
+    /// 从 properties 格式（`key=value`，`#` 注释）解析
+    pub fn from_properties(contents: &str) -> Credentials {
+        let props = parse_properties(contents);
+        let value = |key: &str| props.get(key).filter(|v| !v.is_empty()).map(|v| v.as_str());
+        Credentials {
+            api_key: value(Self::API_KEY).map(str::to_string),
+            secret_key: value(Self::SECRET_KEY).map(Secret::from),
+            passphrase: value(Self::PASSPHRASE).map(Secret::from),
+            private_key_file: value(Self::PRIVATE_KEY_FILE).map(PathBuf::from),
+        }
+    }
+}
+
+/// 凭证来源，`ExchangeSpecificationBuilder::build` 时解析
+pub trait CredentialProvider: fmt::Debug + Send + Sync {
+    /// 没有可用凭证时返回 `Ok(None)`
+    fn credentials(&self) -> Result<Option<Credentials>, CredentialError>;
+}
+
+/// 从环境变量读取：`{PREFIX}_API_KEY`、`{PREFIX}_SECRET_KEY`、
+/// `{PREFIX}_PASSPHRASE`、`{PREFIX}_PRIVATE_KEY_FILE`，prefix 转为大写
+#[derive(Debug, Clone)]
+pub struct EnvCredentialProvider {
+    prefix: String,
+}
+
+impl EnvCredentialProvider {
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: prefix.to_ascii_uppercase(),
+        }
+    }
+
+    pub fn variable(&self, name: &str) -> String {
+        format!("{}_{}", self.prefix, name)
+    }
+
+    fn var(&self, name: &str) -> Option<String> {
+        std::env::var(self.variable(name))
+            .ok()
+            .filter(|v| !v.is_empty())
+    }
+}
+
+impl CredentialProvider for EnvCredentialProvider {
+    fn credentials(&self) -> Result<Option<Credentials>, CredentialError> {
+        let credentials = Credentials {
+            api_key: self.var("API_KEY"),
+            secret_key: self.var("SECRET_KEY").map(Secret::from),
+            passphrase: self.var("PASSPHRASE").map(Secret::from),
+            private_key_file: self.var("PRIVATE_KEY_FILE").map(PathBuf::from),
+        };
+        Ok((!credentials.is_empty()).then_some(credentials))
+    }
+}
+
+/// 从 properties 文件读取（apiKey / secretKey / passphrase / privateKeyFile），文件不存在时返回 None
+#[derive(Debug, Clone)]
+pub struct FileCredentialProvider {
+    paths: Vec<PathBuf>,
+}
+
+impl FileCredentialProvider {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            paths: vec![path.into()],
+        }
+    }
+
+    /// 兼容原有的 `{prefix}-secret.keys`（或 `secret.keys`）：
+    /// 依次查找可执行文件所在目录和 `~/.ssh`
+    pub fn secret_keys(prefix: Option<&str>) -> Self {
+        let resource = match prefix {
+            Some(p) => format!("{}-secret.keys", p),
+            None => "secret.keys".to_string(),
+        };
+
+        let mut paths = Vec::new();
+        if let Ok(mut path) = std::env::current_exe() {
+            path.set_file_name(&resource);
+            paths.push(path);
+        }
+        if let Some(home) = dirs::home_dir() {
+            paths.push(home.join(".ssh").join(&resource));
+        }
+        Self { paths }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn read(path: &Path) -> Result<Option<Zeroizing<String>>, CredentialError> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        std::fs::read_to_string(path)
+            .map(|contents| Some(Zeroizing::new(contents)))
+            .map_err(|source| CredentialError::Io {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+}
+
+impl CredentialProvider for FileCredentialProvider {
+    fn credentials(&self) -> Result<Option<Credentials>, CredentialError> {
+        for path in &self.paths {
+            if let Some(contents) = Self::read(path)? {
+                return Ok(Some(Credentials::from_properties(&contents)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// 按顺序组合多个 provider，逐字段取第一个提供该字段的值
+#[derive(Debug, Clone, Default)]
+pub struct ChainCredentialProvider {
+    providers: Vec<Arc<dyn CredentialProvider>>,
+}
+
+impl ChainCredentialProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.providers.push(Arc::new(provider));
+        self
+    }
+
+    pub fn with_arc(mut self, provider: Arc<dyn CredentialProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// 默认链：环境变量优先，其次 `{prefix}-secret.keys`
+    pub fn default_for(prefix: &str) -> Self {
+        Self::new()
+            .with(EnvCredentialProvider::new(prefix))
+            .with(FileCredentialProvider::secret_keys(Some(prefix)))
+    }
+}
+
+impl CredentialProvider for ChainCredentialProvider {
+    fn credentials(&self) -> Result<Option<Credentials>, CredentialError> {
+        let mut merged = Credentials::default();
+        for provider in &self.providers {
+            if let Some(credentials) = provider.credentials()? {
+                merged = merged.or(credentials);
+            }
+        }
+        Ok((!merged.is_empty()).then_some(merged))
+    }
+}
+
+/// 解析 `key=value` 行，忽略空行和 `#` 注释
+pub fn parse_properties(contents: &str) -> HashMap<String, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            Some((key.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Fixed(Credentials);
+
+    impl CredentialProvider for Fixed {
+        fn credentials(&self) -> Result<Option<Credentials>, CredentialError> {
+            Ok(Some(self.0.clone()))
+        }
+    }
+
+    #[test]
+    fn test_secret_is_redacted() {
+        let credentials = Credentials {
+            api_key: Some("key".into()),
+            secret_key: Some(Secret::new("very-secret")),
+            passphrase: Some(Secret::new("hunter2")),
+            private_key_file: None,
+        };
+        let debug = format!("{:?}", credentials);
+        assert!(!debug.contains("very-secret"));
+        assert!(!debug.contains("hunter2"));
+        assert_eq!(credentials.secret_key.unwrap().expose(), "very-secret");
+    }
+
+    #[test]
+    fn test_from_properties() {
+        let credentials = Credentials::from_properties(
+            "# binance\napiKey = key\nsecretKey=secret=with=equals\nprivateKeyFile=\n",
+        );
+        assert_eq!(credentials.api_key.as_deref(), Some("key"));
+        assert_eq!(
+            credentials.secret_key.as_ref().map(Secret::expose),
+            Some("secret=with=equals")
+        );
+        assert!(credentials.private_key_file.is_none());
+    }
+
+    #[test]
+    fn test_env_provider() {
+        let provider = EnvCredentialProvider::new("xchange_core_test");
+        assert!(provider.credentials().unwrap().is_none());
+
+        // SAFETY: 变量名只在本测试中使用
+        unsafe {
+            std::env::set_var(provider.variable("API_KEY"), "env-key");
+            std::env::set_var(provider.variable("SECRET_KEY"), "env-secret");
+        }
+        let credentials = provider.credentials().unwrap().unwrap();
+        assert_eq!(credentials.api_key.as_deref(), Some("env-key"));
+        assert_eq!(credentials.secret_key.unwrap().expose(), "env-secret");
+        assert!(credentials.passphrase.is_none());
+    }
+
+    #[test]
+    fn test_file_provider() {
+        let path = std::env::temp_dir().join(format!(
+            "xchange-core-credentials-{}.keys",
+            std::process::id()
+        ));
+        let provider = FileCredentialProvider::new(&path);
+        assert!(provider.credentials().unwrap().is_none());
+
+        std::fs::write(&path, "apiKey=file-key\npassphrase=pass\n").unwrap();
+        let credentials = provider.credentials().unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(credentials.api_key.as_deref(), Some("file-key"));
+        assert_eq!(credentials.passphrase.unwrap().expose(), "pass");
+    }
+
+    #[test]
+    fn test_chain_merges_by_field() {
+        let chain = ChainCredentialProvider::new()
+            .with(Fixed(Credentials {
+                api_key: Some("first".into()),
+                ..Default::default()
+            }))
+            .with(Fixed(Credentials {
+                api_key: Some("second".into()),
+                secret_key: Some("secret".into()),
+                ..Default::default()
+            }));
+
+        let credentials = chain.credentials().unwrap().unwrap();
+        assert_eq!(credentials.api_key.as_deref(), Some("first"));
+        assert_eq!(credentials.secret_key.unwrap().expose(), "secret");
+        assert!(
+            ChainCredentialProvider::new()
+                .credentials()
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_specification_resolves_credentials() {
+        let provider = Arc::new(Fixed(Credentials {
+            api_key: Some("provided-key".into()),
+            secret_key: Some("provided-secret".into()),
+            passphrase: Some("provided-passphrase".into()),
+            private_key_file: None,
+        }));
+        let spec = crate::exchange_specification::ExchangeSpecification::builder()
+            .api_key("explicit-key")
+            .credential_provider(provider)
+            .build();
+
+        assert_eq!(spec.api_key.as_deref(), Some("explicit-key"));
+        assert_eq!(
+            spec.secret_key.as_ref().map(Secret::expose),
+            Some("provided-secret")
+        );
+        let debug = format!("{:?}", spec);
+        assert!(!debug.contains("provided-secret"));
+        assert!(!debug.contains("provided-passphrase"));
+    }
+}
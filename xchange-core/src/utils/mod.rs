@@ -1,4 +1,6 @@
 pub mod auth_utils;
+pub mod credential_vault;
+pub mod credentials;
 pub mod time_nonce;
 
 use crate::service::BaseService;